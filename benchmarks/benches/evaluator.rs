@@ -119,12 +119,38 @@ fn bench_arithmetic(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_builtin_calls(c: &mut Criterion) {
+    let mut group = c.benchmark_group("builtin_calls");
+
+    let test_cases = vec![
+        ("operator", "fold(0, +, 1..10000)"),
+        (
+            "partial_application",
+            "let add = +(1); 1..1000 |> map(add) |> fold(0, +)",
+        ),
+        (
+            "placeholder",
+            "let rem = %(_, 7); 1..1000 |> map(rem) |> filter(_ > 3) |> size",
+        ),
+        ("variadic", "1..1000 |> map(|x| max(x, 500, 250)) |> sum"),
+    ];
+
+    for (name, code) in test_cases {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &code, |b, code| {
+            b.iter(|| parse_and_eval(black_box(code)))
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_fibonacci,
     bench_collections,
     bench_pattern_matching,
     bench_string_operations,
-    bench_arithmetic
+    bench_arithmetic,
+    bench_builtin_calls
 );
 criterion_main!(benches);
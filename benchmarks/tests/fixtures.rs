@@ -0,0 +1,114 @@
+use santa_lang::{
+    AoCRunner, Arguments, Backend, Evaluation, Evaluator, ExpressionKind, ExternalFnDef, Lexer, Location, Object,
+    Parser, RuntimeErr, Time,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+struct StubTime {}
+impl Time for StubTime {
+    fn now(&self) -> u128 {
+        0
+    }
+}
+
+// Puzzle inputs are read from the `aocYYYY_dayDD.input` files which accompany each fixture.
+fn read(arguments: &Arguments, source: Location) -> Evaluation {
+    let Object::String(path) = &*arguments[0] else {
+        unreachable!()
+    };
+    let input = path
+        .strip_prefix("aoc://")
+        .and_then(|puzzle| puzzle.split_once('/'))
+        .map(|(year, day)| fixtures_path().join(format!("aoc{}_day{:0>2}.input", year, day)));
+
+    match input.and_then(|input| fs::read_to_string(input).ok()) {
+        Some(content) => Ok(Rc::new(Object::String(content))),
+        None => Err(RuntimeErr {
+            message: format!("Unable to read fixture input: {}", path),
+            source,
+            trace: vec![],
+        }),
+    }
+}
+
+// Some fixtures parse their input as santa-lang literals (i.e. nested Lists).
+fn evaluate(arguments: &Arguments, source: Location) -> Evaluation {
+    let Object::String(expression) = &*arguments[0] else {
+        unreachable!()
+    };
+
+    match Parser::new(Lexer::new(expression)).parse() {
+        Ok(program) => Evaluator::new().evaluate(&program),
+        Err(error) => Err(RuntimeErr {
+            message: error.message,
+            source,
+            trace: vec![],
+        }),
+    }
+}
+
+fn external_functions() -> Vec<ExternalFnDef> {
+    vec![
+        (
+            "read".to_owned(),
+            vec![ExpressionKind::Identifier("path".to_owned())],
+            Rc::new(read),
+        ),
+        (
+            "evaluate".to_owned(),
+            vec![ExpressionKind::Identifier("expression".to_owned())],
+            Rc::new(evaluate),
+        ),
+    ]
+}
+
+fn fixtures_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+fn answers(source: &str, backend: Backend) -> Vec<(Option<String>, Option<String>)> {
+    let mut runner = AoCRunner::new_with_external_functions(StubTime {}, &external_functions());
+    runner.set_backend(backend);
+    runner.set_test_timeout(None);
+
+    let test_cases = match runner.test(source, true) {
+        Ok(test_cases) => test_cases,
+        Err(error) => panic!("{:?} backend failed: {}", backend, error.message),
+    };
+
+    test_cases
+        .into_iter()
+        .map(|test_case| {
+            for result in test_case.part_one.iter().chain(test_case.part_two.iter()) {
+                assert!(
+                    result.passed,
+                    "{:?} backend expected {}, found {}",
+                    backend, result.expected, result.actual
+                );
+            }
+            (
+                test_case.part_one.map(|result| result.actual),
+                test_case.part_two.map(|result| result.actual),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn fixtures_answer_alike_on_both_backends() {
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(fixtures_path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "santa"))
+        .collect();
+    fixtures.sort();
+
+    for fixture in fixtures {
+        let source = fs::read_to_string(&fixture).unwrap();
+        let tree_walking = answers(&source, Backend::TreeWalking);
+        let bytecode = answers(&source, Backend::Bytecode);
+        assert_eq!(tree_walking, bytecode, "{}", fixture.display());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::evaluator::function::Function;
+use crate::evaluator::grid::{coordinates, index_err, point};
 use crate::evaluator::lazy_sequence::{LazyFn, LazySequence};
 use crate::evaluator::object::Object;
 use crate::evaluator::{Evaluation, Evaluator, RuntimeErr};
@@ -33,11 +35,22 @@ builtin! {
         Object::Dictionary(map) => {
             Ok(Rc::new(Object::Integer(map.len() as i64)))
         }
+        Object::PriorityQueue(queue) => {
+            Ok(Rc::new(Object::Integer(queue.len() as i64)))
+        }
+        Object::Grid(grid) => {
+            Ok(Rc::new(Object::Integer(grid.len() as i64)))
+        }
         Object::String(string) => {
             Ok(Rc::new(Object::Integer(string.graphemes(true).count() as i64)))
         }
         Object::LazySequence(sequence) => {
-            Ok(Rc::new(Object::Integer(sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source).count() as i64)))
+            let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            let count = iter.by_ref().count();
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
+            Ok(Rc::new(Object::Integer(count as i64)))
         }
     }
 }
@@ -85,6 +98,13 @@ builtin! {
             }
             Ok(Rc::new(Object::Dictionary(elements)))
         }
+        (Object::Function(mapper), Object::Grid(grid)) => {
+            let mut cells = Vec::with_capacity(grid.len());
+            for ((x, y), cell) in grid.cells() {
+                cells.push(mapper.apply(evaluator, cell_args(mapper, cell, x, y), source)?);
+            }
+            Ok(Rc::new(Object::Grid(grid.with_cells(Rc::new(cells)))))
+        }
         (Object::Function(mapper), Object::LazySequence(sequence)) => {
             Ok(Rc::new(Object::LazySequence(sequence.with_fn(LazyFn::Map(mapper.clone())))))
         }
@@ -146,6 +166,15 @@ builtin! {
             }
             Ok(Rc::new(Object::Dictionary(elements)))
         }
+        (Object::Function(predicate), Object::Grid(grid)) => {
+            let mut elements = HashMap::default();
+            for ((x, y), cell) in grid.cells() {
+                if predicate.apply(evaluator, cell_args(predicate, cell, x, y), source)?.is_truthy() {
+                    elements.insert(point(x, y), Rc::clone(cell));
+                }
+            }
+            Ok(Rc::new(Object::Dictionary(elements)))
+        }
         (Object::Function(predicate), Object::LazySequence(sequence)) => {
             Ok(Rc::new(Object::LazySequence(sequence.with_fn(LazyFn::Filter(predicate.clone())))))
         }
@@ -203,12 +232,16 @@ builtin! {
         (_, Object::Function(folder), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
             let mut accumulator = Rc::clone(initial);
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 accumulator = folder.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&accumulator), Rc::clone(&element)], source)?;
                 if let Object::Break(value) = &*accumulator {
                     return Ok(Rc::clone(value));
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::clone(&accumulator))
         }
         (_, Object::Function(folder), Object::String(string)) => {
@@ -255,12 +288,16 @@ builtin! {
         }
         (Object::Function(side_effect), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 let result = side_effect.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&element)], source)?;
                 if let Object::Break(_) = &*result {
                     break;
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Nil))
         }
         (Object::Function(side_effect), Object::String(string)) => {
@@ -336,18 +373,21 @@ builtin! {
             let mut elements = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
             let mut accumulator = match elements.next() {
                 Some(element) => Rc::clone(&element),
-                None => return Err(RuntimeErr {
+                None => return Err(elements.take_halting_error().unwrap_or_else(|| RuntimeErr {
                     message: "Unable to reduce an empty LazySequence".to_owned(),
                     source,
                     trace: shared_evaluator.borrow().get_trace()
-                })
+                }))
             };
-            for element in elements {
+            for element in &mut elements {
                 accumulator = reducer.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&accumulator), Rc::clone(&element)], source)?;
                 if let Object::Break(value) = &*accumulator {
                     return Ok(Rc::clone(value));
                 }
             }
+            if let Some(err) = elements.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::clone(&accumulator))
         }
         (Object::Function(reducer), Object::String(string)) => {
@@ -440,13 +480,25 @@ builtin! {
             }
             Ok(Rc::new(Object::Nil))
         }
+        (Object::Function(predicate), Object::Grid(grid)) => {
+            for ((x, y), cell) in grid.cells() {
+                if predicate.apply(evaluator, cell_args(predicate, cell, x, y), source)?.is_truthy() {
+                    return Ok(Rc::clone(cell));
+                }
+            }
+            Ok(Rc::new(Object::Nil))
+        }
         (Object::Function(predicate), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 if predicate.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&element)], source)?.is_truthy() {
                     return Ok(Rc::clone(&element))
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Nil))
         }
         (Object::Function(predicate), Object::String(string)) => {
@@ -490,14 +542,27 @@ builtin! {
             }
             Ok(Rc::new(Object::Integer(count)))
         }
+        (Object::Function(predicate), Object::Grid(grid)) => {
+            let mut count = 0;
+            for ((x, y), cell) in grid.cells() {
+                if predicate.apply(evaluator, cell_args(predicate, cell, x, y), source)?.is_truthy() {
+                    count += 1;
+                }
+            }
+            Ok(Rc::new(Object::Integer(count)))
+        }
         (Object::Function(predicate), Object::LazySequence(sequence)) => {
             let mut count = 0;
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 if predicate.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&element)], source)?.is_truthy() {
                     count += 1;
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Integer(count)))
         }
         (Object::Function(predicate), Object::String(string)) => {
@@ -513,43 +578,50 @@ builtin! {
     }
 }
 
+#[inline]
+fn add_integer(sum: Object, value: &Object) -> Object {
+    match (&sum, value) {
+        (Object::Integer(a), Object::Integer(b)) if a.checked_add(*b).is_some() => Object::Integer(a + b),
+        (Object::Integer(_) | Object::BigInteger(_), Object::Integer(_) | Object::BigInteger(_)) => {
+            Object::from(sum.to_big_integer().unwrap() + value.to_big_integer().unwrap())
+        }
+        _ => sum,
+    }
+}
+
 builtin! {
     sum(collection) [evaluator, source] match {
         Object::List(list) => {
-            let mut sum = 0;
+            let mut sum = Object::Integer(0);
             for element in list {
-                if let Object::Integer(value) = &**element {
-                    sum += value;
-                }
+                sum = add_integer(sum, element);
             }
-            Ok(Rc::new(Object::Integer(sum)))
+            Ok(Rc::new(sum))
         }
         Object::Set(set) => {
-            let mut sum = 0;
+            let mut sum = Object::Integer(0);
             for element in set {
-                if let Object::Integer(value) = &**element {
-                    sum += value;
-                }
+                sum = add_integer(sum, element);
             }
-            Ok(Rc::new(Object::Integer(sum)))
+            Ok(Rc::new(sum))
         }
         Object::Dictionary(map) => {
-            let mut sum = 0;
+            let mut sum = Object::Integer(0);
             for (key, value) in map {
-                if let Object::Integer(value) = &**value {
-                    sum += value;
-                }
+                sum = add_integer(sum, value);
             }
-            Ok(Rc::new(Object::Integer(sum)))
+            Ok(Rc::new(sum))
         }
         Object::LazySequence(sequence) => {
-            let mut sum = 0;
-            for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
-                if let Object::Integer(value) = &*element {
-                    sum += value;
-                }
+            let mut sum = Object::Integer(0);
+            let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
+                sum = add_integer(sum, &element);
+            }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
             }
-            Ok(Rc::new(Object::Integer(sum)))
+            Ok(Rc::new(sum))
         }
     }
 }
@@ -593,7 +665,12 @@ builtin! {
                 Ok(Rc::new(Object::Nil))
             }
             Object::LazySequence(sequence) => {
-                if let Some(max) = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source).max() {
+                let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+                let max = iter.by_ref().max();
+                if let Some(err) = iter.take_halting_error() {
+                    return Err(err);
+                }
+                if let Some(max) = max {
                     return Ok(Rc::clone(&max));
                 }
 
@@ -647,7 +724,12 @@ builtin! {
                 Ok(Rc::new(Object::Nil))
             }
             Object::LazySequence(sequence) => {
-                if let Some(min) = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source).min() {
+                let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+                let min = iter.by_ref().min();
+                if let Some(err) = iter.take_halting_error() {
+                    return Err(err);
+                }
+                if let Some(min) = min {
                     return Ok(Rc::clone(&min));
                 }
 
@@ -707,6 +789,13 @@ builtin! {
             let to_pairs = |(key, value)| Rc::new(Object::List(vec![key, value].into()));
             Ok(Rc::new(Object::List(map.clone().into_iter().map(to_pairs).collect::<Vector<Rc<Object>>>())))
         }
+        Object::PriorityQueue(queue) => {
+            let to_pairs = |(value, priority): (&Rc<Object>, &Rc<Object>)| Rc::new(Object::List(vec![Rc::clone(value), Rc::clone(priority)].into()));
+            Ok(Rc::new(Object::List(queue.iter().map(to_pairs).collect::<Vector<Rc<Object>>>())))
+        }
+        Object::Grid(grid) => {
+            Ok(Rc::new(Object::List(grid.rows().map(|row| Rc::new(Object::List(row))).collect::<Vector<Rc<Object>>>())))
+        }
         Object::LazySequence(sequence) => {
             let result = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source).try_collect()?;
             Ok(Rc::new(Object::List(result)))
@@ -738,7 +827,8 @@ builtin! {
         }
         Object::LazySequence(sequence) => {
             let mut elements = HashSet::default();
-            for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
                 if !element.is_hashable() {
                     return Err(RuntimeErr {
                         message: format!("Unable to include a {} within an Set", element.name()),
@@ -748,6 +838,9 @@ builtin! {
                 }
                 elements.insert(Rc::clone(&element));
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Set(elements)))
         }
         Object::String(string) => {
@@ -791,10 +884,15 @@ builtin! {
         Object::Dictionary(map) => {
             Ok(Rc::new(Object::Dictionary(map.clone())))
         }
+        Object::Grid(grid) => {
+            let cells = grid.cells().map(|((x, y), cell)| (point(x, y), Rc::clone(cell)));
+            Ok(Rc::new(Object::Dictionary(cells.collect())))
+        }
         Object::LazySequence(sequence) => {
             let mut elements = HashMap::default();
 
-            for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
                 if let Object::List(pair) = &*element {
                     if pair.len() == 2 {
                         if !pair[0].is_hashable() {
@@ -818,6 +916,9 @@ builtin! {
                     trace: evaluator.get_trace()
                 })
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
 
             Ok(Rc::new(Object::Dictionary(elements)))
         }
@@ -850,6 +951,76 @@ builtin! {
     }
 }
 
+// States are generated as with `iterate`, until a previously seen state recurs. Unlike `iterate`, errors raised by
+// the generator are propagated. The resulting cycle holds each distinct state, so that any subsequent state can be
+// found via `cycle_state`.
+builtin! {
+    cycle_detect(generator, initial) [evaluator, source] match {
+        (Object::Function(generator), _) => {
+            #[allow(clippy::mutable_key_type)]
+            let mut seen = std::collections::HashMap::new();
+            let mut states = Vector::new();
+            let mut state = Rc::clone(initial);
+            loop {
+                if !state.is_hashable() {
+                    return Err(RuntimeErr {
+                        message: format!("Unable to use a {} as a cycle state", state.name()),
+                        source,
+                        trace: evaluator.get_trace()
+                    });
+                }
+                if let Some(start) = seen.get(&state) {
+                    let mut cycle = HashMap::default();
+                    cycle.insert(Rc::new(Object::String("start".to_owned())), Rc::new(Object::Integer(*start as i64)));
+                    cycle.insert(Rc::new(Object::String("length".to_owned())), Rc::new(Object::Integer((states.len() - start) as i64)));
+                    cycle.insert(Rc::new(Object::String("states".to_owned())), Rc::new(Object::List(states)));
+                    return Ok(Rc::new(Object::Dictionary(cycle)));
+                }
+                seen.insert(Rc::clone(&state), states.len());
+                states.push_back(Rc::clone(&state));
+                state = generator.apply(evaluator, vec![state], source)?;
+            }
+        }
+    }
+}
+
+builtin! {
+    cycle_state(index, cycle) [evaluator, source] match {
+        (Object::Integer(index), Object::Dictionary(cycle)) => {
+            if *index < 0 {
+                return Err(RuntimeErr {
+                    message: format!("Expected a non-negative index, found: {}", index),
+                    source,
+                    trace: evaluator.get_trace()
+                });
+            }
+            let field = |name: &str| cycle.get(&Rc::new(Object::String(name.to_owned()))).map(|value| &**value);
+            match (field("start"), field("length"), field("states")) {
+                (Some(Object::Integer(start)), Some(Object::Integer(length)), Some(Object::List(states)))
+                    if *start >= 0 && *length > 0 && start.checked_add(*length).is_some_and(|end| end as usize <= states.len()) =>
+                {
+                    let position = if *index < start + length { *index } else { start + (index - start) % length };
+                    Ok(Rc::clone(&states[position as usize]))
+                }
+                _ => Err(RuntimeErr {
+                    message: "Expected a cycle, as returned by cycle_detect".to_owned(),
+                    source,
+                    trace: evaluator.get_trace()
+                })
+            }
+        }
+    }
+}
+
+// Grid cells are passed along with their point to functions which accept it, as Dictionary values are with their key.
+fn cell_args(function: &Function, cell: &Rc<Object>, x: i64, y: i64) -> Vec<Rc<Object>> {
+    if function.arity() >= 2 {
+        vec![Rc::clone(cell), point(x, y)]
+    } else {
+        vec![Rc::clone(cell)]
+    }
+}
+
 #[inline]
 fn lazy_zipper(sequences: &Vector<Rc<Object>>) -> Option<Rc<Object>> {
     let mut zipped = Vec::with_capacity(sequences.len());
@@ -871,6 +1042,8 @@ fn lazy_zipper(sequences: &Vector<Rc<Object>>) -> Option<Rc<Object>> {
 fn eager_zipper(sequences: Vector<Rc<Object>>, evaluator: &mut Evaluator, source: Location) -> Evaluation {
     let shared_evaluator = Rc::new(RefCell::new(evaluator));
 
+    // Halting errors raised whilst iterating a LazySequence are recorded once it ends, so they can be propagated.
+    let error = Rc::new(RefCell::new(None));
     let mut iterators: Vec<Box<dyn Iterator<Item = Rc<Object>>>> = Vec::with_capacity(sequences.len());
     for sequence in &sequences {
         match &**sequence {
@@ -881,7 +1054,15 @@ fn eager_zipper(sequences: Vector<Rc<Object>>, evaluator: &mut Evaluator, source
                     .map(|grapheme| Rc::new(Object::String(grapheme.to_string()))),
             )),
             Object::LazySequence(sequence) => {
-                iterators.push(Box::new(sequence.resolve_iter(Rc::clone(&shared_evaluator), source)));
+                let mut iterator = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+                let error = Rc::clone(&error);
+                iterators.push(Box::new(std::iter::from_fn(move || {
+                    let next = iterator.next();
+                    if next.is_none() {
+                        *error.borrow_mut() = iterator.take_halting_error();
+                    }
+                    next
+                })));
             }
             _ => {
                 return Err(RuntimeErr {
@@ -908,6 +1089,9 @@ fn eager_zipper(sequences: Vector<Rc<Object>>, evaluator: &mut Evaluator, source
         zipped.push_back(Rc::new(Object::List(entry)));
     }
 
+    if let Some(err) = error.take() {
+        return Err(err);
+    }
     Ok(Rc::new(Object::List(zipped)))
 }
 
@@ -961,6 +1145,9 @@ builtin! {
             if let Some(first) = iterator.next() {
                 return Ok(Rc::clone(&first));
             }
+            if let Some(err) = iterator.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Nil))
         }
         Object::String(string) => {
@@ -994,6 +1181,9 @@ builtin! {
             if let Some(second) = iterator.next() {
                 return Ok(Rc::clone(&second));
             }
+            if let Some(err) = iterator.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Nil))
         }
         Object::String(string) => {
@@ -1029,8 +1219,12 @@ builtin! {
                     trace: evaluator.get_trace()
                 });
             }
-            let iterator = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
-            if let Some(last) = iterator.last() {
+            let mut iterator = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            let last = iterator.by_ref().last();
+            if let Some(err) = iterator.take_halting_error() {
+                return Err(err);
+            }
+            if let Some(last) = last {
                 return Ok(Rc::clone(&last));
             }
             Ok(Rc::new(Object::Nil))
@@ -1057,6 +1251,9 @@ builtin! {
         Object::LazySequence(sequence) => {
             let mut iterator = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
             iterator.next();
+            if let Some(err) = iterator.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::LazySequence(iterator.to_sequence())))
         }
         Object::String(string) => {
@@ -1083,11 +1280,15 @@ builtin! {
             Ok(Rc::new(Object::Boolean(map.contains_key(value))))
         }
         (Object::LazySequence(sequence), _) => {
-            for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
                 if element == *value {
                     return Ok(Rc::new(Object::Boolean(true)))
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Boolean(false)))
         }
         (Object::String(string), _) => {
@@ -1111,11 +1312,15 @@ builtin! {
             Ok(Rc::new(Object::Boolean(!map.contains_key(value))))
         }
         (Object::LazySequence(sequence), _) => {
-            for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
                 if element == *value {
                     return Ok(Rc::new(Object::Boolean(false)))
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Boolean(true)))
         }
         (Object::String(string), _) => {
@@ -1155,11 +1360,15 @@ builtin! {
         }
         (Object::Function(predicate), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 if predicate.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&element)], source)?.is_truthy() {
                     return Ok(Rc::new(Object::Boolean(true)))
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Boolean(false)))
         }
         (Object::Function(predicate), Object::String(string)) => {
@@ -1201,11 +1410,15 @@ builtin! {
         }
         (Object::Function(predicate), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 if !predicate.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&element)], source)?.is_truthy() {
                     return Ok(Rc::new(Object::Boolean(false)))
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Boolean(true)))
         }
         (Object::Function(predicate), Object::String(string)) => {
@@ -1288,7 +1501,8 @@ builtin! {
                     }
                     Object::LazySequence(sequence) => {
                         let mut elements = HashSet::default();
-                        for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+                        let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+                        for element in &mut iter {
                             if !element.is_hashable() {
                                 return Err(RuntimeErr {
                                     message: format!("Unable to include a {} within an Set", element.name()),
@@ -1298,6 +1512,9 @@ builtin! {
                             }
                             elements.insert(Rc::clone(&element));
                         }
+                        if let Some(err) = iter.take_halting_error() {
+                            return Err(err);
+                        }
                         elements
                     }
                     Object::String(string) => {
@@ -1339,7 +1556,8 @@ builtin! {
                 }
                 Object::LazySequence(sequence) => {
                     let mut elements = HashSet::default();
-                    for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+                    let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+                    for element in &mut iter {
                         if !element.is_hashable() {
                             return Err(RuntimeErr {
                                 message: format!("Unable to include a {} within an Set", element.name()),
@@ -1349,6 +1567,9 @@ builtin! {
                         }
                         elements.insert(Rc::clone(&element));
                     }
+                    if let Some(err) = iter.take_halting_error() {
+                        return Err(err);
+                    }
                     elements
                 }
                 Object::String(string) => {
@@ -1415,7 +1636,8 @@ builtin! {
                     }
                     Object::LazySequence(sequence) => {
                         let mut elements = HashSet::default();
-                        for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+                        let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+                        for element in &mut iter {
                             if !element.is_hashable() {
                                 return Err(RuntimeErr {
                                     message: format!("Unable to include a {} within an Set", element.name()),
@@ -1425,6 +1647,9 @@ builtin! {
                             }
                             elements.insert(Rc::clone(&element));
                         }
+                        if let Some(err) = iter.take_halting_error() {
+                            return Err(err);
+                        }
                         elements
                     }
                     Object::String(string) => {
@@ -1466,7 +1691,8 @@ builtin! {
                 }
                 Object::LazySequence(sequence) => {
                     let mut elements = HashSet::default();
-                    for element in sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+                    let mut iter = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+                    for element in &mut iter {
                         if !element.is_hashable() {
                             return Err(RuntimeErr {
                                 message: format!("Unable to include a {} within an Set", element.name()),
@@ -1476,6 +1702,9 @@ builtin! {
                         }
                         elements.insert(Rc::clone(&element));
                     }
+                    if let Some(err) = iter.take_halting_error() {
+                        return Err(err);
+                    }
                     elements
                 }
                 Object::String(string) => {
@@ -1532,10 +1761,14 @@ builtin! {
             let mut elements = Vector::new();
             elements.push_back(Rc::clone(initial));
             let mut previous = Rc::clone(initial);
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 previous = folder.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&previous), Rc::clone(&element)], source)?;
                 elements.push_back(Rc::clone(&previous));
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::List(elements)))
         }
         (_, Object::Function(folder), Object::String(string)) => {
@@ -1652,12 +1885,16 @@ builtin! {
         }
         (Object::Function(mapper), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 let mapped = mapper.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&element)], source)?;
                 if mapped.is_truthy() {
                     return Ok(mapped);
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Nil))
         }
         (Object::Function(mapper), Object::String(string)) => {
@@ -1686,6 +1923,12 @@ builtin! {
         (_, _, Object::Dictionary(map)) => {
             Ok(Rc::new(Object::Dictionary(map.update(Rc::clone(key), Rc::clone(value)))))
         }
+        (_, _, Object::Grid(grid)) => {
+            match coordinates(key).and_then(|(x, y)| grid.set(x, y, Rc::clone(value))) {
+                Some(associated) => Ok(Rc::new(Object::Grid(associated))),
+                None => Err(index_err(evaluator, source, grid, key, "associate a value with"))
+            }
+        }
     }
 }
 
@@ -1712,6 +1955,15 @@ builtin! {
             };
             Ok(Rc::new(Object::Dictionary(map.update(Rc::clone(key), updater.apply(evaluator, vec![Rc::clone(&previous), Rc::clone(key)], source)?))))
         }
+        (_, Object::Function(updater), Object::Grid(grid)) => {
+            match coordinates(key).and_then(|(x, y)| grid.get(x, y).map(|previous| (x, y, previous))) {
+                Some((x, y, previous)) => {
+                    let updated = updater.apply(evaluator, vec![Rc::clone(previous), Rc::clone(key)], source)?;
+                    Ok(Rc::new(Object::Grid(grid.set(x, y, updated).unwrap())))
+                }
+                None => Err(index_err(evaluator, source, grid, key, "update"))
+            }
+        }
     }
 }
 
@@ -1803,12 +2055,16 @@ builtin! {
         (_, Object::Function(folder), Object::LazySequence(sequence)) => {
             let shared_evaluator = Rc::new(RefCell::new(evaluator));
             let mut accumulator = Rc::clone(initial);
-            for element in sequence.resolve_iter(Rc::clone(&shared_evaluator), source) {
+            let mut iter = sequence.resolve_iter(Rc::clone(&shared_evaluator), source);
+            for element in &mut iter {
                 accumulator = folder.apply(&mut shared_evaluator.borrow_mut(), vec![Rc::clone(&accumulator), Rc::clone(&element)], source)?;
                 if let Object::Break(value) = &*accumulator {
                     return Ok(Rc::clone(value));
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             if let Object::List(accumulated) = &*accumulator {
                 if let Some(value) = accumulated.get(0) {
                     return Ok(Rc::clone(value));
@@ -1862,6 +2118,13 @@ builtin! {
             }
             Ok(Rc::new(Object::List(rotated)))
         }
+        (Object::Integer(steps), Object::Grid(grid)) => {
+            let mut rotated = grid.clone();
+            for _ in 0..steps.rem_euclid(4) {
+                rotated = rotated.rotate();
+            }
+            Ok(Rc::new(Object::Grid(rotated)))
+        }
     }
 }
 
@@ -0,0 +1,299 @@
+use crate::evaluator::grid::{Grid, point};
+use crate::evaluator::object::Object;
+use crate::evaluator::{Evaluation, Evaluator, RuntimeErr};
+use crate::lexer::Location;
+use im_rc::{HashMap, Vector};
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Grids are represented as Dictionaries keyed by `[x, y]` points, with `x` increasing across each line
+// and `y` increasing down the lines of the input.
+builtin! {
+    grid(input) [evaluator, source] match {
+        Object::String(input) => {
+            parse_grid(evaluator, input, None)
+        }
+    }
+}
+
+builtin! {
+    grid_filter(filter, input) [evaluator, source] match {
+        (Object::String(filter), Object::String(input)) => {
+            let cells: Vec<&str> = filter.graphemes(true).collect();
+            parse_grid(evaluator, input, Some(&|_: &mut Evaluator, cell: &str| Ok(cells.contains(&cell))))
+        }
+        (Object::Function(filter), Object::String(input)) => {
+            parse_grid(evaluator, input, Some(&|evaluator: &mut Evaluator, cell: &str| {
+                let cell = Rc::new(Object::String(cell.to_owned()));
+                Ok(filter.apply(evaluator, vec![cell], source)?.is_truthy())
+            }))
+        }
+    }
+}
+
+// Dense grids hold every cell in row-major order, and so suit inputs which are read and updated in full.
+builtin! {
+    dense_grid(input) [evaluator, source] match {
+        Object::String(input) => {
+            let rows = input
+                .lines()
+                .map(|line| line.graphemes(true).map(|cell| Rc::new(Object::String(cell.to_owned()))).collect())
+                .collect();
+            Ok(Rc::new(Object::Grid(Grid::from_rows(rows))))
+        }
+        Object::List(rows) => {
+            let mut cells = Vec::with_capacity(rows.len());
+            for row in rows {
+                match &**row {
+                    Object::List(row) => cells.push(row.iter().cloned().collect()),
+                    row => {
+                        return Err(RuntimeErr {
+                            message: format!("Expected a List of rows, found: {}", row.name()),
+                            source,
+                            trace: evaluator.get_trace()
+                        });
+                    }
+                }
+            }
+            Ok(Rc::new(Object::Grid(Grid::from_rows(cells))))
+        }
+        Object::Dictionary(grid) => {
+            let Some((min, (max_x, max_y))) = bounds(evaluator, source, grid.keys())? else {
+                return Ok(Rc::new(Object::Grid(Grid::from_rows(vec![]))));
+            };
+            if min.0 < 0 || min.1 < 0 {
+                return Err(RuntimeErr {
+                    message: format!("Unable to place a cell at {} within a Grid", point(min.0, min.1)),
+                    source,
+                    trace: evaluator.get_trace()
+                });
+            }
+            expect_area(evaluator, source, ((0, 0), (max_x, max_y)))?;
+            let rows = (0..=max_y)
+                .map(|y| (0..=max_x).map(|x| grid.get(&point(x, y)).cloned().unwrap_or_else(|| Rc::new(Object::Nil))).collect())
+                .collect();
+            Ok(Rc::new(Object::Grid(Grid::from_rows(rows))))
+        }
+        Object::Grid(grid) => {
+            Ok(Rc::new(Object::Grid(grid.clone())))
+        }
+    }
+}
+
+builtin! {
+    transpose(grid) match {
+        Object::Grid(grid) => {
+            Ok(Rc::new(Object::Grid(grid.transpose())))
+        }
+    }
+}
+
+builtin! {
+    grid_bounds(grid) [evaluator, source] match {
+        Object::Dictionary(grid) => {
+            Ok(bounds_to_object(bounds(evaluator, source, grid.keys())?))
+        }
+        Object::Set(points) => {
+            Ok(bounds_to_object(bounds(evaluator, source, points.iter())?))
+        }
+        Object::List(points) => {
+            Ok(bounds_to_object(bounds(evaluator, source, points.iter())?))
+        }
+        Object::Grid(grid) => {
+            let bounds = (!grid.is_empty()).then(|| ((0, 0), (grid.width() as i64 - 1, grid.height() as i64 - 1)));
+            Ok(bounds_to_object(bounds))
+        }
+    }
+}
+
+builtin! {
+    grid_string(grid) [evaluator, source] match {
+        Object::Dictionary(grid) => {
+            let bounds = bounds(evaluator, source, grid.keys())?;
+            let cell = |x: i64, y: i64| match grid.get(&point(x, y)).map(|value| &**value) {
+                Some(Object::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => " ".to_owned(),
+            };
+            Ok(Rc::new(Object::String(render(evaluator, source, bounds, cell)?)))
+        }
+        Object::Set(points) => {
+            Ok(Rc::new(Object::String(render_points(evaluator, source, points)?)))
+        }
+        Object::List(points) => {
+            Ok(Rc::new(Object::String(render_points(evaluator, source, points)?)))
+        }
+        Object::Grid(grid) => {
+            let lines: Vec<String> = grid
+                .rows()
+                .map(|row| row.iter().map(|cell| match &**cell {
+                    Object::String(cell) => cell.clone(),
+                    Object::Nil => " ".to_owned(),
+                    cell => cell.to_string(),
+                }).collect())
+                .collect();
+            Ok(Rc::new(Object::String(lines.join("\n"))))
+        }
+    }
+}
+
+builtin! {
+    neighbours(point) [evaluator, source] match {
+        Object::List(point) => {
+            offset(evaluator, source, point, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+        }
+    }
+}
+
+builtin! {
+    diagonal_neighbours(point) [evaluator, source] match {
+        Object::List(point) => {
+            offset(evaluator, source, point, &[(1, -1), (1, 1), (-1, 1), (-1, -1)])
+        }
+    }
+}
+
+builtin! {
+    all_neighbours(point) [evaluator, source] match {
+        Object::List(point) => {
+            offset(evaluator, source, point, &[(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)])
+        }
+    }
+}
+
+type Bounds = ((i64, i64), (i64, i64));
+type CellFilter<'a> = &'a dyn Fn(&mut Evaluator, &str) -> Result<bool, RuntimeErr>;
+
+fn parse_grid(evaluator: &mut Evaluator, input: &str, filter: Option<CellFilter>) -> Evaluation {
+    let mut grid = HashMap::default();
+    for (y, line) in input.lines().enumerate() {
+        for (x, cell) in line.graphemes(true).enumerate() {
+            match filter {
+                Some(filter) if !filter(evaluator, cell)? => continue,
+                _ => grid.insert(point(x as i64, y as i64), Rc::new(Object::String(cell.to_owned()))),
+            };
+        }
+    }
+    Ok(Rc::new(Object::Dictionary(grid)))
+}
+
+fn offset(evaluator: &Evaluator, source: Location, point: &Vector<Rc<Object>>, offsets: &[(i64, i64)]) -> Evaluation {
+    let (x, y) = coordinates(evaluator, source, point)?;
+    let mut neighbours = Vector::new();
+    for (dx, dy) in offsets {
+        match (x.checked_add(*dx), y.checked_add(*dy)) {
+            (Some(x), Some(y)) => neighbours.push_back(self::point(x, y)),
+            _ => {
+                return Err(RuntimeErr {
+                    message: format!(
+                        "Unable to offset {} beyond the Integer range",
+                        Object::List(point.clone())
+                    ),
+                    source,
+                    trace: evaluator.get_trace(),
+                });
+            }
+        }
+    }
+    Ok(Rc::new(Object::List(neighbours)))
+}
+
+fn bounds<'a>(
+    evaluator: &Evaluator,
+    source: Location,
+    points: impl Iterator<Item = &'a Rc<Object>>,
+) -> Result<Option<Bounds>, RuntimeErr> {
+    let mut bounds: Option<Bounds> = None;
+    for point in points {
+        let (x, y) = point_coordinates(evaluator, source, point)?;
+        bounds = Some(match bounds {
+            Some(((min_x, min_y), (max_x, max_y))) => ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))),
+            None => ((x, y), (x, y)),
+        });
+    }
+    Ok(bounds)
+}
+
+fn bounds_to_object(bounds: Option<Bounds>) -> Rc<Object> {
+    match bounds {
+        Some(((min_x, min_y), (max_x, max_y))) => {
+            Rc::new(Object::List(vec![point(min_x, min_y), point(max_x, max_y)].into()))
+        }
+        None => Rc::new(Object::Nil),
+    }
+}
+
+fn render(
+    evaluator: &Evaluator,
+    source: Location,
+    bounds: Option<Bounds>,
+    cell: impl Fn(i64, i64) -> String,
+) -> Result<String, RuntimeErr> {
+    let Some(((min_x, min_y), (max_x, max_y))) = bounds else {
+        return Ok(String::new());
+    };
+    expect_area(evaluator, source, ((min_x, min_y), (max_x, max_y)))?;
+    let lines: Vec<String> = (min_y..=max_y)
+        .map(|y| (min_x..=max_x).map(|x| cell(x, y)).collect())
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+// The points are gathered into a set of coordinates up front, so that each rendered cell is a single lookup
+// instead of a scan (or a newly allocated point) per cell.
+fn render_points<'a>(
+    evaluator: &Evaluator,
+    source: Location,
+    points: impl IntoIterator<Item = &'a Rc<Object>> + Copy,
+) -> Result<String, RuntimeErr> {
+    let bounds = bounds(evaluator, source, points.into_iter())?;
+    let plotted = points
+        .into_iter()
+        .map(|point| point_coordinates(evaluator, source, point))
+        .collect::<Result<std::collections::HashSet<_>, _>>()?;
+    let cell = |x: i64, y: i64| if plotted.contains(&(x, y)) { "#" } else { "." }.to_owned();
+    render(evaluator, source, bounds, cell)
+}
+
+// Points are spread across every cell within their bounds when rendered (or made dense), so distant points are
+// rejected rather than exhausting the host's memory.
+const MAX_GRID_AREA: i128 = 10_000_000;
+
+fn expect_area(evaluator: &Evaluator, source: Location, bounds: Bounds) -> Result<(), RuntimeErr> {
+    let ((min_x, min_y), (max_x, max_y)) = bounds;
+    let width = max_x as i128 - min_x as i128 + 1;
+    let height = max_y as i128 - min_y as i128 + 1;
+    if width.saturating_mul(height) <= MAX_GRID_AREA {
+        return Ok(());
+    }
+    Err(RuntimeErr {
+        message: format!(
+            "Unable to span a {}x{} grid, exceeding {} cells",
+            width, height, MAX_GRID_AREA
+        ),
+        source,
+        trace: evaluator.get_trace(),
+    })
+}
+
+fn point_coordinates(evaluator: &Evaluator, source: Location, point: &Object) -> Result<(i64, i64), RuntimeErr> {
+    match point {
+        Object::List(point) => coordinates(evaluator, source, point),
+        point => Err(invalid_point(evaluator, source, point)),
+    }
+}
+
+fn coordinates(evaluator: &Evaluator, source: Location, point: &Vector<Rc<Object>>) -> Result<(i64, i64), RuntimeErr> {
+    match (point.get(0).map(|x| &**x), point.get(1).map(|y| &**y), point.len()) {
+        (Some(Object::Integer(x)), Some(Object::Integer(y)), 2) => Ok((*x, *y)),
+        _ => Err(invalid_point(evaluator, source, &Object::List(point.clone()))),
+    }
+}
+
+fn invalid_point(evaluator: &Evaluator, source: Location, point: &Object) -> RuntimeErr {
+    RuntimeErr {
+        message: format!("Expected an [x, y] point, found: {}", point),
+        source,
+        trace: evaluator.get_trace(),
+    }
+}
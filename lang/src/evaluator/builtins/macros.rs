@@ -11,17 +11,16 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
                 let $evaluator = evaluator;
                 let $source = source;
-                $( let $parameter = arguments.get(stringify!($parameter)).unwrap(); )*
-                let $rest = arguments.get(stringify!($rest)).unwrap();
-                match ($( &**arguments.get(stringify!($parameter)).unwrap() ),*, &**arguments.get(stringify!($rest)).unwrap()) {
+                let [$( $parameter ),*, $rest] = arguments else { unreachable!() };
+                match ($( &**$parameter ),*, &**$rest) {
                     $( $pattern => $body ),*
                     _ => {
                         let mut message = String::new();
 
-                        let arguments = vec![$( (stringify!($parameter), arguments.get(stringify!($parameter)).unwrap().name()) ),*]
+                        let arguments = vec![$( (stringify!($parameter), $parameter.name()) ),*]
                             .iter()
                             .map(|(parameter, argument)| format!("{}", argument))
                             .collect::<Vec<_>>()
@@ -45,16 +44,16 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
                 let $evaluator = evaluator;
                 let $source = source;
-                let $rest = arguments.get(stringify!($rest)).unwrap();
-                match (&**arguments.get(stringify!($rest)).unwrap()) {
+                let [$rest] = arguments else { unreachable!() };
+                match (&**$rest) {
                     $( $pattern => $body ),*
                     _ => {
                         let mut message = String::new();
 
-                        let arguments = vec![$( (stringify!($parameter), arguments.get(stringify!($parameter)).unwrap().name()) ),*]
+                        let arguments = vec![$( (stringify!($parameter), $parameter.name()) ),*]
                             .iter()
                             .map(|(parameter, argument)| format!("{}", argument))
                             .collect::<Vec<_>>()
@@ -78,14 +77,14 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
-                $( let $parameter = arguments.get(stringify!($parameter)).unwrap(); )*
-                match ($( &**arguments.get(stringify!($parameter)).unwrap() ),*) {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+                let [$( $parameter ),*] = arguments else { unreachable!() };
+                match ($( &**$parameter ),*) {
                     $( $pattern => $body ),*
                     _ => {
                         let mut message = String::new();
 
-                        let arguments = vec![$( (stringify!($parameter), arguments.get(stringify!($parameter)).unwrap().name()) ),*]
+                        let arguments = vec![$( (stringify!($parameter), $parameter.name()) ),*]
                             .iter()
                             .map(|(parameter, argument)| format!("{}", argument))
                             .collect::<Vec<_>>()
@@ -110,16 +109,16 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
                 let $evaluator = evaluator;
                 let $source = source;
-                $( let $parameter = arguments.get(stringify!($parameter)).unwrap(); )*
-                match ($( &**arguments.get(stringify!($parameter)).unwrap() ),*) {
+                let [$( $parameter ),*] = arguments else { unreachable!() };
+                match ($( &**$parameter ),*) {
                     $( $pattern => $body ),*
                     _ => {
                         let mut message = String::new();
 
-                        let arguments = vec![$( (stringify!($parameter), arguments.get(stringify!($parameter)).unwrap().name()) ),*]
+                        let arguments = vec![$( (stringify!($parameter), $parameter.name()) ),*]
                             .iter()
                             .map(|(parameter, argument)| format!("{}", argument))
                             .collect::<Vec<_>>()
@@ -143,10 +142,10 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
                 let $evaluator = evaluator;
                 let $source = source;
-                $( let $parameter = arguments.get(stringify!($parameter)).unwrap(); )*
+                let [$( $parameter ),*] = arguments else { unreachable!() };
                 $body
             }
         }
@@ -162,11 +161,10 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
                 let $evaluator = evaluator;
                 let $source = source;
-                $( let $parameter = arguments.get(stringify!($parameter)).unwrap(); )*
-                let $rest = arguments.get(stringify!($rest)).unwrap();
+                let [$( $parameter ),*, $rest] = arguments else { unreachable!() };
                 $body
             }
         }
@@ -182,10 +180,10 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
                 let $evaluator = evaluator;
                 let $source = source;
-                let $rest = arguments.get(stringify!($rest)).unwrap();
+                let [$rest] = arguments else { unreachable!() };
                 $body
             }
         }
@@ -201,8 +199,8 @@ macro_rules! builtin {
 
             #[inline]
             #[allow(unused_variables)]
-            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: std::collections::HashMap<String, Rc<Object>>, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
-                $( let $parameter = arguments.get(stringify!($parameter)).unwrap(); )*
+            pub fn body(evaluator: &mut $crate::evaluator::Evaluator, arguments: &$crate::evaluator::Arguments, source: $crate::lexer::Location) -> Result<std::rc::Rc<$crate::evaluator::object::Object>, $crate::evaluator::RuntimeErr> {
+                let [$( $parameter ),*] = arguments else { unreachable!() };
                 $body
             }
         }
@@ -1,12 +1,20 @@
 use crate::evaluator::object::Object;
 use im_rc::Vector;
+use num_bigint::BigInt;
+use num_traits::Signed;
 use ordered_float::OrderedFloat;
 use std::rc::Rc;
 
 builtin! {
     abs(value) match {
         Object::Integer(value) => {
-            Ok(Rc::new(Object::Integer(value.abs())))
+            match value.checked_abs() {
+                Some(value) => Ok(Rc::new(Object::Integer(value))),
+                None => Ok(Rc::new(Object::from(BigInt::from(*value).abs())))
+            }
+        }
+        Object::BigInteger(value) => {
+            Ok(Rc::new(Object::from(value.abs())))
         }
         Object::Decimal(OrderedFloat(value)) => {
             Ok(Rc::new(Object::Decimal(OrderedFloat(value.abs()))))
@@ -31,6 +39,9 @@ builtin! {
         Object::Integer(value) => {
             Ok(Rc::new(Object::Integer(value.signum())))
         }
+        Object::BigInteger(value) => {
+            Ok(Rc::new(Object::from(value.signum())))
+        }
         Object::Decimal(OrderedFloat(value)) => {
             Ok(Rc::new(Object::Decimal(OrderedFloat(value.signum()))))
         }
@@ -1,6 +1,9 @@
 use crate::evaluator::LazySequence;
 use crate::evaluator::function::Function;
 use crate::evaluator::object::Object;
+use crate::evaluator::{AssertionFailure, Evaluator, RuntimeErr};
+use crate::lexer::Location;
+use im_rc::HashMap;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -26,15 +29,116 @@ builtin! {
 
 builtin! {
     memoize(function) [evaluator, source] match {
-        Object::Function(Function::Closure { parameters, body, environment, }) => {
+        Object::Function(Function::Closure { parameters, body, environment, partial }) => {
             let function = Function::MemoizedClosure {
                 parameters: parameters.clone(),
                 body: body.clone(),
                 environment: Rc::clone(environment),
+                partial: partial.clone(),
                 cache: Rc::new(RefCell::new(std::collections::HashMap::default()))
             };
             Ok(Rc::new(Object::Function(function)))
         }
+        Object::Function(Function::Compiled(closure)) => {
+            Ok(Rc::new(Object::Function(Function::Compiled(Rc::new(closure.memoized())))))
+        }
+    }
+}
+
+builtin! {
+    error(message) [evaluator, source] match {
+        Object::String(message) => {
+            Err(RuntimeErr { message: message.clone(), source, trace: evaluator.get_trace() })
+        }
+    }
+}
+
+// Exposed as `try` (a reserved word in Rust), so unexpected arguments are reported under that name.
+builtin! {
+    try_fn(function) [evaluator, source] {
+        let Object::Function(function) = &**function else {
+            return Err(RuntimeErr {
+                message: format!("Unexpected argument: try({})", function.name()),
+                source,
+                trace: evaluator.get_trace(),
+            });
+        };
+
+        // The function is called without arguments, so one expecting any would only be partially applied.
+        if function.arity() != 0 {
+            return Err(RuntimeErr {
+                message: format!("Expected a Function without parameters, found one with {}", function.arity()),
+                source,
+                trace: evaluator.get_trace(),
+            });
+        }
+
+        // Frames are left in place when an error is propagated, so they are unwound to where the
+        // attempt began before the error is returned as a value.
+        let depth = evaluator.frames.len();
+        let mut result = HashMap::default();
+        match function.apply(evaluator, vec![], source) {
+            Ok(value) => {
+                result.insert(Rc::new(Object::String("value".to_owned())), value);
+            }
+            // Interruptions and exhausted step budgets halt the evaluation as a whole, whilst failed assertions must
+            // fail the test case they occur within, so neither are recoverable.
+            Err(err) if evaluator.is_halted() || evaluator.assertion_failure.is_some() => return Err(err),
+            Err(err) => {
+                evaluator.truncate_frames(depth);
+                let mut location = HashMap::default();
+                location.insert(Rc::new(Object::String("start".to_owned())), Rc::new(Object::Integer(err.source.start as i64)));
+                location.insert(Rc::new(Object::String("end".to_owned())), Rc::new(Object::Integer(err.source.end as i64)));
+                let mut error = HashMap::default();
+                error.insert(Rc::new(Object::String("message".to_owned())), Rc::new(Object::String(err.message)));
+                error.insert(Rc::new(Object::String("location".to_owned())), Rc::new(Object::Dictionary(location)));
+                result.insert(Rc::new(Object::String("error".to_owned())), Rc::new(Object::Dictionary(error)));
+            }
+        }
+        Ok(Rc::new(Object::Dictionary(result)))
     }
 }
 
+builtin! {
+    assert(condition) [evaluator, source] {
+        if condition.is_truthy() {
+            return pass_assertion(evaluator);
+        }
+        fail_assertion(evaluator, "true".to_owned(), condition.to_string(), source)
+    }
+}
+
+builtin! {
+    assert_eq(expected, actual) [evaluator, source] {
+        if expected == actual {
+            return pass_assertion(evaluator);
+        }
+        fail_assertion(evaluator, expected.to_string(), actual.to_string(), source)
+    }
+}
+
+fn pass_assertion(evaluator: &mut Evaluator) -> Result<Rc<Object>, RuntimeErr> {
+    evaluator.assertions += 1;
+    Ok(Rc::new(Object::Nil))
+}
+
+fn fail_assertion(
+    evaluator: &mut Evaluator,
+    expected: String,
+    actual: String,
+    source: Location,
+) -> Result<Rc<Object>, RuntimeErr> {
+    evaluator.assertions += 1;
+    let message = format!("Assertion failed: expected {}, actual {}", expected, actual);
+    evaluator.assertion_failure = Some(AssertionFailure {
+        expected,
+        actual,
+        source,
+        module: evaluator.modules.loading_module().cloned(),
+    });
+    Err(RuntimeErr {
+        message,
+        source,
+        trace: evaluator.get_trace(),
+    })
+}
@@ -2,9 +2,12 @@
 mod macros;
 mod bitwise;
 mod collection;
+mod grid;
 mod math;
 mod miscellaneous;
 pub mod operators;
+mod queue;
+mod search;
 mod string;
 
 builtins! {
@@ -40,6 +43,8 @@ builtins! {
     collection::repeat,
     collection::cycle,
     collection::iterate,
+    collection::cycle_detect,
+    collection::cycle_state,
     collection::keys,
     collection::values,
     collection::first,
@@ -51,6 +56,22 @@ builtins! {
     collection::rotate,
     collection::chunk,
     collection::combinations,
+    queue::priority_queue,
+    queue::enqueue,
+    queue::dequeue,
+    queue::peek,
+    search::bfs,
+    search::dijkstra,
+    search::a_star,
+    grid::grid,
+    grid::grid_filter,
+    grid::dense_grid,
+    grid::transpose,
+    grid::grid_bounds,
+    grid::grid_string,
+    grid::neighbours,
+    grid::diagonal_neighbours,
+    grid::all_neighbours,
     math::abs,
     math::vec_add,
     math::signum,
@@ -73,7 +94,10 @@ builtins! {
     string::join,
     miscellaneous::range,
     miscellaneous::id,
-    miscellaneous::memoize
+    miscellaneous::memoize,
+    miscellaneous::error,
+    miscellaneous::assert,
+    miscellaneous::assert_eq
 }
 
 builtin_aliases! {
@@ -92,5 +116,6 @@ builtin_aliases! {
     "excludes?" => collection::excludes,
     "any?" => collection::any,
     "all?" => collection::all,
-    "type" => miscellaneous::type_name
+    "type" => miscellaneous::type_name,
+    "try" => miscellaneous::try_fn
 }
@@ -1,18 +1,47 @@
 use crate::evaluator::object::Object;
 use crate::evaluator::{Evaluation, Evaluator, RuntimeErr};
 use crate::lexer::Location;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+#[inline]
+fn is_integer(object: &Object) -> bool {
+    matches!(object, Object::Integer(_) | Object::BigInteger(_))
+}
+
+#[inline]
+fn big_integers(left: &Object, right: &Object) -> (BigInt, BigInt) {
+    (left.to_big_integer().unwrap(), right.to_big_integer().unwrap())
+}
+
 #[inline]
 pub fn plus(evaluator: &mut Evaluator, left: &Rc<Object>, right: &Rc<Object>, source: Location) -> Evaluation {
     match (&**left, &**right) {
-        (Object::Integer(a), Object::Integer(b)) => Ok(Rc::new(Object::Integer(a + b))),
-        (Object::Integer(a), Object::Decimal(b)) => Ok(Rc::new(Object::Integer(a + (f64::from(*b) as i64)))),
+        (Object::Integer(a), Object::Integer(b)) => match a.checked_add(*b) {
+            Some(sum) => Ok(Rc::new(Object::Integer(sum))),
+            None => Ok(Rc::new(Object::from(BigInt::from(*a) + b))),
+        },
+        (a, b) if is_integer(a) && is_integer(b) => {
+            let (a, b) = big_integers(a, b);
+            Ok(Rc::new(Object::from(a + b)))
+        }
+        (Object::Integer(a), Object::Decimal(b)) => {
+            let b = f64::from(*b) as i64;
+            match a.checked_add(b) {
+                Some(sum) => Ok(Rc::new(Object::Integer(sum))),
+                None => Ok(Rc::new(Object::from(BigInt::from(*a) + b))),
+            }
+        }
+        (Object::BigInteger(a), Object::Decimal(b)) => Ok(Rc::new(Object::from(a + (f64::from(*b) as i64)))),
         (Object::Decimal(a), Object::Decimal(b)) => Ok(Rc::new(Object::Decimal(*a + *b))),
         (Object::Decimal(a), Object::Integer(b)) => Ok(Rc::new(Object::Decimal(a + (*b as f64)))),
+        (Object::Decimal(a), Object::BigInteger(b)) => Ok(Rc::new(Object::Decimal(a + b.to_f64().unwrap()))),
         (Object::String(a), Object::String(b)) => Ok(Rc::new(Object::String(format!("{}{}", a, b)))),
         (Object::String(a), Object::Integer(b)) => Ok(Rc::new(Object::String(format!("{}{}", a, b)))),
+        (Object::String(a), Object::BigInteger(b)) => Ok(Rc::new(Object::String(format!("{}{}", a, b)))),
         (Object::String(a), Object::Decimal(b)) => Ok(Rc::new(Object::String(format!("{}{}", a, b)))),
         (Object::List(a), Object::List(b)) => {
             let mut list = a.clone();
@@ -28,9 +57,13 @@ pub fn plus(evaluator: &mut Evaluator, left: &Rc<Object>, right: &Rc<Object>, so
         }
         (Object::List(a), Object::LazySequence(b)) => {
             let mut list = a.clone();
-            for element in b.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = b.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
                 list.push_back(Rc::clone(&element));
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::List(list)))
         }
         (Object::Set(a), Object::Set(b)) => Ok(Rc::new(Object::Set(a.clone().union(b.clone())))),
@@ -43,9 +76,13 @@ pub fn plus(evaluator: &mut Evaluator, left: &Rc<Object>, right: &Rc<Object>, so
         }
         (Object::Set(a), Object::LazySequence(b)) => {
             let mut set = a.clone();
-            for element in b.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = b.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for element in &mut iter {
                 set.insert(Rc::clone(&element));
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
             Ok(Rc::new(Object::Set(set)))
         }
         (Object::Dictionary(a), Object::Dictionary(b)) => {
@@ -72,10 +109,25 @@ builtin! {
 #[inline]
 pub fn minus(evaluator: &mut Evaluator, left: &Rc<Object>, right: &Rc<Object>, source: Location) -> Evaluation {
     match (&**left, &**right) {
-        (Object::Integer(a), Object::Integer(b)) => Ok(Rc::new(Object::Integer(a - b))),
-        (Object::Integer(a), Object::Decimal(b)) => Ok(Rc::new(Object::Integer(a - (f64::from(*b) as i64)))),
+        (Object::Integer(a), Object::Integer(b)) => match a.checked_sub(*b) {
+            Some(difference) => Ok(Rc::new(Object::Integer(difference))),
+            None => Ok(Rc::new(Object::from(BigInt::from(*a) - b))),
+        },
+        (a, b) if is_integer(a) && is_integer(b) => {
+            let (a, b) = big_integers(a, b);
+            Ok(Rc::new(Object::from(a - b)))
+        }
+        (Object::Integer(a), Object::Decimal(b)) => {
+            let b = f64::from(*b) as i64;
+            match a.checked_sub(b) {
+                Some(difference) => Ok(Rc::new(Object::Integer(difference))),
+                None => Ok(Rc::new(Object::from(BigInt::from(*a) - b))),
+            }
+        }
+        (Object::BigInteger(a), Object::Decimal(b)) => Ok(Rc::new(Object::from(a - (f64::from(*b) as i64)))),
         (Object::Decimal(a), Object::Decimal(b)) => Ok(Rc::new(Object::Decimal(*a - *b))),
         (Object::Decimal(a), Object::Integer(b)) => Ok(Rc::new(Object::Decimal(a - (*b as f64)))),
+        (Object::Decimal(a), Object::BigInteger(b)) => Ok(Rc::new(Object::Decimal(a - b.to_f64().unwrap()))),
         (Object::List(a), Object::List(b)) => {
             let mut list = a.clone();
             list.retain(|element| !b.contains(element));
@@ -90,7 +142,7 @@ pub fn minus(evaluator: &mut Evaluator, left: &Rc<Object>, right: &Rc<Object>, s
             let mut list = a.clone();
             let resolved_b = b
                 .resolve_iter(Rc::new(RefCell::new(evaluator)), source)
-                .collect::<Vec<_>>();
+                .collect_until_halted()?;
             list.retain(|element| !resolved_b.contains(element));
             Ok(Rc::new(Object::List(list)))
         }
@@ -108,7 +160,7 @@ pub fn minus(evaluator: &mut Evaluator, left: &Rc<Object>, right: &Rc<Object>, s
             let mut set = a.clone();
             let resolved_b = b
                 .resolve_iter(Rc::new(RefCell::new(evaluator)), source)
-                .collect::<Vec<_>>();
+                .collect_until_halted()?;
             set.retain(|element| !resolved_b.contains(element));
             Ok(Rc::new(Object::Set(set)))
         }
@@ -129,10 +181,25 @@ builtin! {
 #[inline]
 pub fn asterisk(left: &Rc<Object>, right: &Rc<Object>, source: Location) -> Evaluation {
     match (&**left, &**right) {
-        (Object::Integer(a), Object::Integer(b)) => Ok(Rc::new(Object::Integer(a * b))),
-        (Object::Integer(a), Object::Decimal(b)) => Ok(Rc::new(Object::Integer(a * (f64::from(*b) as i64)))),
+        (Object::Integer(a), Object::Integer(b)) => match a.checked_mul(*b) {
+            Some(product) => Ok(Rc::new(Object::Integer(product))),
+            None => Ok(Rc::new(Object::from(BigInt::from(*a) * b))),
+        },
+        (a, b) if is_integer(a) && is_integer(b) => {
+            let (a, b) = big_integers(a, b);
+            Ok(Rc::new(Object::from(a * b)))
+        }
+        (Object::Integer(a), Object::Decimal(b)) => {
+            let b = f64::from(*b) as i64;
+            match a.checked_mul(b) {
+                Some(product) => Ok(Rc::new(Object::Integer(product))),
+                None => Ok(Rc::new(Object::from(BigInt::from(*a) * b))),
+            }
+        }
+        (Object::BigInteger(a), Object::Decimal(b)) => Ok(Rc::new(Object::from(a * (f64::from(*b) as i64)))),
         (Object::Decimal(a), Object::Decimal(b)) => Ok(Rc::new(Object::Decimal(*a * *b))),
         (Object::Decimal(a), Object::Integer(b)) => Ok(Rc::new(Object::Decimal(a * (*b as f64)))),
+        (Object::Decimal(a), Object::BigInteger(b)) => Ok(Rc::new(Object::Decimal(a * b.to_f64().unwrap()))),
         (Object::String(a), Object::Integer(b)) => Ok(Rc::new(Object::String(a.repeat(*b as usize)))),
         (Object::List(a), Object::Integer(b)) => {
             let mut list = a.clone();
@@ -156,12 +223,14 @@ builtin! {
 }
 
 #[inline]
-fn floored_div(a: i64, b: i64) -> i64 {
+fn floored_div(a: i64, b: i64) -> Object {
     // Python-style floored division (floors toward negative infinity)
     // http://python-history.blogspot.com/2010/08/why-pythons-integer-division-floors.html
-    let d = a / b;
-    let r = a % b;
-    if (r != 0) && ((r < 0) != (b < 0)) { d - 1 } else { d }
+    // The only overflowing case is i64::MIN / -1, which is promoted to a BigInteger.
+    let (Some(d), Some(r)) = (a.checked_div(b), a.checked_rem(b)) else {
+        return Object::from(BigInt::from(a).div_floor(&BigInt::from(b)));
+    };
+    Object::Integer(if (r != 0) && ((r < 0) != (b < 0)) { d - 1 } else { d })
 }
 
 #[inline]
@@ -175,7 +244,18 @@ pub fn slash(left: &Rc<Object>, right: &Rc<Object>, source: Location) -> Evaluat
                     trace: vec![],
                 });
             }
-            Ok(Rc::new(Object::Integer(floored_div(*a, *b))))
+            Ok(Rc::new(floored_div(*a, *b)))
+        }
+        (a, b) if is_integer(a) && is_integer(b) => {
+            let (a, b) = big_integers(a, b);
+            if b.is_zero() {
+                return Err(RuntimeErr {
+                    message: "Division by zero".to_string(),
+                    source,
+                    trace: vec![],
+                });
+            }
+            Ok(Rc::new(Object::from(a.div_floor(&b))))
         }
         (Object::Integer(a), Object::Decimal(b)) => {
             if f64::from(*b) == 0.0 {
@@ -234,7 +314,7 @@ pub fn modulo(left: &Rc<Object>, right: &Rc<Object>, source: Location) -> Evalua
                 });
             }
             // http://python-history.blogspot.com/2010/08/why-pythons-integer-division-floors.html
-            let remainder = a % b;
+            let remainder = a.checked_rem(*b).unwrap_or(0);
             let result = if remainder == 0 || a.signum() == b.signum() {
                 remainder
             } else {
@@ -242,6 +322,17 @@ pub fn modulo(left: &Rc<Object>, right: &Rc<Object>, source: Location) -> Evalua
             };
             Ok(Rc::new(Object::Integer(result)))
         }
+        (a, b) if is_integer(a) && is_integer(b) => {
+            let (a, b) = big_integers(a, b);
+            if b.is_zero() {
+                return Err(RuntimeErr {
+                    message: "Division by zero".to_string(),
+                    source,
+                    trace: vec![],
+                });
+            }
+            Ok(Rc::new(Object::from(a.mod_floor(&b))))
+        }
         _ => Err(RuntimeErr {
             message: format!("Unsupported operation: {} % {}", left.name(), right.name()),
             source,
@@ -0,0 +1,66 @@
+use crate::evaluator::RuntimeErr;
+use crate::evaluator::object::Object;
+use crate::evaluator::priority_queue::PriorityQueue;
+use std::rc::Rc;
+
+builtin! {
+    priority_queue(entries) [evaluator, source] match {
+        Object::List(list) => {
+            let mut queue = PriorityQueue::default();
+            for entry in list {
+                match &**entry {
+                    Object::List(pair) if pair.len() == 2 => {
+                        queue = queue.enqueue(Rc::clone(&pair[0]), Rc::clone(&pair[1]));
+                    }
+                    _ => {
+                        return Err(RuntimeErr {
+                            message: format!("Expected a [value, priority] pair, found: {}", entry),
+                            source,
+                            trace: evaluator.get_trace()
+                        });
+                    }
+                }
+            }
+            Ok(Rc::new(Object::PriorityQueue(queue)))
+        }
+        Object::PriorityQueue(queue) => {
+            Ok(Rc::new(Object::PriorityQueue(queue.clone())))
+        }
+    }
+}
+
+builtin! {
+    enqueue(value, priority, queue) match {
+        (_, _, Object::PriorityQueue(queue)) => {
+            Ok(Rc::new(Object::PriorityQueue(queue.enqueue(Rc::clone(value), Rc::clone(priority)))))
+        }
+    }
+}
+
+builtin! {
+    dequeue(queue) [evaluator, source] match {
+        Object::PriorityQueue(queue) => {
+            match queue.dequeue() {
+                Some((value, rest)) => {
+                    Ok(Rc::new(Object::List(vec![value, Rc::new(Object::PriorityQueue(rest))].into())))
+                }
+                None => Err(RuntimeErr {
+                    message: "Unable to dequeue from an empty PriorityQueue".to_owned(),
+                    source,
+                    trace: evaluator.get_trace()
+                })
+            }
+        }
+    }
+}
+
+builtin! {
+    peek(queue) match {
+        Object::PriorityQueue(queue) => {
+            match queue.peek() {
+                Some(value) => Ok(Rc::clone(value)),
+                None => Ok(Rc::new(Object::Nil))
+            }
+        }
+    }
+}
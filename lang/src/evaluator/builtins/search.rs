@@ -0,0 +1,241 @@
+use crate::evaluator::function::Function;
+use crate::evaluator::object::Object;
+use crate::evaluator::{Evaluation, Evaluator, RuntimeErr};
+use crate::lexer::Location;
+use im_rc::{HashMap, Vector};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::rc::Rc;
+
+builtin! {
+    bfs(start, neighbours, goal) [evaluator, source] match {
+        (_, Object::Function(neighbours), _) => {
+            search(evaluator, source, start, &Search { neighbours, goal, heuristic: None, weighted: false })
+        }
+    }
+}
+
+builtin! {
+    dijkstra(start, neighbours, goal) [evaluator, source] match {
+        (_, Object::Function(neighbours), _) => {
+            search(evaluator, source, start, &Search { neighbours, goal, heuristic: None, weighted: true })
+        }
+    }
+}
+
+builtin! {
+    a_star(start, neighbours, goal, heuristic) [evaluator, source] match {
+        (_, Object::Function(neighbours), _, Object::Function(heuristic)) => {
+            search(evaluator, source, start, &Search { neighbours, goal, heuristic: Some(heuristic), weighted: true })
+        }
+    }
+}
+
+// The best known distance to each discovered state, along with the state it was reached from.
+type Predecessors = std::collections::HashMap<Rc<Object>, (i64, Option<Rc<Object>>)>;
+type Visited =
+    HashMap<Rc<Object>, Rc<Object>, std::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>>;
+
+struct Search<'a> {
+    neighbours: &'a Function,
+    goal: &'a Object,
+    heuristic: Option<&'a Function>,
+    weighted: bool,
+}
+
+// Each search expands states in order of their distance (plus heuristic estimate) from the start, with ties broken
+// by discovery order so that an unweighted search behaves as a breadth-first search. States are settled when they are
+// expanded, with the resulting `visited` map holding the distance of each settled state. A settled state is reopened
+// if a shorter distance to it is later found, which can only occur with an admissible but inconsistent heuristic.
+fn search(evaluator: &mut Evaluator, source: Location, start: &Rc<Object>, search: &Search) -> Evaluation {
+    expect_hashable(evaluator, source, start)?;
+
+    #[allow(clippy::mutable_key_type)]
+    let mut best: Predecessors = std::collections::HashMap::new();
+    let mut frontier = BinaryHeap::new();
+    let mut discovered: u64 = 0;
+    let mut visited: Visited = HashMap::default();
+
+    best.insert(Rc::clone(start), (0, None));
+    frontier.push(Reverse((
+        estimate(evaluator, source, search, start, 0)?,
+        discovered,
+        Rc::clone(start),
+    )));
+
+    while let Some(Reverse((_, _, state))) = frontier.pop() {
+        let distance = best[&state].0;
+        if visited
+            .get(&state)
+            .is_some_and(|settled| **settled == Object::Integer(distance))
+        {
+            continue;
+        }
+
+        visited.insert(Rc::clone(&state), Rc::new(Object::Integer(distance)));
+
+        if is_goal(evaluator, source, search.goal, &state)? {
+            return Ok(result(Some((distance, path(&best, &state))), visited));
+        }
+
+        for (neighbour, cost) in expand(evaluator, source, search, &state)? {
+            let next = add_cost(evaluator, source, distance, cost)?;
+            if best.get(&neighbour).is_some_and(|(known, _)| *known <= next) {
+                continue;
+            }
+            best.insert(Rc::clone(&neighbour), (next, Some(Rc::clone(&state))));
+            discovered += 1;
+            frontier.push(Reverse((
+                estimate(evaluator, source, search, &neighbour, next)?,
+                discovered,
+                neighbour,
+            )));
+        }
+    }
+
+    Ok(result(None, visited))
+}
+
+fn expand(
+    evaluator: &mut Evaluator,
+    source: Location,
+    search: &Search,
+    state: &Rc<Object>,
+) -> Result<Vec<(Rc<Object>, i64)>, RuntimeErr> {
+    let neighbours = search.neighbours.apply(evaluator, vec![Rc::clone(state)], source)?;
+    let neighbours: Vec<Rc<Object>> = match &*neighbours {
+        Object::List(list) => list.iter().cloned().collect(),
+        Object::Set(set) => set.iter().cloned().collect(),
+        neighbours => {
+            return Err(RuntimeErr {
+                message: format!("Expected neighbours to be a List or Set, found: {}", neighbours.name()),
+                source,
+                trace: evaluator.get_trace(),
+            });
+        }
+    };
+
+    let mut expanded = Vec::with_capacity(neighbours.len());
+    for neighbour in neighbours {
+        let (neighbour, cost) = if search.weighted {
+            match &*neighbour {
+                Object::List(pair) if pair.len() == 2 => {
+                    (Rc::clone(&pair[0]), expect_cost(evaluator, source, &pair[1])?)
+                }
+                _ => {
+                    return Err(RuntimeErr {
+                        message: format!("Expected a [state, cost] pair, found: {}", neighbour),
+                        source,
+                        trace: evaluator.get_trace(),
+                    });
+                }
+            }
+        } else {
+            (neighbour, 1)
+        };
+        expect_hashable(evaluator, source, &neighbour)?;
+        expanded.push((neighbour, cost));
+    }
+    Ok(expanded)
+}
+
+fn estimate(
+    evaluator: &mut Evaluator,
+    source: Location,
+    search: &Search,
+    state: &Rc<Object>,
+    distance: i64,
+) -> Result<i64, RuntimeErr> {
+    match search.heuristic {
+        Some(heuristic) => {
+            let estimate = heuristic.apply(evaluator, vec![Rc::clone(state)], source)?;
+            let estimate = expect_cost(evaluator, source, &estimate)?;
+            add_cost(evaluator, source, distance, estimate)
+        }
+        None => Ok(distance),
+    }
+}
+
+fn is_goal(evaluator: &mut Evaluator, source: Location, goal: &Object, state: &Rc<Object>) -> Result<bool, RuntimeErr> {
+    match goal {
+        Object::Function(predicate) => Ok(predicate.apply(evaluator, vec![Rc::clone(state)], source)?.is_truthy()),
+        goal => Ok(goal == &**state),
+    }
+}
+
+#[allow(clippy::mutable_key_type)]
+fn path(best: &Predecessors, goal: &Rc<Object>) -> Vector<Rc<Object>> {
+    let mut path = Vector::new();
+    let mut current = Some(Rc::clone(goal));
+    while let Some(state) = current {
+        current = best[&state].1.clone();
+        path.push_front(state);
+    }
+    path
+}
+
+fn result(found: Option<(i64, Vector<Rc<Object>>)>, visited: Visited) -> Rc<Object> {
+    let (distance, path) = match found {
+        Some((distance, path)) => (Object::Integer(distance), Object::List(path)),
+        None => (Object::Nil, Object::Nil),
+    };
+
+    let mut result = HashMap::default();
+    result.insert(Rc::new(Object::String("distance".to_owned())), Rc::new(distance));
+    result.insert(Rc::new(Object::String("path".to_owned())), Rc::new(path));
+    result.insert(
+        Rc::new(Object::String("visited".to_owned())),
+        Rc::new(Object::Dictionary(visited)),
+    );
+    Rc::new(Object::Dictionary(result))
+}
+
+fn expect_cost(evaluator: &Evaluator, source: Location, cost: &Object) -> Result<i64, RuntimeErr> {
+    match cost {
+        Object::Integer(cost) if *cost >= 0 => Ok(*cost),
+        Object::Integer(cost) => Err(RuntimeErr {
+            message: format!("Expected a non-negative cost, found: {}", cost),
+            source,
+            trace: evaluator.get_trace(),
+        }),
+        Object::BigInteger(cost) => Err(RuntimeErr {
+            message: format!("Search cost exceeds the Integer range, found: {}", cost),
+            source,
+            trace: evaluator.get_trace(),
+        }),
+        cost => Err(RuntimeErr {
+            message: format!("Expected an Integer cost, found: {}", cost.name()),
+            source,
+            trace: evaluator.get_trace(),
+        }),
+    }
+}
+
+fn add_cost(evaluator: &Evaluator, source: Location, distance: i64, cost: i64) -> Result<i64, RuntimeErr> {
+    distance.checked_add(cost).ok_or_else(|| RuntimeErr {
+        message: "Search distance exceeds the Integer range".to_owned(),
+        source,
+        trace: evaluator.get_trace(),
+    })
+}
+
+fn expect_hashable(evaluator: &Evaluator, source: Location, state: &Object) -> Result<(), RuntimeErr> {
+    if is_hashable_state(state) {
+        return Ok(());
+    }
+    Err(RuntimeErr {
+        message: format!("Unable to use a {} as a search state", state.name()),
+        source,
+        trace: evaluator.get_trace(),
+    })
+}
+
+// Unlike Set elements and Dictionary keys, search states may also be Dictionaries (e.g. `#{"pos": [0, 0]}`).
+fn is_hashable_state(state: &Object) -> bool {
+    match state {
+        Object::Dictionary(map) => map
+            .iter()
+            .all(|(key, value)| key.is_hashable() && is_hashable_state(value)),
+        state => state.is_hashable(),
+    }
+}
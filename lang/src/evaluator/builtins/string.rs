@@ -1,6 +1,7 @@
 use crate::evaluator::RuntimeErr;
 use crate::evaluator::object::Object;
 use im_rc::Vector;
+use num_bigint::BigInt;
 use ordered_float::OrderedFloat;
 use regex::Regex;
 use std::rc::Rc;
@@ -14,6 +15,9 @@ builtin! {
         Object::Integer(value) => {
             Ok(Rc::new(Object::Integer(*value)))
         }
+        Object::BigInteger(value) => {
+            Ok(Rc::new(Object::BigInteger(value.clone())))
+        }
         Object::Decimal(OrderedFloat(value)) => {
             Ok(Rc::new(Object::Integer(value.round() as i64)))
         }
@@ -22,6 +26,10 @@ builtin! {
                 return Ok(Rc::new(Object::Integer(parsed)));
             }
 
+            if let Ok(parsed) = value.trim().parse::<BigInt>() {
+                return Ok(Rc::new(Object::from(parsed)));
+            }
+
             if let Ok(parsed) = value.trim().parse::<f64>() {
                 return Ok(Rc::new(Object::Integer(parsed.round() as i64)))
             }
@@ -40,6 +48,8 @@ builtin! {
             for capture in pattern.captures_iter(value) {
                 if let Ok(parsed) = capture[0].parse::<i64>() {
                     ints.push_back(Rc::new(Object::Integer(parsed)));
+                } else if let Ok(parsed) = capture[0].parse::<BigInt>() {
+                    ints.push_back(Rc::new(Object::from(parsed)));
                 }
             }
 
@@ -184,7 +194,9 @@ builtin! {
             let evaluator_cell = RefCell::new(evaluator);
             let strings: Vec<String> = sequence
                 .resolve_iter(Rc::new(evaluator_cell), source)
-                .map(|item| object_to_unquoted_string(&item))
+                .collect_until_halted()?
+                .iter()
+                .map(|item| object_to_unquoted_string(item))
                 .collect();
             Ok(Rc::new(Object::String(strings.join(separator))))
         }
@@ -5,10 +5,16 @@ use std::rc::Rc;
 
 pub type EnvironmentRef = Rc<RefCell<Environment>>;
 
+type Variable = (String, Rc<Object>, bool);
+
+// Variables declared by resolved identifiers are addressed by their slot, whilst those declared by name
+// (i.e. imports and host-provided values) are kept apart within the store, so the two never collide.
 #[derive(Debug, Clone)]
 pub struct Environment {
-    store: Vec<(String, Rc<Object>, bool)>,
+    slots: Vec<Option<Variable>>,
+    store: Vec<Variable>,
     sections: Vec<(String, Rc<Section>, Vec<Attribute>)>,
+    binding_attributes: Vec<(String, Vec<Attribute>)>,
     outer: Option<EnvironmentRef>,
 }
 
@@ -19,20 +25,28 @@ pub struct EnvironmentErr {
 impl Environment {
     pub fn new() -> EnvironmentRef {
         Rc::new(RefCell::new(Environment {
+            slots: vec![],
             store: vec![],
             sections: vec![],
+            binding_attributes: vec![],
             outer: None,
         }))
     }
 
     pub fn from(outer: EnvironmentRef) -> EnvironmentRef {
         Rc::new(RefCell::new(Environment {
+            slots: vec![],
             store: vec![],
             sections: vec![],
+            binding_attributes: vec![],
             outer: Some(outer),
         }))
     }
 
+    pub fn copy(environment: &EnvironmentRef) -> EnvironmentRef {
+        Rc::new(RefCell::new(environment.borrow().clone()))
+    }
+
     pub fn get_sections(&self, name: &str) -> Vec<Rc<Section>> {
         self.sections
             .iter()
@@ -61,21 +75,61 @@ impl Environment {
         attributes.iter().any(|a| a.name == attr_name)
     }
 
+    pub fn add_binding_attributes(&mut self, name: &str, attributes: Vec<Attribute>) {
+        self.binding_attributes.push((name.to_owned(), attributes))
+    }
+
+    pub fn get_binding_attributes(&self, name: &str) -> Vec<Attribute> {
+        if let Some((_, attributes)) = self.binding_attributes.iter().find(|(name_, _)| name_ == name) {
+            return attributes.clone();
+        }
+
+        match &self.outer {
+            Some(outer) => outer.borrow().get_binding_attributes(name),
+            None => vec![],
+        }
+    }
+
+    pub fn section_attribute<'a>(attributes: &'a [Attribute], attr_name: &str) -> Option<&'a Attribute> {
+        attributes.iter().find(|a| a.name == attr_name)
+    }
+
     pub fn declare_variable(&mut self, name: &str, value: Rc<Object>, mutable: bool) -> Result<(), EnvironmentErr> {
-        for (name_, _, _) in &self.store {
-            if name_ == name {
-                return Err(EnvironmentErr {
-                    message: format!("Variable '{}' has already been declared", name),
-                });
-            }
+        if self.is_declared(name) {
+            return Err(EnvironmentErr {
+                message: format!("Variable '{}' has already been declared", name),
+            });
         }
 
         self.store.push((name.to_owned(), value, mutable));
         Ok(())
     }
 
+    pub fn declare_slot(
+        &mut self,
+        slot: usize,
+        name: &str,
+        value: Rc<Object>,
+        mutable: bool,
+    ) -> Result<(), EnvironmentErr> {
+        if self.slots.get(slot).is_some_and(|variable| variable.is_some())
+            || self.store.iter().any(|(name_, _, _)| name_ == name)
+        {
+            return Err(EnvironmentErr {
+                message: format!("Variable '{}' has already been declared", name),
+            });
+        }
+
+        if slot >= self.slots.len() {
+            self.slots.resize(slot + 1, None);
+        }
+
+        self.slots[slot] = Some((name.to_owned(), value, mutable));
+        Ok(())
+    }
+
     pub fn get_variable(&self, name: &str) -> Option<Rc<Object>> {
-        for (name_, value, _) in &self.store {
+        for (name_, value, _) in self.variables_iter() {
             if name_ == name {
                 return Some(Rc::clone(value));
             }
@@ -88,18 +142,34 @@ impl Environment {
         None
     }
 
+    pub fn get_slot(&self, depth: usize, slot: usize) -> Option<Rc<Object>> {
+        if depth > 0 {
+            return self.outer.as_ref()?.borrow().get_slot(depth - 1, slot);
+        }
+
+        match self.slots.get(slot) {
+            Some(Some((_, value, _))) => Some(Rc::clone(value)),
+            _ => None,
+        }
+    }
+
     pub fn assign_variable(&mut self, name: &str, value: Rc<Object>) -> Result<(), EnvironmentErr> {
-        for (name_, value_, mutable) in self.store.iter_mut() {
-            if *name_ == name {
-                if !*mutable {
-                    return Err(EnvironmentErr {
-                        message: format!("Variable '{}' is not mutable", name),
-                    });
-                }
+        let variable = self
+            .slots
+            .iter_mut()
+            .flatten()
+            .chain(self.store.iter_mut())
+            .find(|(name_, _, _)| name_ == name);
 
-                *value_ = value;
-                return Ok(());
+        if let Some((_, value_, mutable)) = variable {
+            if !*mutable {
+                return Err(EnvironmentErr {
+                    message: format!("Variable '{}' is not mutable", name),
+                });
             }
+
+            *value_ = value;
+            return Ok(());
         }
 
         if let Some(outer) = &self.outer {
@@ -111,6 +181,36 @@ impl Environment {
         })
     }
 
+    // Slots which have been resolved but not yet declared (i.e. referenced ahead of their `let`) fall back
+    // to looking the variable up by name, matching how it would have been found prior to resolution.
+    pub fn assign_slot(
+        &mut self,
+        depth: usize,
+        slot: usize,
+        name: &str,
+        value: Rc<Object>,
+    ) -> Result<(), EnvironmentErr> {
+        if depth > 0 {
+            if let Some(outer) = &self.outer {
+                return outer.borrow_mut().assign_slot(depth - 1, slot, name, value);
+            }
+        }
+
+        match self.slots.get_mut(slot) {
+            Some(Some((_, value_, mutable))) => {
+                if !*mutable {
+                    return Err(EnvironmentErr {
+                        message: format!("Variable '{}' is not mutable", name),
+                    });
+                }
+
+                *value_ = value;
+                Ok(())
+            }
+            _ => self.assign_variable(name, value),
+        }
+    }
+
     pub fn set_variable(&mut self, name: &str, value: Rc<Object>) {
         for (name_, value_, _) in self.store.iter_mut() {
             if *name_ == name {
@@ -122,10 +222,39 @@ impl Environment {
         self.store.push((name.to_owned(), value, false));
     }
 
-    pub fn variables(&self) -> Vec<(String, Rc<Object>)> {
-        self.store
+    pub fn set_slot(&mut self, slot: usize, name: &str, value: Rc<Object>) {
+        if slot >= self.slots.len() {
+            self.slots.resize(slot + 1, None);
+        }
+
+        self.slots[slot] = Some((name.to_owned(), value, false));
+    }
+
+    pub fn slot_of(&self, name: &str) -> Option<usize> {
+        self.slots
             .iter()
+            .position(|variable| matches!(variable, Some((name_, _, _)) if name_ == name))
+    }
+
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_declared(&self, name: &str) -> bool {
+        self.variables_iter().any(|(name_, _, _)| name_ == name)
+    }
+
+    pub fn outer(&self) -> Option<EnvironmentRef> {
+        self.outer.as_ref().map(Rc::clone)
+    }
+
+    pub fn variables(&self) -> Vec<(String, Rc<Object>)> {
+        self.variables_iter()
             .map(|(name, value, _)| (name.clone(), Rc::clone(value)))
             .collect()
     }
+
+    fn variables_iter(&self) -> impl Iterator<Item = &Variable> {
+        self.slots.iter().flatten().chain(self.store.iter())
+    }
 }
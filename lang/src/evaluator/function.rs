@@ -1,7 +1,8 @@
 use super::environment::{Environment, EnvironmentRef};
+use crate::evaluator::vm;
 use crate::evaluator::{Evaluation, Evaluator, Frame, Object, RuntimeErr};
 use crate::lexer::Location;
-use crate::parser::ast::{Expression, ExpressionKind, Statement};
+use crate::parser::ast::{Expression, ExpressionKind, Statement, TypeVariant};
 use im_rc::Vector;
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -9,12 +10,18 @@ use std::fmt;
 use std::hash::Hash;
 use std::rc::Rc;
 
-pub type Arguments = HashMap<String, Rc<Object>>;
+pub type Arguments = [Rc<Object>];
 pub type ExternalFnDef = (String, Vec<ExpressionKind>, ExternalFn);
 
-type BuiltinFn = fn(&mut Evaluator, Arguments, Location) -> Evaluation;
-type ExternalFn = Rc<dyn Fn(Arguments, Location) -> Evaluation>;
-type MemoizedCache = Rc<RefCell<HashMap<Vec<Rc<Object>>, Rc<Object>>>>;
+type BuiltinFn = fn(&mut Evaluator, &Arguments, Location) -> Evaluation;
+type ExternalFn = Rc<dyn Fn(&Arguments, Location) -> Evaluation>;
+type PartialArguments = Vec<Option<Rc<Object>>>;
+
+enum InternalArguments {
+    Complete(Vec<Rc<Object>>),
+    Partial(PartialArguments),
+}
+pub(crate) type MemoizedCache = Rc<RefCell<HashMap<Vec<Rc<Object>>, Rc<Object>>>>;
 
 #[derive(Clone)]
 pub enum Function {
@@ -22,40 +29,66 @@ pub enum Function {
         parameters: Vec<Expression>,
         body: Statement,
         environment: EnvironmentRef,
+        partial: Option<EnvironmentRef>,
     },
     MemoizedClosure {
         parameters: Vec<Expression>,
         body: Statement,
         environment: EnvironmentRef,
+        partial: Option<EnvironmentRef>,
         cache: MemoizedCache,
     },
     Builtin {
         parameters: Vec<ExpressionKind>,
         body: BuiltinFn,
-        partial: Option<Arguments>,
+        partial: Option<PartialArguments>,
     },
     External {
         parameters: Vec<ExpressionKind>,
         body: ExternalFn,
-        partial: Option<Arguments>,
+        partial: Option<PartialArguments>,
+    },
+    Constructor {
+        type_name: Rc<str>,
+        name: Rc<str>,
+        parameters: Vec<ExpressionKind>,
+        partial: Option<PartialArguments>,
     },
+    Compiled(Rc<vm::Closure>),
     Composition {
         functions: Vec<Function>,
     },
     Continuation {
+        function: Rc<Object>,
         arguments: Vec<Rc<Object>>,
+        source: Location,
     },
 }
 
+// Applying a function recurses on the native stack, which is grown on demand so that deep recursion is bounded
+// by the evaluator's frame depth rather than the stack size of the host (i.e. a PHP runtime).
+// Growing is not supported on WASM, where `DEFAULT_MAX_FRAME_DEPTH` is lowered instead.
+const STACK_RED_ZONE: usize = 1024 * 1024;
+const STACK_SEGMENT_SIZE: usize = 16 * 1024 * 1024;
+
+pub fn with_growable_stack<R>(callback: impl FnOnce() -> R) -> R {
+    stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT_SIZE, callback)
+}
+
 impl Function {
     pub fn apply(&self, evaluator: &mut Evaluator, arguments: Vec<Rc<Object>>, source: Location) -> Evaluation {
+        with_growable_stack(|| self.apply_on_stack(evaluator, arguments, source))
+    }
+
+    fn apply_on_stack(&self, evaluator: &mut Evaluator, arguments: Vec<Rc<Object>>, source: Location) -> Evaluation {
         match self {
             Self::Closure {
                 parameters,
                 body,
                 environment,
+                partial,
             } => {
-                let enclosed_environment = Environment::from(Rc::clone(environment));
+                let enclosed_environment = Self::parameter_environment(environment, partial);
                 let remaining_parameters =
                     self.assign_closure_parameters(Rc::clone(&enclosed_environment), parameters, &arguments)?;
 
@@ -63,37 +96,18 @@ impl Function {
                     return Ok(Rc::new(Object::Function(Self::Closure {
                         parameters: remaining_parameters,
                         body: body.clone(),
-                        environment: enclosed_environment,
+                        environment: Rc::clone(environment),
+                        partial: Some(enclosed_environment),
                     })));
                 }
 
                 evaluator.push_frame(Frame::ClosureCall {
                     source,
                     environment: Rc::clone(&enclosed_environment),
-                });
-
-                let mut result = evaluator.eval_statement(body)?;
-
-                loop {
-                    if let Object::Function(Function::Continuation { arguments }) = &*result {
-                        let remaining_parameters =
-                            self.assign_closure_parameters(Rc::clone(&enclosed_environment), parameters, arguments)?;
-
-                        if !remaining_parameters.is_empty() {
-                            result = Rc::new(Object::Function(Self::Closure {
-                                parameters: remaining_parameters,
-                                body: body.clone(),
-                                environment: enclosed_environment,
-                            }));
-                            break;
-                        }
-
-                        result = evaluator.eval_statement(body)?;
-                        continue;
-                    }
+                })?;
 
-                    break;
-                }
+                let result = evaluator.eval_statement(body, true)?;
+                let result = Self::resolve_tail_calls(evaluator, result)?;
 
                 evaluator.pop_frame();
 
@@ -109,13 +123,14 @@ impl Function {
                 parameters,
                 body,
                 environment,
+                partial,
                 cache,
             } => {
                 if let Some(result) = cache.borrow().get(&arguments) {
                     return Ok(Rc::clone(result));
                 }
 
-                let enclosed_environment = Environment::from(Rc::clone(environment));
+                let enclosed_environment = Self::parameter_environment(environment, partial);
                 let remaining_parameters =
                     self.assign_closure_parameters(Rc::clone(&enclosed_environment), parameters, &arguments)?;
 
@@ -123,37 +138,18 @@ impl Function {
                     return Ok(Rc::new(Object::Function(Self::Closure {
                         parameters: remaining_parameters,
                         body: body.clone(),
-                        environment: enclosed_environment,
+                        environment: Rc::clone(environment),
+                        partial: Some(enclosed_environment),
                     })));
                 }
 
                 evaluator.push_frame(Frame::ClosureCall {
                     source,
                     environment: Rc::clone(&enclosed_environment),
-                });
-
-                let mut result = evaluator.eval_statement(body)?;
+                })?;
 
-                loop {
-                    if let Object::Function(Function::Continuation { arguments }) = &*result {
-                        let remaining_parameters =
-                            self.assign_closure_parameters(Rc::clone(&enclosed_environment), parameters, arguments)?;
-
-                        if !remaining_parameters.is_empty() {
-                            result = Rc::new(Object::Function(Self::Closure {
-                                parameters: remaining_parameters,
-                                body: body.clone(),
-                                environment: enclosed_environment,
-                            }));
-                            break;
-                        }
-
-                        result = evaluator.eval_statement(body)?;
-                        continue;
-                    }
-
-                    break;
-                }
+                let result = evaluator.eval_statement(body, true)?;
+                let result = Self::resolve_tail_calls(evaluator, result)?;
 
                 evaluator.pop_frame();
 
@@ -172,25 +168,20 @@ impl Function {
                 body,
                 partial,
             } => {
-                let mut evaluated_arguments = match partial {
-                    Some(args) => args.clone(),
-                    None => HashMap::new(),
+                let evaluated_arguments = match Self::assign_internal_parameters(parameters, partial, arguments) {
+                    InternalArguments::Complete(evaluated_arguments) => evaluated_arguments,
+                    InternalArguments::Partial(partial) => {
+                        return Ok(Rc::new(Object::Function(Self::Builtin {
+                            parameters: parameters.clone(),
+                            body: *body,
+                            partial: Some(partial),
+                        })));
+                    }
                 };
 
-                let remaining_parameters =
-                    self.assign_interal_parameters(&mut evaluated_arguments, parameters, &arguments)?;
-
-                if !remaining_parameters.is_empty() {
-                    return Ok(Rc::new(Object::Function(Self::Builtin {
-                        parameters: remaining_parameters,
-                        body: *body,
-                        partial: Some(evaluated_arguments),
-                    })));
-                }
-
-                evaluator.push_frame(Frame::BuiltinCall { source });
+                evaluator.push_frame(Frame::BuiltinCall { source })?;
 
-                let result = body(evaluator, evaluated_arguments, source)?;
+                let result = body(evaluator, &evaluated_arguments, source)?;
 
                 evaluator.pop_frame();
 
@@ -207,25 +198,20 @@ impl Function {
                 body,
                 partial,
             } => {
-                let mut evaluated_arguments = match partial {
-                    Some(args) => args.clone(),
-                    None => HashMap::new(),
+                let evaluated_arguments = match Self::assign_internal_parameters(parameters, partial, arguments) {
+                    InternalArguments::Complete(evaluated_arguments) => evaluated_arguments,
+                    InternalArguments::Partial(partial) => {
+                        return Ok(Rc::new(Object::Function(Self::External {
+                            parameters: parameters.clone(),
+                            body: Rc::clone(body),
+                            partial: Some(partial),
+                        })));
+                    }
                 };
 
-                let remaining_parameters =
-                    self.assign_interal_parameters(&mut evaluated_arguments, parameters, &arguments)?;
-
-                if !remaining_parameters.is_empty() {
-                    return Ok(Rc::new(Object::Function(Self::External {
-                        parameters: remaining_parameters,
-                        body: Rc::clone(body),
-                        partial: Some(evaluated_arguments),
-                    })));
-                }
+                evaluator.push_frame(Frame::ExternalCall { source })?;
 
-                evaluator.push_frame(Frame::ExternalCall { source });
-
-                let result = body(evaluated_arguments, source)?;
+                let result = body(&evaluated_arguments, source)?;
 
                 evaluator.pop_frame();
 
@@ -237,6 +223,25 @@ impl Function {
 
                 Ok(returned_result)
             }
+            Self::Constructor {
+                type_name,
+                name,
+                parameters,
+                partial,
+            } => match Self::assign_internal_parameters(parameters, partial, arguments) {
+                InternalArguments::Complete(fields) => Ok(Rc::new(Object::Variant {
+                    type_name: Rc::clone(type_name),
+                    name: Rc::clone(name),
+                    fields: fields.into(),
+                })),
+                InternalArguments::Partial(partial) => Ok(Rc::new(Object::Function(Self::Constructor {
+                    type_name: Rc::clone(type_name),
+                    name: Rc::clone(name),
+                    parameters: parameters.clone(),
+                    partial: Some(partial),
+                }))),
+            },
+            Self::Compiled(closure) => vm::call(evaluator, closure, arguments, source),
             Self::Composition { functions } => {
                 let mut result = Rc::clone(&arguments[0]);
 
@@ -249,10 +254,137 @@ impl Function {
 
                 Ok(result)
             }
-            Self::Continuation { .. } => unreachable!(),
+            // Continuations are resolved by the closure which returns them, yet should one escape (i.e. be handed to a
+            // caller as a value) the pending call is resolved before its result is applied.
+            Self::Continuation { .. } => {
+                let resolved = Self::resolve_tail_calls(evaluator, Rc::new(Object::Function(self.clone())))?;
+                let resolved = match &*resolved {
+                    Object::Return(value) => Rc::clone(value),
+                    _ => resolved,
+                };
+                match &*resolved {
+                    Object::Function(function) => function.apply(evaluator, arguments, source),
+                    _ => Err(RuntimeErr {
+                        message: format!("Expected a Function, found: {}", resolved.name()),
+                        source,
+                        trace: evaluator.get_trace(),
+                    }),
+                }
+            }
         }
     }
 
+    // Memoized closures reached through a tail call evaluate to the result of the whole chain of tail calls,
+    // so that result is cached against each of their arguments once the chain has been resolved.
+    fn resolve_tail_calls(evaluator: &mut Evaluator, mut result: Rc<Object>) -> Evaluation {
+        let mut memoized: Vec<(MemoizedCache, Vec<Rc<Object>>)> = vec![];
+
+        let result = loop {
+            let continuation = match &*result {
+                Object::Return(value) => Rc::clone(value),
+                _ => Rc::clone(&result),
+            };
+            let Object::Function(Self::Continuation {
+                function,
+                arguments,
+                source,
+            }) = &*continuation
+            else {
+                break result;
+            };
+            let Object::Function(function) = &**function else {
+                unreachable!()
+            };
+
+            let (parameters, body, environment, partial) = match function {
+                Self::Closure {
+                    parameters,
+                    body,
+                    environment,
+                    partial,
+                } => (parameters, body, environment, partial),
+                Self::MemoizedClosure {
+                    parameters,
+                    body,
+                    environment,
+                    partial,
+                    cache,
+                } => {
+                    if let Some(cached) = cache.borrow().get(arguments) {
+                        break Rc::clone(cached);
+                    }
+                    memoized.push((Rc::clone(cache), arguments.clone()));
+                    (parameters, body, environment, partial)
+                }
+                _ => break function.apply(evaluator, arguments.clone(), *source)?,
+            };
+
+            let enclosed_environment = Self::parameter_environment(environment, partial);
+            let remaining_parameters =
+                function.assign_closure_parameters(Rc::clone(&enclosed_environment), parameters, arguments)?;
+
+            if !remaining_parameters.is_empty() {
+                break Rc::new(Object::Function(Self::Closure {
+                    parameters: remaining_parameters,
+                    body: body.clone(),
+                    environment: Rc::clone(environment),
+                    partial: Some(enclosed_environment),
+                }));
+            }
+
+            evaluator.pop_frame();
+            evaluator.push_frame(Frame::ClosureCall {
+                source: *source,
+                environment: enclosed_environment,
+            })?;
+            result = evaluator.eval_statement(body, true)?;
+        };
+
+        if !memoized.is_empty() {
+            let value = match &*result {
+                Object::Return(value) => Rc::clone(value),
+                _ => Rc::clone(&result),
+            };
+            for (cache, arguments) in memoized {
+                cache.borrow_mut().insert(arguments, Rc::clone(&value));
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Partially applied arguments are bound within a copy of the same environment the remaining parameters
+    // are assigned to, so that every parameter is addressed at the depth the resolver expects.
+    fn parameter_environment(environment: &EnvironmentRef, partial: &Option<EnvironmentRef>) -> EnvironmentRef {
+        match partial {
+            Some(partial) => Environment::copy(partial),
+            None => Environment::from(Rc::clone(environment)),
+        }
+    }
+
+    /// Returns the value bound to a declared type variant; a constructor function for those with fields,
+    /// or the value itself for those without.
+    pub fn constructor(type_name: &str, variant: &TypeVariant) -> Object {
+        if variant.fields.is_empty() {
+            return Object::Variant {
+                type_name: type_name.into(),
+                name: variant.name.as_str().into(),
+                fields: Rc::new([]),
+            };
+        }
+
+        Object::Function(Self::Constructor {
+            type_name: type_name.into(),
+            name: variant.name.as_str().into(),
+            parameters: variant
+                .fields
+                .iter()
+                .map(|field| ExpressionKind::Identifier(field.to_owned()))
+                .collect(),
+            partial: None,
+        })
+    }
+
     /// Returns the number of parameters this function expects.
     /// For variadic functions (with rest parameters), returns 1.
     pub fn arity(&self) -> usize {
@@ -264,14 +396,32 @@ impl Function {
             | Self::External {
                 parameters, partial, ..
             } => {
-                let bound_count = partial.as_ref().map_or(0, |p| p.len());
-                parameters.len().saturating_sub(bound_count)
+                let unbound_count = Self::unbound_parameters(parameters, partial).count();
+                let bound_count = parameters.len() - unbound_count;
+                unbound_count.saturating_sub(bound_count)
             }
+            Self::Constructor {
+                parameters, partial, ..
+            } => Self::unbound_parameters(parameters, partial).count(),
+            Self::Compiled(closure) => closure.parameters().len(),
             Self::Composition { .. } => 1,
             Self::Continuation { .. } => 0,
         }
     }
 
+    fn unbound_parameters<'a>(
+        parameters: &'a [ExpressionKind],
+        partial: &'a Option<PartialArguments>,
+    ) -> impl Iterator<Item = &'a ExpressionKind> {
+        parameters
+            .iter()
+            .enumerate()
+            .filter_map(move |(position, parameter)| match partial {
+                Some(partial) if partial[position].is_some() => None,
+                _ => Some(parameter),
+            })
+    }
+
     fn assign_closure_parameters(
         &self,
         environment: EnvironmentRef,
@@ -287,12 +437,13 @@ impl Function {
             }
 
             match &parameter.kind {
-                ExpressionKind::Identifier(name) => {
-                    environment.borrow_mut().set_variable(name, Rc::clone(argument));
+                ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+                    Self::assign_parameter(&environment, parameter, Rc::clone(argument));
                 }
-                ExpressionKind::RestIdentifier(name) => {
-                    environment.borrow_mut().set_variable(
-                        name,
+                ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. } => {
+                    Self::assign_parameter(
+                        &environment,
+                        parameter,
                         Rc::new(Object::List(arguments.clone().into_iter().skip(position).collect())),
                     );
                     break;
@@ -331,43 +482,62 @@ impl Function {
         Ok(remaining_parameters)
     }
 
-    fn assign_interal_parameters(
-        &self,
-        evaluated_arguments: &mut Arguments,
-        #[allow(clippy::ptr_arg)] parameters: &Vec<ExpressionKind>,
-        #[allow(clippy::ptr_arg)] arguments: &Vec<Rc<Object>>,
-    ) -> Result<Vec<ExpressionKind>, RuntimeErr> {
-        let mut remaining_parameters = vec![];
+    fn assign_internal_parameters(
+        parameters: &[ExpressionKind],
+        partial: &Option<PartialArguments>,
+        arguments: Vec<Rc<Object>>,
+    ) -> InternalArguments {
+        let is_variadic = matches!(parameters.last(), Some(ExpressionKind::RestIdentifier(_)));
+
+        // Fully applied calls without placeholders are passed through as-is, only collecting the rest parameter.
+        if partial.is_none()
+            && arguments.len() >= parameters.len()
+            && !arguments
+                .iter()
+                .any(|argument| matches!(**argument, Object::Placeholder))
+        {
+            let mut arguments = arguments;
+            if is_variadic {
+                let rest = arguments.split_off(parameters.len() - 1);
+                arguments.push(Rc::new(Object::List(rest.into_iter().collect())));
+            } else {
+                arguments.truncate(parameters.len());
+            }
+            return InternalArguments::Complete(arguments);
+        }
 
-        for (position, (parameter, argument)) in parameters.iter().zip(arguments.iter()).enumerate() {
-            if let Object::Placeholder = **argument {
-                remaining_parameters.push(parameter.clone());
+        let mut evaluated_arguments = match partial {
+            Some(partial) => partial.clone(),
+            None => vec![None; parameters.len()],
+        };
+        let mut arguments = arguments.into_iter();
+
+        for (position, parameter) in parameters.iter().enumerate() {
+            if evaluated_arguments[position].is_some() {
                 continue;
             }
 
-            match &parameter {
-                ExpressionKind::Identifier(name) => {
-                    evaluated_arguments.insert(name.to_owned(), Rc::clone(argument));
-                }
-                ExpressionKind::RestIdentifier(name) => {
-                    evaluated_arguments.insert(
-                        name.to_owned(),
-                        Rc::new(Object::List(arguments.clone().into_iter().skip(position).collect())),
-                    );
-                    break;
-                }
-                ExpressionKind::Placeholder => {
-                    continue;
-                }
-                _ => {
-                    unreachable!()
-                }
+            let Some(argument) = arguments.next() else {
+                break;
+            };
+
+            if let Object::Placeholder = *argument {
+                continue;
             }
-        }
 
-        remaining_parameters.append(&mut parameters.clone().into_iter().skip(arguments.len()).collect());
+            evaluated_arguments[position] = Some(match parameter {
+                ExpressionKind::RestIdentifier(_) => Rc::new(Object::List(
+                    std::iter::once(argument).chain(arguments.by_ref()).collect(),
+                )),
+                _ => argument,
+            });
+        }
 
-        Ok(remaining_parameters)
+        if evaluated_arguments.iter().all(Option::is_some) {
+            InternalArguments::Complete(evaluated_arguments.into_iter().flatten().collect())
+        } else {
+            InternalArguments::Partial(evaluated_arguments)
+        }
     }
 
     fn destructure_list_pattern_parameter(
@@ -389,17 +559,18 @@ impl Function {
 
         for (position, parameter) in parameter.iter().enumerate() {
             match &parameter.kind {
-                ExpressionKind::Identifier(name) => {
+                ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
                     let object = if let Some(value) = list.iter().nth(position) {
                         Rc::clone(value)
                     } else {
                         Rc::new(Object::Nil)
                     };
-                    environment.borrow_mut().set_variable(name, object);
+                    Self::assign_parameter(&environment, parameter, object);
                 }
-                ExpressionKind::RestIdentifier(name) => {
-                    environment.borrow_mut().set_variable(
-                        name,
+                ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. } => {
+                    Self::assign_parameter(
+                        &environment,
+                        parameter,
                         Rc::new(Object::List(list.clone().into_iter().skip(position).collect())),
                     );
                     break;
@@ -460,11 +631,11 @@ impl Function {
         for element in parameter {
             match &element.kind {
                 // Shorthand: #{name} -> key "name", var name
-                ExpressionKind::Identifier(name) => {
+                ExpressionKind::Identifier(name) | ExpressionKind::ResolvedIdentifier { name, .. } => {
                     let key = Rc::new(Object::String(name.clone()));
                     let value = dict.get(&key).cloned().unwrap_or_else(|| Rc::new(Object::Nil));
                     remaining_keys.remove(&key);
-                    environment.borrow_mut().set_variable(name, value);
+                    Self::assign_parameter(&environment, element, value);
                 }
                 // Explicit: #{"key": binding}
                 ExpressionKind::DictionaryEntryPattern { key, value: binding } => {
@@ -487,16 +658,14 @@ impl Function {
                     Self::bind_parameter_pattern(Rc::clone(&environment), binding, value)?;
                 }
                 // Rest: #{..rest}
-                ExpressionKind::RestIdentifier(name) => {
+                ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. } => {
                     let mut rest_dict = im_rc::HashMap::default();
                     for k in &remaining_keys {
                         if let Some(v) = dict.get(k) {
                             rest_dict.insert(Rc::clone(k), Rc::clone(v));
                         }
                     }
-                    environment
-                        .borrow_mut()
-                        .set_variable(name, Rc::new(Object::Dictionary(rest_dict)));
+                    Self::assign_parameter(&environment, element, Rc::new(Object::Dictionary(rest_dict)));
                     break;
                 }
                 ExpressionKind::Placeholder => continue,
@@ -513,10 +682,23 @@ impl Function {
         Ok(argument)
     }
 
+    fn assign_parameter(environment: &EnvironmentRef, parameter: &Expression, value: Rc<Object>) {
+        match &parameter.kind {
+            ExpressionKind::ResolvedIdentifier { name, slot, .. }
+            | ExpressionKind::ResolvedRestIdentifier { name, slot } => {
+                environment.borrow_mut().set_slot(*slot, name, value)
+            }
+            ExpressionKind::Identifier(name) | ExpressionKind::RestIdentifier(name) => {
+                environment.borrow_mut().set_variable(name, value)
+            }
+            _ => unreachable!(),
+        }
+    }
+
     fn bind_parameter_pattern(environment: EnvironmentRef, binding: &Expression, value: Rc<Object>) -> Evaluation {
         match &binding.kind {
-            ExpressionKind::Identifier(name) => {
-                environment.borrow_mut().set_variable(name, Rc::clone(&value));
+            ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+                Self::assign_parameter(&environment, binding, Rc::clone(&value));
                 Ok(value)
             }
             ExpressionKind::Placeholder => Ok(value),
@@ -552,16 +734,44 @@ impl fmt::Display for Function {
                 let formatted: Vec<String> = parameters.iter().map(|parameter| parameter.to_string()).collect();
                 format!("|{}| {{ [memoized] }}", formatted.join(", "))
             }
-            Function::Builtin { parameters, .. } => {
-                let formatted: Vec<String> = parameters.iter().map(|parameter| parameter.to_string()).collect();
+            Function::Builtin {
+                parameters, partial, ..
+            } => {
+                let formatted: Vec<String> = Self::unbound_parameters(parameters, partial)
+                    .map(|parameter| parameter.to_string())
+                    .collect();
                 format!("|{}| {{ [builtin] }}", formatted.join(", "))
             }
-            Function::External { parameters, .. } => {
-                let formatted: Vec<String> = parameters.iter().map(|parameter| parameter.to_string()).collect();
+            Function::External {
+                parameters, partial, ..
+            } => {
+                let formatted: Vec<String> = Self::unbound_parameters(parameters, partial)
+                    .map(|parameter| parameter.to_string())
+                    .collect();
                 format!("|{}| {{ [external] }}", formatted.join(", "))
             }
+            Function::Constructor {
+                parameters, partial, ..
+            } => {
+                let formatted: Vec<String> = Self::unbound_parameters(parameters, partial)
+                    .map(|parameter| parameter.to_string())
+                    .collect();
+                format!("|{}| {{ [constructor] }}", formatted.join(", "))
+            }
+            Function::Compiled(closure) => {
+                let formatted: Vec<String> = closure
+                    .parameters()
+                    .iter()
+                    .map(|parameter| parameter.to_string())
+                    .collect();
+                if closure.is_memoized() {
+                    format!("|{}| {{ [memoized] }}", formatted.join(", "))
+                } else {
+                    format!("|{}| {{ [closure] }}", formatted.join(", "))
+                }
+            }
             Function::Composition { .. } => "|a| { [composed] }".to_owned(),
-            Function::Continuation { .. } => unreachable!(),
+            Function::Continuation { .. } => "|| { [continuation] }".to_owned(),
         };
         write!(f, "{}", s)
     }
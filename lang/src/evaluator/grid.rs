@@ -0,0 +1,166 @@
+use crate::evaluator::{Evaluator, Object, RuntimeErr};
+use crate::lexer::Location;
+use im_rc::Vector;
+use std::fmt;
+use std::rc::Rc;
+
+// A dense grid of cells held in row-major order, indexed by `[x, y]` points with `[0, 0]` being the top-left cell.
+// Cells are held contiguously behind an Rc, so that indexing is a direct read and cloned grids share their cells.
+// Updating a cell copies the cells on write (via `Rc::make_mut`), leaving the grid it derives from unchanged.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Rc<Vec<Rc<Object>>>,
+}
+
+impl Grid {
+    // Shorter rows are padded with `nil` cells, so that every row shares the width of the longest.
+    pub fn from_rows(rows: Vec<Vec<Rc<Object>>>) -> Self {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let height = if width == 0 { 0 } else { rows.len() };
+        let mut cells = Vec::with_capacity(width * height);
+        for row in rows.into_iter().take(height) {
+            let padding = width - row.len();
+            cells.extend(row);
+            cells.extend((0..padding).map(|_| Rc::new(Object::Nil)));
+        }
+        Self {
+            width,
+            height,
+            cells: Rc::new(cells),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<&Rc<Object>> {
+        self.position(x, y).map(|position| &self.cells[position])
+    }
+
+    pub fn set(&self, x: i64, y: i64, value: Rc<Object>) -> Option<Self> {
+        self.position(x, y).map(|position| {
+            let mut cells = Rc::clone(&self.cells);
+            Rc::make_mut(&mut cells)[position] = value;
+            self.with_cells(cells)
+        })
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = ((i64, i64), &Rc<Object>)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(position, cell)| (((position % width) as i64, (position / width) as i64), cell))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Vector<Rc<Object>>> + '_ {
+        (0..self.height).map(|y| {
+            self.cells[y * self.width..(y + 1) * self.width]
+                .iter()
+                .cloned()
+                .collect()
+        })
+    }
+
+    pub fn with_cells(&self, cells: Rc<Vec<Rc<Object>>>) -> Self {
+        Self {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let cells: Vec<_> = (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .map(|(x, y)| Rc::clone(&self.cells[y * self.width + x]))
+            .collect();
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: Rc::new(cells),
+        }
+    }
+
+    // Rotates the grid a quarter turn clockwise, i.e. the left-most column becomes the top row.
+    pub fn rotate(&self) -> Self {
+        let cells: Vec<_> = (0..self.width)
+            .flat_map(|x| (0..self.height).rev().map(move |y| (x, y)))
+            .map(|(x, y)| Rc::clone(&self.cells[y * self.width + x]))
+            .collect();
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: Rc::new(cells),
+        }
+    }
+
+    fn position(&self, x: i64, y: i64) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+}
+
+pub fn point(x: i64, y: i64) -> Rc<Object> {
+    Rc::new(Object::List(
+        vec![Rc::new(Object::Integer(x)), Rc::new(Object::Integer(y))].into(),
+    ))
+}
+
+pub fn coordinates(index: &Object) -> Option<(i64, i64)> {
+    match index {
+        Object::List(point) if point.len() == 2 => match (&*point[0], &*point[1]) {
+            (Object::Integer(x), Object::Integer(y)) => Some((*x, *y)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub fn index_err(evaluator: &Evaluator, source: Location, grid: &Grid, index: &Object, operation: &str) -> RuntimeErr {
+    RuntimeErr {
+        message: format!(
+            "Unable to {} Grid index {}, size is {}x{}",
+            operation,
+            index,
+            grid.width(),
+            grid.height()
+        ),
+        source,
+        trace: evaluator.get_trace(),
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<String> = self.rows().map(|row| Object::List(row).to_string()).collect();
+        write!(f, "Grid([{}])", rows.join(", "))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Grid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.rows().map(Object::List))
+    }
+}
@@ -0,0 +1,244 @@
+use crate::evaluator::{Environment, EnvironmentErr, EnvironmentRef, Evaluation, Evaluator, Object, RuntimeErr};
+use crate::lexer::{Lexer, Location};
+use crate::parser::Parser;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+#[derive(Debug, Default)]
+pub struct Modules {
+    cache: HashMap<PathBuf, Module>,
+    loading: Vec<LoadingModule>,
+    imported: Vec<Vec<String>>,
+    failed_module: Option<ModuleSource>,
+    entry_path: Option<PathBuf>,
+    file_imports: bool,
+}
+
+// Locations within errors raised by a module refer to its own source, which is retained as it was read
+// so that hosts are able to render them without reading the module again.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModuleSource {
+    pub path: String,
+    pub source: String,
+}
+
+#[derive(Debug)]
+struct LoadingModule {
+    canonical_path: PathBuf,
+    resolved_path: PathBuf,
+    source: ModuleSource,
+}
+
+// Only the bindings a module declares itself are exported, with those it has imported left out,
+// so that modules sharing a common dependency can be imported alongside one another.
+#[derive(Debug)]
+struct Module {
+    environment: EnvironmentRef,
+    imported: Vec<String>,
+}
+
+impl Module {
+    fn exports(&self) -> Vec<(String, Rc<Object>)> {
+        let mut exports = self.environment.borrow().variables();
+        exports.retain(|(name, _)| !self.imported.contains(name));
+        exports
+    }
+}
+
+impl Modules {
+    // Imports made by the entry source are resolved relative to its path, falling back to the
+    // current directory when evaluating source which has no path (i.e. within the REPL).
+    pub fn set_entry_path(&mut self, path: &str) {
+        self.entry_path = Some(PathBuf::from(path));
+    }
+
+    // Imports read from the host's filesystem, so are disabled unless enabled by the host (i.e. the CLI),
+    // leaving embedded runtimes which evaluate untrusted programs unable to read arbitrary files.
+    pub fn set_file_imports(&mut self, enabled: bool) {
+        self.file_imports = enabled;
+    }
+
+    // Errors raised whilst evaluating an imported module refer to locations within that module's
+    // source, so the innermost failing module is recorded alongside them.
+    pub fn take_failed_module(&mut self) -> Option<ModuleSource> {
+        self.failed_module.take()
+    }
+
+    pub fn loading_module(&self) -> Option<&ModuleSource> {
+        self.loading.last().map(|module| &module.source)
+    }
+}
+
+pub fn import(evaluator: &mut Evaluator, path: &str, bindings: &Option<Vec<String>>, source: Location) -> Evaluation {
+    if evaluator.modules.loading.is_empty() {
+        evaluator.modules.failed_module = None;
+    }
+
+    if !evaluator.modules.file_imports {
+        return Err(RuntimeErr {
+            message: format!("Unable to import module {}, file imports are not enabled", path),
+            source,
+            trace: evaluator.get_trace(),
+        });
+    }
+
+    let importing_path = match evaluator.modules.loading.last() {
+        Some(module) => Some(&module.resolved_path),
+        None => evaluator.modules.entry_path.as_ref(),
+    };
+    let resolved_path = match importing_path {
+        Some(importing_path) => importing_path.parent().unwrap_or(Path::new("")).join(path),
+        None => PathBuf::from(path),
+    };
+
+    let canonical_path = match resolved_path.canonicalize() {
+        Ok(canonical_path) => canonical_path,
+        Err(_) => {
+            return Err(RuntimeErr {
+                message: format!("Unable to find module: {}", resolved_path.display()),
+                source,
+                trace: evaluator.get_trace(),
+            });
+        }
+    };
+
+    let exported = match evaluator.modules.cache.get(&canonical_path) {
+        Some(module) => module.exports(),
+        None => {
+            if evaluator
+                .modules
+                .loading
+                .iter()
+                .any(|module| module.canonical_path == canonical_path)
+            {
+                let cycle: Vec<String> = evaluator
+                    .modules
+                    .loading
+                    .iter()
+                    .map(|module| module.resolved_path.display().to_string())
+                    .chain(std::iter::once(resolved_path.display().to_string()))
+                    .collect();
+                return Err(RuntimeErr {
+                    message: format!("Import cycle detected: {}", cycle.join(" -> ")),
+                    source,
+                    trace: evaluator.get_trace(),
+                });
+            }
+
+            let module = evaluate_module(evaluator, &canonical_path, &resolved_path, source)?;
+            let exported = module.exports();
+            evaluator.modules.cache.insert(canonical_path, module);
+            exported
+        }
+    };
+
+    let environment = evaluator.environment();
+    match bindings {
+        Some(bindings) => {
+            for binding in bindings {
+                match exported.iter().find(|(name, _)| name == binding) {
+                    Some((name, value)) => declare(evaluator, &environment, name, value, source)?,
+                    None => {
+                        return Err(RuntimeErr {
+                            message: format!("Module '{}' does not define '{}'", path, binding),
+                            source,
+                            trace: evaluator.get_trace(),
+                        });
+                    }
+                }
+            }
+        }
+        None => {
+            for (name, value) in &exported {
+                declare(evaluator, &environment, name, value, source)?;
+            }
+        }
+    }
+
+    Ok(Rc::new(Object::Nil))
+}
+
+fn evaluate_module(
+    evaluator: &mut Evaluator,
+    canonical_path: &Path,
+    resolved_path: &Path,
+    source: Location,
+) -> Result<Module, RuntimeErr> {
+    let module_source = match std::fs::read_to_string(canonical_path) {
+        Ok(module_source) => module_source,
+        Err(error) => {
+            return Err(RuntimeErr {
+                message: format!("Unable to read module {}: {}", resolved_path.display(), error),
+                source,
+                trace: evaluator.get_trace(),
+            });
+        }
+    };
+
+    let module_source = ModuleSource {
+        path: resolved_path.display().to_string(),
+        source: module_source,
+    };
+    let mut parser = Parser::new(Lexer::new(&module_source.source));
+    let program = match parser.parse() {
+        Ok(program) => program,
+        Err(error) => {
+            evaluator.modules.failed_module.get_or_insert(module_source);
+            return Err(RuntimeErr {
+                message: error.message,
+                source: error.source,
+                trace: vec![],
+            });
+        }
+    };
+
+    let environment = Environment::new();
+    evaluator.modules.loading.push(LoadingModule {
+        canonical_path: canonical_path.to_path_buf(),
+        resolved_path: resolved_path.to_path_buf(),
+        source: module_source,
+    });
+    evaluator.modules.imported.push(vec![]);
+    let result = evaluator.evaluate_with_environment(&program, Rc::clone(&environment));
+    let loaded = evaluator.modules.loading.pop();
+    let imported = evaluator.modules.imported.pop().unwrap_or_default();
+
+    if let Err(error) = result {
+        if let Some(loaded) = loaded {
+            evaluator.modules.failed_module.get_or_insert(loaded.source);
+        }
+        return Err(error);
+    }
+
+    Ok(Module { environment, imported })
+}
+
+// Importing the very same binding again (e.g. from a module imported twice) leaves the existing declaration in place.
+fn declare(
+    evaluator: &mut Evaluator,
+    environment: &EnvironmentRef,
+    name: &str,
+    value: &Rc<Object>,
+    source: Location,
+) -> Result<(), RuntimeErr> {
+    if let Some(imported) = evaluator.modules.imported.last_mut() {
+        imported.push(name.to_owned());
+    }
+    if environment
+        .borrow()
+        .get_variable(name)
+        .is_some_and(|declared| Rc::ptr_eq(&declared, value))
+    {
+        return Ok(());
+    }
+    match environment.borrow_mut().declare_variable(name, Rc::clone(value), false) {
+        Ok(_) => Ok(()),
+        Err(EnvironmentErr { message }) => Err(RuntimeErr {
+            message,
+            source,
+            trace: evaluator.get_trace(),
+        }),
+    }
+}
@@ -1,3 +1,4 @@
+use crate::evaluator::grid::{coordinates, index_err};
 use crate::evaluator::object::Object;
 use crate::evaluator::{Evaluation, Evaluator, RuntimeErr};
 use crate::lexer::Location;
@@ -21,7 +22,8 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
             let is_unbounded_negative_range = adjusted_sequence.is_unbounded_negative_range();
 
             let mut result = Vector::new();
-            for step in adjusted_sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = adjusted_sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for step in &mut iter {
                 if let Object::Integer(index) = &*step {
                     if *index == 0 && is_unbounded_negative_range {
                         break;
@@ -38,6 +40,9 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
                     });
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
 
             Ok(Rc::new(Object::List(result)))
         }
@@ -53,6 +58,14 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
                 Ok(Rc::new(Object::Nil))
             }
         }
+        (Object::Grid(grid), index) => match coordinates(index) {
+            Some((x, y)) => Ok(grid.get(x, y).cloned().unwrap_or_else(|| Rc::new(Object::Nil))),
+            None => Err(RuntimeErr {
+                message: format!("Expected an [x, y] Grid index, found: {}", index),
+                source,
+                trace: evaluator.get_trace(),
+            }),
+        },
         (Object::String(string), Object::Integer(index)) => {
             if let Some(character) = string_lookup(string, *index) {
                 Ok(Rc::new(Object::String(character.to_string())))
@@ -64,6 +77,8 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
             let mut iterator = sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
             if let Some(element) = iterator.nth(*index as usize) {
                 Ok(Rc::clone(&element))
+            } else if let Some(err) = iterator.take_halting_error() {
+                Err(err)
             } else {
                 Ok(Rc::new(Object::Nil))
             }
@@ -77,7 +92,8 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
             let is_unbounded_negative_range = adjusted_sequence.is_unbounded_negative_range();
 
             let mut result = String::new();
-            for step in adjusted_sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source) {
+            let mut iter = adjusted_sequence.resolve_iter(Rc::new(RefCell::new(evaluator)), source);
+            for step in &mut iter {
                 if let Object::Integer(index) = &*step {
                     if *index == 0 && is_unbounded_negative_range {
                         break;
@@ -94,6 +110,9 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
                     });
                 }
             }
+            if let Some(err) = iter.take_halting_error() {
+                return Err(err);
+            }
 
             Ok(Rc::new(Object::String(result)))
         }
@@ -109,6 +128,59 @@ pub fn lookup(evaluator: &mut Evaluator, left: Rc<Object>, index: Rc<Object>, so
     }
 }
 
+#[inline]
+pub fn assign(
+    evaluator: &mut Evaluator,
+    left: Rc<Object>,
+    index: Rc<Object>,
+    value: Rc<Object>,
+    source: Location,
+) -> Evaluation {
+    match (&*left, &*index) {
+        (Object::List(list), Object::Integer(position)) => {
+            let position = if *position < 0 {
+                list.len() as i64 + *position
+            } else {
+                *position
+            };
+            if position < 0 {
+                return Err(RuntimeErr {
+                    message: format!("Unable to assign to List index {}, length is {}", index, list.len()),
+                    source,
+                    trace: evaluator.get_trace(),
+                });
+            }
+
+            let mut assigned = list.clone();
+            while assigned.len() <= position as usize {
+                assigned.push_back(Rc::new(Object::Nil));
+            }
+            Ok(Rc::new(Object::List(assigned.update(position as usize, value))))
+        }
+        (Object::Dictionary(map), key) if key.is_hashable() => {
+            Ok(Rc::new(Object::Dictionary(map.update(index, value))))
+        }
+        (Object::Dictionary(_), key) => Err(RuntimeErr {
+            message: format!("Unable to use a {} as a Dictionary key", key.name()),
+            source,
+            trace: evaluator.get_trace(),
+        }),
+        (Object::Grid(grid), _) => match coordinates(&index).and_then(|(x, y)| grid.set(x, y, value)) {
+            Some(assigned) => Ok(Rc::new(Object::Grid(assigned))),
+            None => Err(index_err(evaluator, source, grid, &index, "assign to")),
+        },
+        (_, _) => Err(RuntimeErr {
+            message: format!(
+                "Unable to perform index assignment, found: {}[{}]",
+                left.name(),
+                index.name()
+            ),
+            source,
+            trace: evaluator.get_trace(),
+        }),
+    }
+}
+
 fn list_lookup(list: &Vector<Rc<Object>>, index: i64) -> Option<Rc<Object>> {
     if index > 0 && index as usize >= list.len() {
         return None;
@@ -46,6 +46,7 @@ pub fn apply(
                     trailing_comment: None,
                 },
                 environment: evaluator.environment(),
+                partial: None,
             })));
         }
         (ExpressionKind::Placeholder, _) => {
@@ -71,6 +72,7 @@ pub fn apply(
                     trailing_comment: None,
                 },
                 environment: evaluator.environment(),
+                partial: None,
             })));
         }
         (_, ExpressionKind::Placeholder) => {
@@ -96,6 +98,7 @@ pub fn apply(
                     trailing_comment: None,
                 },
                 environment: evaluator.environment(),
+                partial: None,
             })));
         }
         _ => {}
@@ -133,25 +136,32 @@ pub fn apply(
     let evaluated_left = evaluator.eval_expression(left)?;
     let evaluated_right = evaluator.eval_expression(right)?;
 
+    apply_operator(evaluator, &evaluated_left, operator, &evaluated_right, source)
+}
+
+#[inline]
+pub fn apply_operator(
+    evaluator: &mut Evaluator,
+    evaluated_left: &Rc<Object>,
+    operator: &Infix,
+    evaluated_right: &Rc<Object>,
+    source: Location,
+) -> Evaluation {
     match operator {
-        Infix::Plus => {
-            crate::evaluator::builtins::operators::plus(evaluator, &evaluated_left, &evaluated_right, source)
-        }
+        Infix::Plus => crate::evaluator::builtins::operators::plus(evaluator, evaluated_left, evaluated_right, source),
         Infix::Minus => {
-            crate::evaluator::builtins::operators::minus(evaluator, &evaluated_left, &evaluated_right, source)
-        }
-        Infix::Asterisk => crate::evaluator::builtins::operators::asterisk(&evaluated_left, &evaluated_right, source),
-        Infix::Slash => crate::evaluator::builtins::operators::slash(&evaluated_left, &evaluated_right, source),
-        Infix::Modulo => crate::evaluator::builtins::operators::modulo(&evaluated_left, &evaluated_right, source),
-        Infix::Equal => crate::evaluator::builtins::operators::equal(&evaluated_left, &evaluated_right),
-        Infix::NotEqual => crate::evaluator::builtins::operators::not_equal(&evaluated_left, &evaluated_right),
-        Infix::LessThan => crate::evaluator::builtins::operators::less_than(&evaluated_left, &evaluated_right),
-        Infix::LessThanEqual => {
-            crate::evaluator::builtins::operators::less_than_equal(&evaluated_left, &evaluated_right)
+            crate::evaluator::builtins::operators::minus(evaluator, evaluated_left, evaluated_right, source)
         }
-        Infix::GreaterThan => crate::evaluator::builtins::operators::greater_than(&evaluated_left, &evaluated_right),
+        Infix::Asterisk => crate::evaluator::builtins::operators::asterisk(evaluated_left, evaluated_right, source),
+        Infix::Slash => crate::evaluator::builtins::operators::slash(evaluated_left, evaluated_right, source),
+        Infix::Modulo => crate::evaluator::builtins::operators::modulo(evaluated_left, evaluated_right, source),
+        Infix::Equal => crate::evaluator::builtins::operators::equal(evaluated_left, evaluated_right),
+        Infix::NotEqual => crate::evaluator::builtins::operators::not_equal(evaluated_left, evaluated_right),
+        Infix::LessThan => crate::evaluator::builtins::operators::less_than(evaluated_left, evaluated_right),
+        Infix::LessThanEqual => crate::evaluator::builtins::operators::less_than_equal(evaluated_left, evaluated_right),
+        Infix::GreaterThan => crate::evaluator::builtins::operators::greater_than(evaluated_left, evaluated_right),
         Infix::GreaterThanEqual => {
-            crate::evaluator::builtins::operators::greater_than_equal(&evaluated_left, &evaluated_right)
+            crate::evaluator::builtins::operators::greater_than_equal(evaluated_left, evaluated_right)
         }
         _ => unreachable!(),
     }
@@ -275,6 +275,23 @@ impl LazySequenceIter<'_> {
         self.error.take()
     }
 
+    // Most builtins end iteration silently upon an error, yet errors which halt evaluation (i.e. an exhausted step
+    // budget or interrupt) must still be re-raised so that they can not be swallowed.
+    pub fn take_halting_error(&mut self) -> Option<crate::evaluator::RuntimeErr> {
+        if self.evaluator.borrow().is_halted() {
+            return self.error.take();
+        }
+        None
+    }
+
+    pub fn collect_until_halted(mut self) -> Result<Vector<Rc<Object>>, crate::evaluator::RuntimeErr> {
+        let result = self.by_ref().collect();
+        match self.take_halting_error() {
+            Some(err) => Err(err),
+            None => Ok(result),
+        }
+    }
+
     pub fn try_collect(mut self) -> Result<Vector<Rc<Object>>, crate::evaluator::RuntimeErr> {
         let mut result = Vector::new();
         for item in self.by_ref() {
@@ -332,9 +349,18 @@ impl LazySequenceIter<'_> {
             } => {
                 let next = Rc::clone(current);
 
-                *current = generator
-                    .apply(&mut self.evaluator.borrow_mut(), vec![Rc::clone(current)], self.source)
-                    .ok()?;
+                let generated =
+                    generator.apply(&mut self.evaluator.borrow_mut(), vec![Rc::clone(current)], self.source);
+                *current = match generated {
+                    Ok(value) => value,
+                    Err(err) => {
+                        // Generator errors end the sequence, unless they halt evaluation.
+                        if self.evaluator.borrow().is_halted() {
+                            self.error = Some(err);
+                        }
+                        return None;
+                    }
+                };
 
                 Some(next)
             }
@@ -387,6 +413,12 @@ impl Iterator for LazySequenceIter<'_> {
 
     fn next(&mut self) -> Option<Rc<Object>> {
         'next: loop {
+            // Producing an element may not call a function (e.g. an unbounded range), so is charged as a step.
+            if let Err(err) = self.evaluator.borrow_mut().step(self.source) {
+                self.error = Some(err);
+                return None;
+            }
+
             let mut next = self.next_value()?;
 
             for function in self.functions.iter_mut() {
@@ -460,7 +492,10 @@ impl Iterator for LazySequenceIter<'_> {
                         for iterator in iterators.iter_mut() {
                             match iterator.next() {
                                 Some(element) => entry.push_back(element),
-                                None => return None,
+                                None => {
+                                    self.error = iterator.take_halting_error();
+                                    return None;
+                                }
                             }
                         }
 
@@ -1,44 +1,33 @@
 use crate::evaluator::object::Object;
-use crate::evaluator::{Environment, EnvironmentErr, Evaluation, Evaluator, Frame, RuntimeErr};
+use crate::evaluator::{Environment, Evaluation, Evaluator, Frame, Function, RuntimeErr};
 use crate::parser::ast::MatchCase;
 use crate::parser::ast::{Expression, ExpressionKind};
+use crate::parser::compile_regex_pattern;
 use im_rc::Vector;
+use regex::Regex;
 use std::rc::Rc;
 
 type PatternMatch = bool;
 
 #[inline]
-pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCase]) -> Evaluation {
+pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCase], tail: bool) -> Evaluation {
     let evaluated_subject = evaluator.eval_expression(subject)?;
 
     for case in cases {
         match &case.pattern.kind {
-            ExpressionKind::Identifier(name) => {
+            ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
                 evaluator.push_frame(Frame::Block {
-                    _source: case.pattern.source,
+                    source: case.pattern.source,
                     environment: Environment::from(evaluator.environment()),
-                });
-                match evaluator
-                    .environment()
-                    .borrow_mut()
-                    .declare_variable(name, Rc::clone(&evaluated_subject), false)
-                {
-                    Ok(_) => {}
-                    Err(EnvironmentErr { message }) => {
-                        return Err(RuntimeErr {
-                            message,
-                            source: case.pattern.source,
-                            trace: evaluator.get_trace(),
-                        });
-                    }
-                };
+                })?;
+                evaluator.declare(&case.pattern, Rc::clone(&evaluated_subject), false)?;
                 if let Some(guard) = &case.guard {
                     if !evaluator.eval_expression(guard)?.is_truthy() {
                         evaluator.pop_frame();
                         continue;
                     }
                 }
-                let result = evaluator.eval_statement(&case.consequence)?;
+                let result = evaluator.eval_statement(&case.consequence, tail)?;
                 evaluator.pop_frame();
                 return Ok(result);
             }
@@ -48,13 +37,13 @@ pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCa
                         continue;
                     }
                 }
-                return evaluator.eval_statement(&case.consequence);
+                return evaluator.eval_statement(&case.consequence, tail);
             }
             ExpressionKind::ListMatchPattern(pattern) => {
                 evaluator.push_frame(Frame::Block {
-                    _source: case.pattern.source,
+                    source: case.pattern.source,
                     environment: Environment::from(evaluator.environment()),
-                });
+                })?;
                 if !destructure_match_list_pattern(evaluator, pattern, Rc::clone(&evaluated_subject))? {
                     evaluator.pop_frame();
                     continue;
@@ -65,15 +54,15 @@ pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCa
                         continue;
                     }
                 }
-                let result = evaluator.eval_statement(&case.consequence)?;
+                let result = evaluator.eval_statement(&case.consequence, tail)?;
                 evaluator.pop_frame();
                 return Ok(result);
             }
             ExpressionKind::DictionaryMatchPattern(pattern) => {
                 evaluator.push_frame(Frame::Block {
-                    _source: case.pattern.source,
+                    source: case.pattern.source,
                     environment: Environment::from(evaluator.environment()),
-                });
+                })?;
                 if !destructure_match_dictionary_pattern(evaluator, pattern, Rc::clone(&evaluated_subject))? {
                     evaluator.pop_frame();
                     continue;
@@ -84,52 +73,61 @@ pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCa
                         continue;
                     }
                 }
-                let result = evaluator.eval_statement(&case.consequence)?;
+                let result = evaluator.eval_statement(&case.consequence, tail)?;
                 evaluator.pop_frame();
                 return Ok(result);
             }
-            ExpressionKind::InclusiveRange { from, to } => {
-                if let (ExpressionKind::Integer(from), ExpressionKind::Integer(to), Object::Integer(index)) =
-                    (&from.kind, &to.kind, &*evaluated_subject)
-                {
-                    if (from.replace('_', "").parse::<i64>().unwrap()..=to.replace('_', "").parse::<i64>().unwrap())
-                        .contains(index)
-                    {
-                        if let Some(guard) = &case.guard {
-                            if !evaluator.eval_expression(guard)?.is_truthy() {
-                                continue;
-                            }
-                        }
-                        return evaluator.eval_statement(&case.consequence);
+            ExpressionKind::VariantMatchPattern { name, fields } => {
+                evaluator.push_frame(Frame::Block {
+                    source: case.pattern.source,
+                    environment: Environment::from(evaluator.environment()),
+                })?;
+                if !destructure_match_variant_pattern(evaluator, name, fields, Rc::clone(&evaluated_subject))? {
+                    evaluator.pop_frame();
+                    continue;
+                }
+                if let Some(guard) = &case.guard {
+                    if !evaluator.eval_expression(guard)?.is_truthy() {
+                        evaluator.pop_frame();
+                        continue;
                     }
                 }
+                let result = evaluator.eval_statement(&case.consequence, tail)?;
+                evaluator.pop_frame();
+                return Ok(result);
             }
-            ExpressionKind::ExclusiveRange { from, until } => {
-                if let (ExpressionKind::Integer(from), ExpressionKind::Integer(until), Object::Integer(index)) =
-                    (&from.kind, &until.kind, &*evaluated_subject)
-                {
-                    if (from.replace('_', "").parse::<i64>().unwrap()..until.replace('_', "").parse::<i64>().unwrap())
-                        .contains(index)
-                    {
-                        if let Some(guard) = &case.guard {
-                            if !evaluator.eval_expression(guard)?.is_truthy() {
-                                continue;
-                            }
-                        }
-                        return evaluator.eval_statement(&case.consequence);
+            ExpressionKind::OrMatchPattern(_)
+            | ExpressionKind::BindingMatchPattern { .. }
+            | ExpressionKind::StringMatchPattern { .. }
+            | ExpressionKind::RegexMatchPattern { .. } => {
+                evaluator.push_frame(Frame::Block {
+                    source: case.pattern.source,
+                    environment: Environment::from(evaluator.environment()),
+                })?;
+                if !match_case_pattern(evaluator, &case.pattern, Rc::clone(&evaluated_subject))? {
+                    evaluator.pop_frame();
+                    continue;
+                }
+                if let Some(guard) = &case.guard {
+                    if !evaluator.eval_expression(guard)?.is_truthy() {
+                        evaluator.pop_frame();
+                        continue;
                     }
                 }
+                let result = evaluator.eval_statement(&case.consequence, tail)?;
+                evaluator.pop_frame();
+                return Ok(result);
             }
-            ExpressionKind::UnboundedRange { from } => {
-                if let (ExpressionKind::Integer(from), Object::Integer(index)) = (&from.kind, &*evaluated_subject) {
-                    if (from.replace('_', "").parse::<i64>().unwrap()..).contains(index) {
-                        if let Some(guard) = &case.guard {
-                            if !evaluator.eval_expression(guard)?.is_truthy() {
-                                continue;
-                            }
+            ExpressionKind::InclusiveRange { .. }
+            | ExpressionKind::ExclusiveRange { .. }
+            | ExpressionKind::UnboundedRange { .. } => {
+                if range_pattern_contains(&case.pattern, &evaluated_subject) == Some(true) {
+                    if let Some(guard) = &case.guard {
+                        if !evaluator.eval_expression(guard)?.is_truthy() {
+                            continue;
                         }
-                        return evaluator.eval_statement(&case.consequence);
                     }
+                    return evaluator.eval_statement(&case.consequence, tail);
                 }
             }
             _ => {
@@ -141,7 +139,7 @@ pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCa
                         continue;
                     }
                 }
-                return evaluator.eval_statement(&case.consequence);
+                return evaluator.eval_statement(&case.consequence, tail);
             }
         }
     }
@@ -149,6 +147,66 @@ pub fn matcher(evaluator: &mut Evaluator, subject: &Expression, cases: &[MatchCa
     Ok(Rc::new(Object::Nil))
 }
 
+type PatternMatcher = fn(&mut Evaluator, &Expression, Rc<Object>) -> Result<PatternMatch, RuntimeErr>;
+
+// Case patterns differ from those nested within a list from how ranges are matched, which only match upon
+// integers at this level.
+fn match_case_pattern(
+    evaluator: &mut Evaluator,
+    pattern: &Expression,
+    subject: Rc<Object>,
+) -> Result<PatternMatch, RuntimeErr> {
+    match &pattern.kind {
+        ExpressionKind::OrMatchPattern(alternatives) => {
+            match_alternatives(evaluator, alternatives, subject, match_case_pattern)
+        }
+        ExpressionKind::BindingMatchPattern { name, pattern } => {
+            evaluator.declare(name, Rc::clone(&subject), false)?;
+            match_case_pattern(evaluator, pattern, subject)
+        }
+        ExpressionKind::InclusiveRange { .. }
+        | ExpressionKind::ExclusiveRange { .. }
+        | ExpressionKind::UnboundedRange { .. } => Ok(range_pattern_contains(pattern, &subject) == Some(true)),
+        _ => match_single_pattern(evaluator, pattern, subject),
+    }
+}
+
+// Ranges bounded by integer literals (which may exceed the i64 range) are matched upon integer subjects, with
+// `None` returned for any other range or subject.
+fn range_pattern_contains(pattern: &Expression, subject: &Object) -> Option<bool> {
+    let integer = |expression: &Expression| match &expression.kind {
+        ExpressionKind::Integer(value) => Some(Object::from_integer_literal(value)),
+        _ => None,
+    };
+    if !matches!(subject, Object::Integer(_) | Object::BigInteger(_)) {
+        return None;
+    }
+    match &pattern.kind {
+        ExpressionKind::InclusiveRange { from, to } => Some((integer(from)?..=integer(to)?).contains(subject)),
+        ExpressionKind::ExclusiveRange { from, until } => Some((integer(from)?..integer(until)?).contains(subject)),
+        ExpressionKind::UnboundedRange { from } => Some((integer(from)?..).contains(subject)),
+        _ => None,
+    }
+}
+
+// Any variables declared by an alternative which fails to match are discarded before the next is attempted.
+fn match_alternatives(
+    evaluator: &mut Evaluator,
+    alternatives: &[Expression],
+    subject: Rc<Object>,
+    matcher: PatternMatcher,
+) -> Result<PatternMatch, RuntimeErr> {
+    let environment = evaluator.environment();
+    for alternative in alternatives {
+        let declared = environment.borrow().clone();
+        if matcher(evaluator, alternative, Rc::clone(&subject))? {
+            return Ok(true);
+        }
+        *environment.borrow_mut() = declared;
+    }
+    Ok(false)
+}
+
 fn destructure_match_list_pattern(
     evaluator: &mut Evaluator,
     pattern: &[Expression],
@@ -160,9 +218,12 @@ fn destructure_match_list_pattern(
     };
 
     // Find the rest pattern position (if any)
-    let rest_position = pattern
-        .iter()
-        .position(|p| matches!(p.kind, ExpressionKind::RestIdentifier(_)));
+    let rest_position = pattern.iter().position(|p| {
+        matches!(
+            p.kind,
+            ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. }
+        )
+    });
 
     match rest_position {
         None => {
@@ -205,31 +266,16 @@ fn destructure_match_list_pattern(
 
             // Bind the rest pattern
             let rest_pattern = &pattern[rest_idx];
-            if let ExpressionKind::RestIdentifier(name) = &rest_pattern.kind {
-                let rest_start = patterns_before;
-                let rest_end = list.len() - patterns_after;
-                let rest: Vector<Rc<Object>> = list
-                    .iter()
-                    .skip(rest_start)
-                    .take(rest_end - rest_start)
-                    .cloned()
-                    .collect();
-
-                match evaluator
-                    .environment()
-                    .borrow_mut()
-                    .declare_variable(name, Rc::new(Object::List(rest)), false)
-                {
-                    Ok(_) => {}
-                    Err(EnvironmentErr { message }) => {
-                        return Err(RuntimeErr {
-                            message,
-                            source: rest_pattern.source,
-                            trace: evaluator.get_trace(),
-                        });
-                    }
-                }
-            }
+            let rest_start = patterns_before;
+            let rest_end = list.len() - patterns_after;
+            let rest: Vector<Rc<Object>> = list
+                .iter()
+                .skip(rest_start)
+                .take(rest_end - rest_start)
+                .cloned()
+                .collect();
+
+            evaluator.declare(rest_pattern, Rc::new(Object::List(rest)), false)?;
 
             Ok(true)
         }
@@ -253,7 +299,7 @@ fn destructure_match_dictionary_pattern(
     for element in pattern {
         match &element.kind {
             // Shorthand: #{name} -> key "name", var name
-            ExpressionKind::Identifier(name) => {
+            ExpressionKind::Identifier(name) | ExpressionKind::ResolvedIdentifier { name, .. } => {
                 let key = Rc::new(Object::String(name.clone()));
                 // Key must exist for the pattern to match
                 if !dict.contains_key(&key) {
@@ -262,20 +308,7 @@ fn destructure_match_dictionary_pattern(
                 let value = dict.get(&key).unwrap();
                 remaining_keys.remove(&key);
 
-                match evaluator
-                    .environment()
-                    .borrow_mut()
-                    .declare_variable(name, Rc::clone(value), false)
-                {
-                    Ok(_) => {}
-                    Err(EnvironmentErr { message }) => {
-                        return Err(RuntimeErr {
-                            message,
-                            source: element.source,
-                            trace: evaluator.get_trace(),
-                        });
-                    }
-                }
+                evaluator.declare(element, Rc::clone(value), false)?;
             }
             // Explicit: #{"key": binding}
             ExpressionKind::DictionaryEntryPattern { key, value: binding } => {
@@ -293,7 +326,7 @@ fn destructure_match_dictionary_pattern(
                 }
             }
             // Rest: #{..rest}
-            ExpressionKind::RestIdentifier(name) => {
+            ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. } => {
                 let mut rest_dict = im_rc::HashMap::default();
                 for k in &remaining_keys {
                     if let Some(v) = dict.get(k) {
@@ -301,20 +334,7 @@ fn destructure_match_dictionary_pattern(
                     }
                 }
 
-                match evaluator.environment().borrow_mut().declare_variable(
-                    name,
-                    Rc::new(Object::Dictionary(rest_dict)),
-                    false,
-                ) {
-                    Ok(_) => {}
-                    Err(EnvironmentErr { message }) => {
-                        return Err(RuntimeErr {
-                            message,
-                            source: element.source,
-                            trace: evaluator.get_trace(),
-                        });
-                    }
-                }
+                evaluator.declare(element, Rc::new(Object::Dictionary(rest_dict)), false)?;
             }
             ExpressionKind::Placeholder => continue,
             _ => {
@@ -330,6 +350,126 @@ fn destructure_match_dictionary_pattern(
     Ok(true)
 }
 
+fn destructure_match_variant_pattern(
+    evaluator: &mut Evaluator,
+    name: &str,
+    pattern: &Option<Vec<Expression>>,
+    subject: Rc<Object>,
+) -> Result<PatternMatch, RuntimeErr> {
+    let declared = evaluator.environment().borrow().get_variable(name);
+    let length = pattern.as_ref().map(|pattern| pattern.len());
+    if !variant_matches(declared.as_deref(), name, length, &subject) {
+        return Ok(false);
+    }
+
+    // Without any field patterns only the variant itself is matched upon
+    let (Some(pattern), Object::Variant { fields, .. }) = (pattern, &*subject) else {
+        return Ok(true);
+    };
+
+    for (sub_pattern, field) in pattern.iter().zip(fields.iter()) {
+        if !match_single_pattern(evaluator, sub_pattern, Rc::clone(field))? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+// Variant patterns match against the constructor their name is bound to, so that variants of differing types
+// which share a name are told apart. Names bound to anything else match upon the subject's type (i.e. `Integer`).
+pub fn variant_matches(declared: Option<&Object>, name: &str, length: Option<usize>, subject: &Object) -> bool {
+    let declared = match declared {
+        Some(Object::Variant { type_name, name, .. })
+        | Some(Object::Function(Function::Constructor { type_name, name, .. })) => Some((type_name, name)),
+        _ => None,
+    };
+
+    match (declared, subject) {
+        (
+            Some((declared_type, declared_name)),
+            Object::Variant {
+                type_name,
+                name: variant,
+                fields,
+            },
+        ) => {
+            type_name == declared_type && variant == declared_name && length.is_none_or(|length| length == fields.len())
+        }
+        (Some(_), _) => false,
+        (None, _) => length.is_none() && subject.name() == name,
+    }
+}
+
+fn destructure_match_string_pattern(
+    evaluator: &mut Evaluator,
+    prefix: &Option<String>,
+    remainder: &Expression,
+    suffix: &Option<String>,
+    subject: Rc<Object>,
+) -> Result<PatternMatch, RuntimeErr> {
+    match string_remainder(prefix.as_deref(), suffix.as_deref(), &subject) {
+        Some(value) => match_single_pattern(evaluator, remainder, value),
+        None => Ok(false),
+    }
+}
+
+fn destructure_match_regex_pattern(
+    evaluator: &mut Evaluator,
+    pattern: &str,
+    captures: &[Expression],
+    subject: Rc<Object>,
+) -> Result<PatternMatch, RuntimeErr> {
+    let regex = match evaluator.regexes.get(pattern) {
+        Some(regex) => regex.clone(),
+        None => {
+            // Patterns have already been validated whilst parsing, so are only compiled here upon first use.
+            let regex = compile_regex_pattern(pattern).unwrap();
+            evaluator.regexes.insert(pattern.to_owned(), regex.clone());
+            regex
+        }
+    };
+
+    let Some(values) = regex_captures(&regex, &subject) else {
+        return Ok(false);
+    };
+
+    for (capture, value) in captures.iter().zip(values) {
+        evaluator.declare(capture, value, false)?;
+    }
+
+    Ok(true)
+}
+
+pub fn string_remainder(prefix: Option<&str>, suffix: Option<&str>, subject: &Object) -> Option<Rc<Object>> {
+    let Object::String(subject) = subject else {
+        return None;
+    };
+    let remainder = subject
+        .strip_prefix(prefix.unwrap_or_default())?
+        .strip_suffix(suffix.unwrap_or_default())?;
+    Some(Rc::new(Object::String(remainder.to_owned())))
+}
+
+// Named groups are captured in the order they appear within the pattern, with those which did not
+// participate in the match being nil.
+pub fn regex_captures(regex: &Regex, subject: &Object) -> Option<Vec<Rc<Object>>> {
+    let Object::String(subject) = subject else {
+        return None;
+    };
+    let captures = regex.captures(subject)?;
+    Some(
+        regex
+            .capture_names()
+            .flatten()
+            .map(|name| match captures.name(name) {
+                Some(capture) => Rc::new(Object::String(capture.as_str().to_owned())),
+                None => Rc::new(Object::Nil),
+            })
+            .collect(),
+    )
+}
+
 fn match_dictionary_binding(
     evaluator: &mut Evaluator,
     binding: &Expression,
@@ -337,24 +477,32 @@ fn match_dictionary_binding(
 ) -> Result<PatternMatch, RuntimeErr> {
     match &binding.kind {
         ExpressionKind::Placeholder => Ok(true),
-        ExpressionKind::Identifier(name) => {
-            match evaluator
-                .environment()
-                .borrow_mut()
-                .declare_variable(name, value, false)
-            {
-                Ok(_) => Ok(true),
-                Err(EnvironmentErr { message }) => Err(RuntimeErr {
-                    message,
-                    source: binding.source,
-                    trace: evaluator.get_trace(),
-                }),
-            }
+        ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+            evaluator.declare(binding, value, false)?;
+            Ok(true)
         }
         ExpressionKind::ListMatchPattern(pattern) => destructure_match_list_pattern(evaluator, pattern, value),
         ExpressionKind::DictionaryMatchPattern(pattern) => {
             destructure_match_dictionary_pattern(evaluator, pattern, value)
         }
+        ExpressionKind::VariantMatchPattern { name, fields } => {
+            destructure_match_variant_pattern(evaluator, name, fields, value)
+        }
+        ExpressionKind::OrMatchPattern(alternatives) => {
+            match_alternatives(evaluator, alternatives, value, match_dictionary_binding)
+        }
+        ExpressionKind::StringMatchPattern {
+            prefix,
+            remainder,
+            suffix,
+        } => destructure_match_string_pattern(evaluator, prefix, remainder, suffix, value),
+        ExpressionKind::RegexMatchPattern { pattern, captures } => {
+            destructure_match_regex_pattern(evaluator, pattern, captures, value)
+        }
+        ExpressionKind::BindingMatchPattern { name, pattern } => {
+            evaluator.declare(name, Rc::clone(&value), false)?;
+            match_dictionary_binding(evaluator, pattern, value)
+        }
         // Literal match - compare evaluated value
         _ => {
             if value != evaluator.eval_expression(binding)? {
@@ -373,56 +521,35 @@ fn match_single_pattern(
 ) -> Result<PatternMatch, RuntimeErr> {
     match &sub_pattern.kind {
         ExpressionKind::Placeholder => Ok(true),
-        ExpressionKind::Identifier(name) => {
-            match evaluator
-                .environment()
-                .borrow_mut()
-                .declare_variable(name, element, false)
-            {
-                Ok(_) => Ok(true),
-                Err(EnvironmentErr { message }) => Err(RuntimeErr {
-                    message,
-                    source: sub_pattern.source,
-                    trace: evaluator.get_trace(),
-                }),
-            }
+        ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+            evaluator.declare(sub_pattern, element, false)?;
+            Ok(true)
         }
         ExpressionKind::ListMatchPattern(pattern) => destructure_match_list_pattern(evaluator, pattern, element),
         ExpressionKind::DictionaryMatchPattern(pattern) => {
             destructure_match_dictionary_pattern(evaluator, pattern, element)
         }
-        ExpressionKind::InclusiveRange { from, to } => {
-            if let (ExpressionKind::Integer(from), ExpressionKind::Integer(to), Object::Integer(index)) =
-                (&from.kind, &to.kind, &*element)
-            {
-                if !(from.replace('_', "").parse::<i64>().unwrap()..=to.replace('_', "").parse::<i64>().unwrap())
-                    .contains(index)
-                {
-                    return Ok(false);
-                }
-            }
-            Ok(true)
+        ExpressionKind::VariantMatchPattern { name, fields } => {
+            destructure_match_variant_pattern(evaluator, name, fields, element)
         }
-        ExpressionKind::ExclusiveRange { from, until } => {
-            if let (ExpressionKind::Integer(from), ExpressionKind::Integer(until), Object::Integer(index)) =
-                (&from.kind, &until.kind, &*element)
-            {
-                if !(from.replace('_', "").parse::<i64>().unwrap()..until.replace('_', "").parse::<i64>().unwrap())
-                    .contains(index)
-                {
-                    return Ok(false);
-                }
-            }
-            Ok(true)
+        ExpressionKind::OrMatchPattern(alternatives) => {
+            match_alternatives(evaluator, alternatives, element, match_single_pattern)
         }
-        ExpressionKind::UnboundedRange { from } => {
-            if let (ExpressionKind::Integer(from), Object::Integer(index)) = (&from.kind, &*element) {
-                if !(from.replace('_', "").parse::<i64>().unwrap()..).contains(index) {
-                    return Ok(false);
-                }
-            }
-            Ok(true)
+        ExpressionKind::StringMatchPattern {
+            prefix,
+            remainder,
+            suffix,
+        } => destructure_match_string_pattern(evaluator, prefix, remainder, suffix, element),
+        ExpressionKind::RegexMatchPattern { pattern, captures } => {
+            destructure_match_regex_pattern(evaluator, pattern, captures, element)
+        }
+        ExpressionKind::BindingMatchPattern { name, pattern } => {
+            evaluator.declare(name, Rc::clone(&element), false)?;
+            match_single_pattern(evaluator, pattern, element)
         }
+        ExpressionKind::InclusiveRange { .. }
+        | ExpressionKind::ExclusiveRange { .. }
+        | ExpressionKind::UnboundedRange { .. } => Ok(range_pattern_contains(sub_pattern, &element) != Some(false)),
         _ => {
             if element != evaluator.eval_expression(sub_pattern)? {
                 Ok(false)
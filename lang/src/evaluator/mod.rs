@@ -1,11 +1,16 @@
 mod builtins;
 mod environment;
 mod function;
+mod grid;
+mod import;
 mod index;
 mod infix;
 mod lazy_sequence;
 mod matcher;
 mod object;
+mod priority_queue;
+mod resolver;
+mod vm;
 
 #[cfg(test)]
 mod tests;
@@ -13,12 +18,16 @@ mod tests;
 pub use crate::evaluator::environment::{Environment, EnvironmentErr, EnvironmentRef};
 use crate::evaluator::function::Function;
 pub use crate::evaluator::function::{Arguments, ExternalFnDef};
+pub use crate::evaluator::import::ModuleSource;
+use crate::evaluator::import::Modules;
 use crate::evaluator::lazy_sequence::LazySequence;
 pub use crate::evaluator::object::Object;
 use crate::lexer::Location;
-use crate::parser::ast::{Expression, ExpressionKind, Prefix, Program, Statement, StatementKind};
+use crate::parser::ast::{Expression, ExpressionKind, Infix, Prefix, Program, Statement, StatementKind, StringPart};
 use im_rc::{HashMap, HashSet, Vector};
+use num_bigint::BigInt;
 use ordered_float::OrderedFloat;
+use regex::Regex;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -30,13 +39,69 @@ pub struct RuntimeErr {
     pub trace: Vec<Location>,
 }
 
+// Failed assertions are raised as a `RuntimeErr` to halt evaluation, with the compared values retained
+// on the evaluator so that the test runner can report them as a failing test case.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssertionFailure {
+    pub expected: String,
+    pub actual: String,
+    pub source: Location,
+    pub module: Option<ModuleSource>,
+}
+
 pub type Evaluation = Result<Rc<Object>, RuntimeErr>;
 type ExternalFnLookup = std::collections::HashMap<String, Rc<Object>>;
 
+// Only calls count towards the frame depth, so both backends reach the limit at the same depth of recursion.
+// Function application grows the native stack on demand (see `Function::apply`), so the limit is reached well
+// before the host's stack is exhausted, allowing runaway recursion to surface as a `RuntimeErr`.
+// The stack can not be grown on WASM, so a conservative limit is used which fits within its fixed-size stack.
+#[cfg(not(target_arch = "wasm32"))]
+pub const DEFAULT_MAX_FRAME_DEPTH: usize = 20_000;
+#[cfg(target_arch = "wasm32")]
+pub const DEFAULT_MAX_FRAME_DEPTH: usize = 1_000;
+
+// A host-provided interrupt is consulted periodically as frames are pushed (i.e. at each call and block) and
+// LazySequence elements are produced, allowing long-running evaluations to be halted. Checking every frame would be
+// needlessly costly.
+pub type InterruptFn = Rc<dyn Fn() -> bool>;
+const INTERRUPT_CHECK_INTERVAL: u32 = 1_024;
+
+struct Interrupt {
+    check: InterruptFn,
+    ticks: u32,
+}
+
+impl std::fmt::Debug for Interrupt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interrupt").field("ticks", &self.ticks).finish()
+    }
+}
+
+// Programs can either be walked directly, or compiled into bytecode which is executed by a stack-based VM.
+// Both backends share the same `Object` representation, builtins and global `Environment`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backend {
+    TreeWalking,
+    Bytecode,
+}
+
 #[derive(Debug)]
 pub struct Evaluator {
     frames: Vec<Frame>,
+    frame_depth: usize,
+    max_frame_depth: usize,
     external_functions: Option<ExternalFnLookup>,
+    modules: Modules,
+    backend: Backend,
+    regexes: std::collections::HashMap<String, Regex>,
+    assertions: usize,
+    assertion_failure: Option<AssertionFailure>,
+    interrupt: Option<Interrupt>,
+    interrupted: bool,
+    max_steps: Option<u64>,
+    steps: u64,
 }
 
 #[derive(Debug)]
@@ -45,7 +110,7 @@ pub enum Frame {
         environment: EnvironmentRef,
     },
     Block {
-        _source: Location,
+        source: Location,
         environment: EnvironmentRef,
     },
     ClosureCall {
@@ -60,12 +125,47 @@ pub enum Frame {
     },
 }
 
+impl Frame {
+    fn is_call(&self) -> bool {
+        matches!(
+            self,
+            Frame::ClosureCall { .. } | Frame::BuiltinCall { .. } | Frame::ExternalCall { .. }
+        )
+    }
+}
+
+// Each collection along an index assignment target (i.e. `grid[y][x]`), paired with the evaluated index into it.
+struct IndexTarget<'a> {
+    root: &'a Expression,
+    collections: Vec<Rc<Object>>,
+    indices: Vec<(Rc<Object>, Location)>,
+}
+
+impl IndexTarget<'_> {
+    fn lookup(&self, evaluator: &mut Evaluator) -> Evaluation {
+        let collection = self.collections.last().unwrap();
+        let (index, source) = self.indices.last().unwrap();
+        crate::evaluator::index::lookup(evaluator, Rc::clone(collection), Rc::clone(index), *source)
+    }
+}
+
 impl Evaluator {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         Self {
             frames: vec![],
+            frame_depth: 0,
+            max_frame_depth: DEFAULT_MAX_FRAME_DEPTH,
             external_functions: None,
+            modules: Modules::default(),
+            backend: Backend::TreeWalking,
+            regexes: std::collections::HashMap::new(),
+            assertions: 0,
+            assertion_failure: None,
+            interrupt: None,
+            interrupted: false,
+            max_steps: None,
+            steps: 0,
         }
     }
 
@@ -86,32 +186,169 @@ impl Evaluator {
 
         Self {
             frames: vec![],
+            frame_depth: 0,
+            max_frame_depth: DEFAULT_MAX_FRAME_DEPTH,
             external_functions: Some(external_functions),
+            modules: Modules::default(),
+            backend: Backend::TreeWalking,
+            regexes: std::collections::HashMap::new(),
+            assertions: 0,
+            assertion_failure: None,
+            interrupt: None,
+            interrupted: false,
+            max_steps: None,
+            steps: 0,
         }
     }
 
     pub fn evaluate(&mut self, program: &Program) -> Evaluation {
-        self.push_frame(Frame::Program {
-            environment: Environment::new(),
-        });
-        let result = self.eval_statement_block(&program.statements)?;
-        self.pop_frame();
-        Ok(result)
+        self.reset_steps();
+        self.evaluate_with_environment(program, Environment::new())
     }
 
     pub fn evaluate_with_environment(&mut self, program: &Program, environment: EnvironmentRef) -> Evaluation {
-        self.push_frame(Frame::Program { environment });
-        let result = self.eval_statement_block(&program.statements)?;
-        self.pop_frame();
-        Ok(result)
+        // Only the tree-walking backend evaluates the resolved program, whereas the compiler resolves
+        // variables itself, so resolution is skipped when running on the VM.
+        let resolved = match self.backend {
+            Backend::TreeWalking => Some(crate::evaluator::resolver::resolve(
+                self,
+                program,
+                Rc::clone(&environment),
+            )?),
+            Backend::Bytecode => None,
+        };
+
+        // Frames are left in place when an error is propagated, so they are unwound here to keep the
+        // evaluator usable for subsequent evaluations (i.e. within the REPL).
+        let depth = self.frames.len();
+        if depth == 0 {
+            self.assertion_failure = None;
+        }
+        self.push_frame(Frame::Program { environment })?;
+        let result = match resolved {
+            Some(resolved) => self.eval_statement_block(&resolved.statements, false),
+            None => crate::evaluator::vm::execute(self, program),
+        };
+        self.truncate_frames(depth);
+        result
+    }
+
+    pub fn set_max_frame_depth(&mut self, max_frame_depth: usize) {
+        self.max_frame_depth = max_frame_depth;
+    }
+
+    // An optional step budget bounds the total number of frames pushed (i.e. calls and blocks) and LazySequence
+    // elements produced between resets, allowing untrusted programs to be run by hosts without relying upon
+    // wall-clock time.
+    pub fn set_max_steps(&mut self, max_steps: Option<u64>) {
+        self.max_steps = max_steps;
+    }
+
+    pub fn reset_steps(&mut self) {
+        self.steps = 0;
     }
 
-    fn push_frame(&mut self, frame: Frame) {
+    pub fn set_backend(&mut self, backend: Backend) {
+        self.backend = backend;
+    }
+
+    pub fn set_source_path(&mut self, path: &str) {
+        self.modules.set_entry_path(path);
+    }
+
+    pub fn set_file_imports(&mut self, enabled: bool) {
+        self.modules.set_file_imports(enabled);
+    }
+
+    pub fn take_failed_module(&mut self) -> Option<ModuleSource> {
+        self.modules.take_failed_module()
+    }
+
+    pub fn assertions(&self) -> usize {
+        self.assertions
+    }
+
+    pub fn take_assertion_failure(&mut self) -> Option<AssertionFailure> {
+        self.assertion_failure.take()
+    }
+
+    pub fn set_interrupt(&mut self, interrupt: Option<InterruptFn>) {
+        self.interrupt = interrupt.map(|check| Interrupt { check, ticks: 0 });
+    }
+
+    pub fn take_interrupted(&mut self) -> bool {
+        std::mem::take(&mut self.interrupted)
+    }
+
+    fn is_halted(&self) -> bool {
+        self.interrupted || self.max_steps.is_some_and(|max_steps| self.steps > max_steps)
+    }
+
+    // Charges a step against the budget and periodically consults the interrupt. Besides each pushed frame, this is
+    // also called by loops which may otherwise never push one (i.e. iterating a LazySequence within a builtin).
+    pub(crate) fn step(&mut self, source: Location) -> Result<(), RuntimeErr> {
+        self.steps += 1;
+        if let Some(max_steps) = self.max_steps.filter(|max_steps| self.steps > *max_steps) {
+            return Err(RuntimeErr {
+                message: format!("Maximum evaluation steps of {} exceeded", max_steps),
+                source,
+                trace: self.get_trace(),
+            });
+        }
+
+        if let Some(interrupt) = &mut self.interrupt {
+            interrupt.ticks += 1;
+            if interrupt.ticks == INTERRUPT_CHECK_INTERVAL {
+                interrupt.ticks = 0;
+                if (interrupt.check)() {
+                    self.interrupted = true;
+                    return Err(RuntimeErr {
+                        message: "Evaluation was interrupted".to_owned(),
+                        source,
+                        trace: self.get_trace(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_frame(&mut self, frame: Frame) -> Result<(), RuntimeErr> {
+        let source = || match &frame {
+            Frame::Program { .. } => Location { start: 0, end: 0 },
+            Frame::Block { source, .. }
+            | Frame::ClosureCall { source, .. }
+            | Frame::BuiltinCall { source }
+            | Frame::ExternalCall { source } => *source,
+        };
+
+        if frame.is_call() {
+            if self.frame_depth >= self.max_frame_depth {
+                return Err(RuntimeErr {
+                    message: format!("Maximum frame depth of {} exceeded", self.max_frame_depth),
+                    source: source(),
+                    trace: self.get_trace(),
+                });
+            }
+            self.frame_depth += 1;
+        }
+
+        self.step(source())?;
+
         self.frames.push(frame);
+        Ok(())
     }
 
     fn pop_frame(&mut self) {
-        self.frames.pop();
+        if self.frames.pop().is_some_and(|frame| frame.is_call()) {
+            self.frame_depth -= 1;
+        }
+    }
+
+    fn truncate_frames(&mut self, length: usize) {
+        self.frames.truncate(length);
+        self.frame_depth = self.frames.iter().filter(|frame| frame.is_call()).count();
     }
 
     fn environment(&self) -> EnvironmentRef {
@@ -123,7 +360,18 @@ impl Evaluator {
         }
     }
 
-    fn eval_statement_block(&mut self, block: &[Statement]) -> Evaluation {
+    fn is_within_closure_call(&self) -> bool {
+        for frame in self.frames.iter().rev() {
+            match frame {
+                Frame::Block { .. } => continue,
+                Frame::ClosureCall { .. } => return true,
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    fn eval_statement_block(&mut self, block: &[Statement], tail: bool) -> Evaluation {
         let mut result = Rc::new(Object::Nil);
 
         for (index, statement) in block.iter().enumerate() {
@@ -131,39 +379,7 @@ impl Evaluator {
                 continue;
             }
 
-            if let StatementKind::Return(value) = &statement.kind {
-                if let ExpressionKind::Call { function, arguments } = &value.kind {
-                    for frame in self.frames.iter().rev() {
-                        if let Frame::ClosureCall { source, .. } = &frame {
-                            if function.source == *source {
-                                return Ok(Rc::new(Object::Function(Function::Continuation {
-                                    arguments: self.eval_expressions(arguments)?,
-                                })));
-                            }
-                            break;
-                        }
-                    }
-                }
-            }
-
-            if index == block.len() - 1 {
-                if let StatementKind::Expression(expression) = &statement.kind {
-                    if let ExpressionKind::Call { function, arguments } = &expression.kind {
-                        for frame in self.frames.iter().rev() {
-                            if let Frame::ClosureCall { source, .. } = &frame {
-                                if function.source == *source {
-                                    return Ok(Rc::new(Object::Function(Function::Continuation {
-                                        arguments: self.eval_expressions(arguments)?,
-                                    })));
-                                }
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-
-            result = self.eval_statement(statement)?;
+            result = self.eval_statement(statement, tail && index == block.len() - 1)?;
 
             if matches!(&*result, Object::Return(_) | Object::Break(_)) {
                 return Ok(result);
@@ -173,9 +389,14 @@ impl Evaluator {
         Ok(Rc::clone(&result))
     }
 
-    fn eval_statement(&mut self, statement: &Statement) -> Evaluation {
+    // Statements in tail position of a closure body hand calls back to the caller as continuations,
+    // which `Function::apply` then evaluates in place of the current call frame.
+    fn eval_statement(&mut self, statement: &Statement, tail: bool) -> Evaluation {
         match &statement.kind {
-            StatementKind::Return(value) => Ok(Rc::new(Object::Return(self.eval_expression(value)?))),
+            StatementKind::Return(value) => {
+                let tail = self.is_within_closure_call();
+                Ok(Rc::new(Object::Return(self.eval_tail_expression(value, tail)?)))
+            }
             StatementKind::Break(value) => Ok(Rc::new(Object::Break(self.eval_expression(value)?))),
             StatementKind::Comment(_) => Ok(Rc::new(Object::Nil)),
             StatementKind::Section { name, body, attributes } => {
@@ -184,67 +405,129 @@ impl Evaluator {
                     .add_section(name, Rc::new(*body.clone()), attributes.clone());
                 Ok(Rc::new(Object::Nil))
             }
-            StatementKind::Expression(expression) => self.eval_expression(expression),
+            StatementKind::Import { path, bindings } => {
+                crate::evaluator::import::import(self, path, bindings, statement.source)
+            }
+            StatementKind::Type { name, variants } => {
+                for variant in variants {
+                    let binding = Expression {
+                        kind: ExpressionKind::Identifier(variant.name.to_owned()),
+                        source: statement.source,
+                    };
+                    self.declare(&binding, Rc::new(Function::constructor(name, variant)), false)?;
+                }
+                Ok(Rc::new(Object::Nil))
+            }
+            StatementKind::Attributed {
+                name,
+                attributes,
+                expression,
+            } => {
+                let value = self.eval_expression(expression)?;
+                self.environment()
+                    .borrow_mut()
+                    .add_binding_attributes(name, attributes.clone());
+                Ok(value)
+            }
+            StatementKind::Expression(expression) => self.eval_tail_expression(expression, tail),
             StatementKind::Block(statements) => {
                 self.push_frame(Frame::Block {
-                    _source: statement.source,
+                    source: statement.source,
                     environment: Environment::from(self.environment()),
-                });
-                let result = self.eval_statement_block(statements)?;
+                })?;
+                let result = self.eval_statement_block(statements, tail)?;
                 self.pop_frame();
                 Ok(result)
             }
         }
     }
 
-    fn eval_expression(&mut self, expression: &Expression) -> Evaluation {
-        match &expression.kind {
-            ExpressionKind::Let { name, value } => self.eval_let_expression(name, value),
-            ExpressionKind::MutableLet { name, value } => self.eval_mutable_let_expression(name, value),
-            ExpressionKind::Assign { name, value } => self.eval_assign_expression(name, value),
-            ExpressionKind::Identifier(name) => {
-                if let Some(value) = self.environment().borrow().get_variable(name) {
-                    return Ok(value);
-                }
+    fn eval_tail_expression(&mut self, expression: &Expression, tail: bool) -> Evaluation {
+        if !tail {
+            return self.eval_expression(expression);
+        }
 
-                if let Some(builtin) = crate::evaluator::builtins::builtins(name) {
-                    return Ok(builtin);
-                }
+        match &expression.kind {
+            ExpressionKind::If {
+                condition,
+                consequence,
+                alternative,
+            } => self.eval_if_expression(condition, consequence, alternative, true),
+            ExpressionKind::Match { subject, cases } => crate::evaluator::matcher::matcher(self, subject, cases, true),
+            ExpressionKind::Call { function, arguments } => {
+                let evaluated_function = self.eval_expression(function)?;
 
-                if let Some(builtin) = crate::evaluator::builtins::builtin_aliases(name) {
-                    return Ok(builtin);
+                if let Object::Function(Function::Closure { .. } | Function::MemoizedClosure { .. }) =
+                    &*evaluated_function
+                {
+                    return Ok(Rc::new(Object::Function(Function::Continuation {
+                        function: evaluated_function,
+                        arguments: self.eval_expressions(arguments)?,
+                        source: function.source,
+                    })));
                 }
 
-                if let Some(external_functions) = &self.external_functions {
-                    if let Some(external) = external_functions.get(name) {
-                        return Ok(Rc::clone(external));
-                    }
+                if let Object::Function(func) = &*evaluated_function {
+                    let evaluated_arguments = self.eval_expressions(arguments)?;
+                    return func.apply(self, evaluated_arguments, function.source);
                 }
 
                 Err(RuntimeErr {
-                    message: format!("Identifier can not be found: {}", name),
-                    source: expression.source,
+                    message: format!("Expected a Function, found: {}", evaluated_function.name()),
+                    source: function.source,
                     trace: self.get_trace(),
                 })
             }
-            ExpressionKind::Integer(value) => {
-                Ok(Rc::new(Object::Integer(value.replace('_', "").parse::<i64>().unwrap())))
+            _ => self.eval_expression(expression),
+        }
+    }
+
+    fn eval_expression(&mut self, expression: &Expression) -> Evaluation {
+        match &expression.kind {
+            ExpressionKind::Let { name, value } => self.eval_let_expression(name, value),
+            ExpressionKind::MutableLet { name, value } => self.eval_mutable_let_expression(name, value),
+            ExpressionKind::Assign { name, value } => self.eval_assign_expression(name, value),
+            ExpressionKind::CompoundAssign { name, operator, value } => {
+                self.eval_compound_assign_expression(name, operator, value, expression.source)
+            }
+            ExpressionKind::ResolvedIdentifier { name, depth, slot } => {
+                if let Some(value) = self.environment().borrow().get_slot(*depth, *slot) {
+                    return Ok(value);
+                }
+
+                self.eval_identifier(name, expression.source)
             }
+            ExpressionKind::Identifier(name) => self.eval_identifier(name, expression.source),
+            ExpressionKind::Integer(value) => Ok(Rc::new(Object::from_integer_literal(value))),
             ExpressionKind::Decimal(value) => Ok(Rc::new(Object::Decimal(
                 value.replace('_', "").parse::<OrderedFloat<f64>>().unwrap(),
             ))),
             ExpressionKind::String(value) => Ok(Rc::new(Object::String(value.to_owned()))),
+            ExpressionKind::InterpolatedString(parts) => {
+                let mut value = String::new();
+                for part in parts {
+                    match part {
+                        StringPart::Literal(literal) => value.push_str(literal),
+                        StringPart::Expression(expression) => match &*self.eval_expression(expression)? {
+                            Object::String(string) => value.push_str(string),
+                            object => value.push_str(&object.to_string()),
+                        },
+                    }
+                }
+                Ok(Rc::new(Object::String(value)))
+            }
             ExpressionKind::Boolean(value) => Ok(Rc::new(Object::Boolean(*value))),
             ExpressionKind::If {
                 condition,
                 consequence,
                 alternative,
-            } => self.eval_if_expression(condition, consequence, alternative),
-            ExpressionKind::Match { subject, cases } => crate::evaluator::matcher::matcher(self, subject, cases),
+            } => self.eval_if_expression(condition, consequence, alternative, false),
+            ExpressionKind::Match { subject, cases } => crate::evaluator::matcher::matcher(self, subject, cases, false),
             ExpressionKind::Function { parameters, body } => Ok(Rc::new(Object::Function(Function::Closure {
                 parameters: parameters.clone(),
                 body: *body.clone(),
                 environment: Rc::clone(&self.environment()),
+                partial: None,
             }))),
             ExpressionKind::Call { function, arguments } => {
                 let evaluated_function = self.eval_expression(function)?;
@@ -382,7 +665,11 @@ impl Evaluator {
             }
             ExpressionKind::Prefix { operator, right } => match (&operator, &*self.eval_expression(right)?) {
                 (Prefix::Bang, object) => Ok(Rc::new(Object::Boolean(!object.is_truthy()))),
-                (Prefix::Minus, Object::Integer(v)) => Ok(Rc::new(Object::Integer(-v))),
+                (Prefix::Minus, Object::Integer(v)) => match v.checked_neg() {
+                    Some(v) => Ok(Rc::new(Object::Integer(v))),
+                    None => Ok(Rc::new(Object::from(-BigInt::from(*v)))),
+                },
+                (Prefix::Minus, Object::BigInteger(v)) => Ok(Rc::new(Object::from(-v))),
                 (Prefix::Minus, Object::Decimal(v)) => Ok(Rc::new(Object::Decimal(-v))),
                 (Prefix::Minus, object) => Err(RuntimeErr {
                     message: format!("Unexpected prefix operation: -{}", object.name()),
@@ -405,23 +692,68 @@ impl Evaluator {
         }
     }
 
+    fn eval_identifier(&self, name: &str, source: Location) -> Evaluation {
+        if let Some(value) = self.environment().borrow().get_variable(name) {
+            return Ok(value);
+        }
+
+        if let Some(builtin) = crate::evaluator::builtins::builtins(name) {
+            return Ok(builtin);
+        }
+
+        if let Some(builtin) = crate::evaluator::builtins::builtin_aliases(name) {
+            return Ok(builtin);
+        }
+
+        if let Some(external_functions) = &self.external_functions {
+            if let Some(external) = external_functions.get(name) {
+                return Ok(Rc::clone(external));
+            }
+        }
+
+        Err(RuntimeErr {
+            message: format!("Identifier can not be found: {}", name),
+            source,
+            trace: self.get_trace(),
+        })
+    }
+
+    fn is_global(&self, name: &str) -> bool {
+        crate::evaluator::builtins::builtins(name).is_some()
+            || crate::evaluator::builtins::builtin_aliases(name).is_some()
+            || self
+                .external_functions
+                .as_ref()
+                .is_some_and(|external_functions| external_functions.contains_key(name))
+    }
+
+    fn declare(&self, binding: &Expression, value: Rc<Object>, mutable: bool) -> Result<(), RuntimeErr> {
+        let declared = match &binding.kind {
+            ExpressionKind::ResolvedIdentifier { name, slot, .. }
+            | ExpressionKind::ResolvedRestIdentifier { name, slot } => self
+                .environment()
+                .borrow_mut()
+                .declare_slot(*slot, name, value, mutable),
+            ExpressionKind::Identifier(name) | ExpressionKind::RestIdentifier(name) => {
+                self.environment().borrow_mut().declare_variable(name, value, mutable)
+            }
+            _ => unreachable!(),
+        };
+
+        declared.map_err(|EnvironmentErr { message }| RuntimeErr {
+            message,
+            source: binding.source,
+            trace: self.get_trace(),
+        })
+    }
+
     fn eval_let_expression(&mut self, name: &Expression, value: &Expression) -> Evaluation {
         let evaluated_value = self.eval_expression(value)?;
 
         match &name.kind {
-            ExpressionKind::Identifier(id) => {
-                match self
-                    .environment()
-                    .borrow_mut()
-                    .declare_variable(id, Rc::clone(&evaluated_value), false)
-                {
-                    Ok(_) => Ok(Rc::clone(&evaluated_value)),
-                    Err(EnvironmentErr { message }) => Err(RuntimeErr {
-                        message,
-                        source: name.source,
-                        trace: self.get_trace(),
-                    }),
-                }
+            ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+                self.declare(name, Rc::clone(&evaluated_value), false)?;
+                Ok(evaluated_value)
             }
             ExpressionKind::IdentifierListPattern(pattern) => {
                 self.destructure_let_list_pattern(pattern, evaluated_value, false, name.source)
@@ -441,19 +773,9 @@ impl Evaluator {
         let evaluated_value = self.eval_expression(value)?;
 
         match &name.kind {
-            ExpressionKind::Identifier(id) => {
-                match self
-                    .environment()
-                    .borrow_mut()
-                    .declare_variable(id, Rc::clone(&evaluated_value), true)
-                {
-                    Ok(_) => Ok(Rc::clone(&evaluated_value)),
-                    Err(EnvironmentErr { message }) => Err(RuntimeErr {
-                        message,
-                        source: name.source,
-                        trace: self.get_trace(),
-                    }),
-                }
+            ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+                self.declare(name, Rc::clone(&evaluated_value), true)?;
+                Ok(evaluated_value)
             }
             ExpressionKind::IdentifierListPattern(pattern) => {
                 self.destructure_let_list_pattern(pattern, evaluated_value, true, name.source)
@@ -471,8 +793,50 @@ impl Evaluator {
 
     fn eval_assign_expression(&mut self, name: &Expression, value: &Expression) -> Evaluation {
         let evaluated_value = self.eval_expression(value)?;
+        self.assign(name, evaluated_value)
+    }
+
+    fn eval_compound_assign_expression(
+        &mut self,
+        name: &Expression,
+        operator: &Infix,
+        value: &Expression,
+        source: Location,
+    ) -> Evaluation {
+        // The target's collection and indices are evaluated once, so that `xs[f()] += 1` reads from
+        // and writes to the very same location.
+        if let ExpressionKind::Index { .. } = &name.kind {
+            let target = self.eval_index_target(name)?;
+            let current_value = target.lookup(self)?;
+            let evaluated_value = self.eval_expression(value)?;
+            let operated_value =
+                crate::evaluator::infix::apply_operator(self, &current_value, operator, &evaluated_value, source)?;
+            return self.store_index_target(target, operated_value);
+        }
+
+        let current_value = self.eval_expression(name)?;
+        let evaluated_value = self.eval_expression(value)?;
+        let operated_value =
+            crate::evaluator::infix::apply_operator(self, &current_value, operator, &evaluated_value, source)?;
+        self.assign(name, operated_value)
+    }
 
+    fn assign(&mut self, name: &Expression, evaluated_value: Rc<Object>) -> Evaluation {
         match &name.kind {
+            ExpressionKind::ResolvedIdentifier { name: id, depth, slot } => {
+                match self
+                    .environment()
+                    .borrow_mut()
+                    .assign_slot(*depth, *slot, id, Rc::clone(&evaluated_value))
+                {
+                    Ok(_) => Ok(Rc::clone(&evaluated_value)),
+                    Err(EnvironmentErr { message }) => Err(RuntimeErr {
+                        message,
+                        source: name.source,
+                        trace: self.get_trace(),
+                    }),
+                }
+            }
             ExpressionKind::Identifier(id) => {
                 match self
                     .environment()
@@ -487,8 +851,85 @@ impl Evaluator {
                     }),
                 }
             }
-            _ => Ok(Rc::new(Object::Nil)),
+            ExpressionKind::Index { .. } => self.eval_index_assignment(name, evaluated_value),
+            _ => Err(RuntimeErr {
+                message: format!("Unable to assign to: {}", name),
+                source: name.source,
+                trace: self.get_trace(),
+            }),
+        }
+    }
+
+    fn eval_index_assignment(&mut self, target: &Expression, value: Rc<Object>) -> Evaluation {
+        let target = self.eval_index_target(target)?;
+        self.store_index_target(target, value)
+    }
+
+    fn eval_index_target<'a>(&mut self, target: &'a Expression) -> Result<IndexTarget<'a>, RuntimeErr> {
+        let mut indices = vec![];
+        let mut root = target;
+        while let ExpressionKind::Index { left, index } = &root.kind {
+            indices.push(index);
+            root = left;
+        }
+        indices.reverse();
+
+        let (id, collection) = match &root.kind {
+            ExpressionKind::Identifier(id) => (id, self.environment().borrow().get_variable(id)),
+            ExpressionKind::ResolvedIdentifier { name: id, depth, slot } => {
+                let environment = self.environment();
+                let collection = environment.borrow().get_slot(*depth, *slot);
+                (id, collection.or_else(|| environment.borrow().get_variable(id)))
+            }
+            _ => {
+                return Err(RuntimeErr {
+                    message: format!("Unable to assign to: {}", target),
+                    source: target.source,
+                    trace: self.get_trace(),
+                });
+            }
+        };
+
+        let mut collections = match collection {
+            Some(collection) => vec![collection],
+            None => {
+                return Err(RuntimeErr {
+                    message: format!("Identifier can not be found: {}", id),
+                    source: root.source,
+                    trace: self.get_trace(),
+                });
+            }
+        };
+        let mut evaluated_indices = Vec::with_capacity(indices.len());
+        for (position, index) in indices.iter().enumerate() {
+            let evaluated_index = self.eval_expression(index)?;
+            if position < indices.len() - 1 {
+                let collection = Rc::clone(&collections[position]);
+                collections.push(crate::evaluator::index::lookup(
+                    self,
+                    collection,
+                    Rc::clone(&evaluated_index),
+                    index.source,
+                )?);
+            }
+            evaluated_indices.push((evaluated_index, index.source));
         }
+
+        Ok(IndexTarget {
+            root,
+            collections,
+            indices: evaluated_indices,
+        })
+    }
+
+    fn store_index_target(&mut self, target: IndexTarget, value: Rc<Object>) -> Evaluation {
+        let mut assigned = Rc::clone(&value);
+        for (collection, (index, source)) in target.collections.into_iter().zip(target.indices).rev() {
+            assigned = crate::evaluator::index::assign(self, collection, index, assigned, source)?;
+        }
+
+        self.assign(target.root, assigned)?;
+        Ok(value)
     }
 
     fn eval_if_expression(
@@ -496,13 +937,14 @@ impl Evaluator {
         condition: &Expression,
         consequence: &Statement,
         alternative: &Option<Box<Statement>>,
+        tail: bool,
     ) -> Evaluation {
         let evaluated_condition = self.eval_expression(condition)?;
 
         if evaluated_condition.is_truthy() {
-            self.eval_statement(consequence)
+            self.eval_statement(consequence, tail)
         } else if let Some(alternative) = alternative {
-            self.eval_statement(alternative)
+            self.eval_statement(alternative, tail)
         } else {
             Ok(Rc::new(Object::Nil))
         }
@@ -523,9 +965,11 @@ impl Evaluator {
                     }
                     Object::LazySequence(sequence) => {
                         // Collect lazy sequence elements using a fresh reborrow
-                        let elements: Vec<Rc<Object>> = {
+                        let elements = {
                             let shared_evaluator = Rc::new(RefCell::new(&mut *self));
-                            sequence.resolve_iter(shared_evaluator, expression.source).collect()
+                            sequence
+                                .resolve_iter(shared_evaluator, expression.source)
+                                .collect_until_halted()?
                         };
                         results.extend(elements);
                         continue;
@@ -569,37 +1013,19 @@ impl Evaluator {
 
         for (position, pattern) in pattern.iter().enumerate() {
             match &pattern.kind {
-                ExpressionKind::Identifier(name) => {
-                    match self.environment().borrow_mut().declare_variable(
-                        name,
+                ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+                    self.declare(
+                        pattern,
                         Rc::clone(list.iter().nth(position).unwrap_or(&Rc::new(Object::Nil))),
                         is_mutable,
-                    ) {
-                        Ok(_) => {}
-                        Err(EnvironmentErr { message }) => {
-                            return Err(RuntimeErr {
-                                message,
-                                source: pattern.source,
-                                trace: self.get_trace(),
-                            });
-                        }
-                    }
+                    )?;
                 }
-                ExpressionKind::RestIdentifier(name) => {
-                    match self.environment().borrow_mut().declare_variable(
-                        name,
+                ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. } => {
+                    self.declare(
+                        pattern,
                         Rc::new(Object::List(list.clone().into_iter().skip(position).collect())),
                         is_mutable,
-                    ) {
-                        Ok(_) => {}
-                        Err(EnvironmentErr { message }) => {
-                            return Err(RuntimeErr {
-                                message,
-                                source: pattern.source,
-                                trace: self.get_trace(),
-                            });
-                        }
-                    }
+                    )?;
                     break;
                 }
                 ExpressionKind::Placeholder => {
@@ -651,25 +1077,12 @@ impl Evaluator {
         for element in pattern {
             match &element.kind {
                 // Shorthand: #{name} -> key "name", var name
-                ExpressionKind::Identifier(name) => {
+                ExpressionKind::Identifier(name) | ExpressionKind::ResolvedIdentifier { name, .. } => {
                     let key = Rc::new(Object::String(name.clone()));
                     let value = dict.get(&key).cloned().unwrap_or_else(|| Rc::new(Object::Nil));
                     remaining_keys.remove(&key);
 
-                    match self
-                        .environment()
-                        .borrow_mut()
-                        .declare_variable(name, value, is_mutable)
-                    {
-                        Ok(_) => {}
-                        Err(EnvironmentErr { message }) => {
-                            return Err(RuntimeErr {
-                                message,
-                                source: element.source,
-                                trace: self.get_trace(),
-                            });
-                        }
-                    }
+                    self.declare(element, value, is_mutable)?;
                 }
                 // Explicit: #{"key": binding}
                 ExpressionKind::DictionaryEntryPattern { key, value: binding } => {
@@ -683,7 +1096,7 @@ impl Evaluator {
                     self.bind_dictionary_pattern_value(binding, dict_value, is_mutable)?;
                 }
                 // Rest: #{..rest}
-                ExpressionKind::RestIdentifier(name) => {
+                ExpressionKind::RestIdentifier(_) | ExpressionKind::ResolvedRestIdentifier { .. } => {
                     let mut rest_dict = HashMap::default();
                     for k in &remaining_keys {
                         if let Some(v) = dict.get(k) {
@@ -691,20 +1104,7 @@ impl Evaluator {
                         }
                     }
 
-                    match self.environment().borrow_mut().declare_variable(
-                        name,
-                        Rc::new(Object::Dictionary(rest_dict)),
-                        is_mutable,
-                    ) {
-                        Ok(_) => {}
-                        Err(EnvironmentErr { message }) => {
-                            return Err(RuntimeErr {
-                                message,
-                                source: element.source,
-                                trace: self.get_trace(),
-                            });
-                        }
-                    }
+                    self.declare(element, Rc::new(Object::Dictionary(rest_dict)), is_mutable)?;
                     break;
                 }
                 ExpressionKind::Placeholder => continue,
@@ -728,19 +1128,9 @@ impl Evaluator {
         is_mutable: bool,
     ) -> Evaluation {
         match &binding.kind {
-            ExpressionKind::Identifier(name) => {
-                match self
-                    .environment()
-                    .borrow_mut()
-                    .declare_variable(name, Rc::clone(&value), is_mutable)
-                {
-                    Ok(_) => Ok(value),
-                    Err(EnvironmentErr { message }) => Err(RuntimeErr {
-                        message,
-                        source: binding.source,
-                        trace: self.get_trace(),
-                    }),
-                }
+            ExpressionKind::Identifier(_) | ExpressionKind::ResolvedIdentifier { .. } => {
+                self.declare(binding, Rc::clone(&value), is_mutable)?;
+                Ok(value)
             }
             ExpressionKind::Placeholder => Ok(value),
             ExpressionKind::IdentifierListPattern(pattern) => {
@@ -1,15 +1,24 @@
 use crate::evaluator::Function;
+use crate::evaluator::grid::Grid;
 use crate::evaluator::lazy_sequence::LazySequence;
+use crate::evaluator::priority_queue::PriorityQueue;
 use im_rc::{HashMap, HashSet, Vector};
+use num_bigint::BigInt;
+use num_traits::{ToPrimitive, Zero};
 use ordered_float::OrderedFloat;
+use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::fmt;
 use std::hash::BuildHasherDefault;
 use std::hash::Hash;
 use std::rc::Rc;
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// Values whose canonical form exceeds this width are rendered across multiple lines by `Object::pretty`.
+const PRETTY_WIDTH: usize = 80;
+const PRETTY_INDENT: usize = 2;
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Object {
     Nil,
@@ -28,13 +37,28 @@ pub enum Object {
     Placeholder,
     Return(Rc<Object>),
     Break(Rc<Object>),
+
+    // Only ever holds values outside of the i64 range (see `From<BigInt>`). This and the variants which
+    // follow are declared last so the derived hashes of the others are unaffected.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_big_integer"))]
+    BigInteger(BigInt),
+
+    Variant {
+        type_name: Rc<str>,
+        name: Rc<str>,
+        fields: Rc<[Rc<Object>]>,
+    },
+
+    PriorityQueue(PriorityQueue),
+
+    Grid(Grid),
 }
 
 impl Object {
     pub fn name(&self) -> String {
         match self {
             Self::Nil => "Nil".to_owned(),
-            Self::Integer(_) => "Integer".to_owned(),
+            Self::Integer(_) | Self::BigInteger(_) => "Integer".to_owned(),
             Self::Decimal(_) => "Decimal".to_owned(),
             Self::Boolean(_) => "Boolean".to_owned(),
             Self::String(_) => "String".to_owned(),
@@ -42,6 +66,8 @@ impl Object {
             Self::List(_) => "List".to_owned(),
             Self::Set(_) => "Set".to_owned(),
             Self::Dictionary(_) => "Dictionary".to_owned(),
+            Self::PriorityQueue(_) => "PriorityQueue".to_owned(),
+            Self::Grid(_) => "Grid".to_owned(),
             Self::LazySequence(sequence) => {
                 if sequence.has_transformations() {
                     "LazySequence".to_owned()
@@ -53,6 +79,7 @@ impl Object {
             }
 
             Self::Function(_) => "Function".to_owned(),
+            Self::Variant { type_name, .. } => type_name.to_string(),
 
             Self::Placeholder => "Placeholder".to_owned(),
             Self::Return(v) => v.name(),
@@ -64,6 +91,7 @@ impl Object {
         match self {
             Self::Nil => false,
             Self::Integer(v) => *v != 0,
+            Self::BigInteger(v) => !v.is_zero(),
             Self::Decimal(OrderedFloat(v)) => *v != 0.0,
             Self::Boolean(v) => *v,
             Self::String(v) => !v.is_empty(),
@@ -71,9 +99,12 @@ impl Object {
             Self::List(v) => !v.is_empty(),
             Self::Set(v) => !v.is_empty(),
             Self::Dictionary(v) => !v.is_empty(),
+            Self::PriorityQueue(v) => !v.is_empty(),
+            Self::Grid(v) => !v.is_empty(),
             Self::LazySequence(_) => true,
 
             Self::Function(_) => true,
+            Self::Variant { .. } => true,
 
             Self::Placeholder => false,
             Self::Return(v) => v.is_truthy(),
@@ -83,11 +114,367 @@ impl Object {
 
     pub fn is_hashable(&self) -> bool {
         match self {
-            Self::Nil | Self::Integer(_) | Self::Decimal(_) | Self::Boolean(_) | Self::String(_) | Self::Set(_) => true,
+            Self::Nil
+            | Self::Integer(_)
+            | Self::BigInteger(_)
+            | Self::Decimal(_)
+            | Self::Boolean(_)
+            | Self::String(_)
+            | Self::Set(_) => true,
             Self::List(list) => list.iter().all(|element| element.is_hashable()),
+            Self::Variant { fields, .. } => fields.iter().all(|field| field.is_hashable()),
+            Self::Grid(grid) => grid.cells().all(|(_, cell)| cell.is_hashable()),
             _ => false,
         }
     }
+
+    // Integer literals beyond the i64 range are promoted to a BigInteger.
+    pub fn from_integer_literal(literal: &str) -> Self {
+        let literal = literal.replace('_', "");
+        match literal.parse::<i64>() {
+            Ok(value) => Self::Integer(value),
+            Err(_) => Self::from(literal.parse::<BigInt>().unwrap()),
+        }
+    }
+
+    pub fn to_big_integer(&self) -> Option<BigInt> {
+        match self {
+            Self::Integer(v) => Some(BigInt::from(*v)),
+            Self::BigInteger(v) => Some(v.clone()),
+            _ => None,
+        }
+    }
+
+    // Large nested values are broken across indented lines, with each element (and entry) reusing the
+    // canonical form where it fits within the remaining width.
+    pub fn pretty(&self) -> String {
+        Layout::of(self).render(0, 0)
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Nil => 0,
+            Self::Integer(_) | Self::BigInteger(_) => 1,
+            Self::Decimal(_) => 2,
+            Self::Boolean(_) => 3,
+            Self::String(_) => 4,
+            Self::List(_) => 5,
+            Self::Set(_) => 6,
+            Self::Dictionary(_) => 7,
+            Self::LazySequence(_) => 8,
+            Self::Function(_) => 9,
+            Self::Placeholder => 10,
+            Self::Return(_) => 11,
+            Self::Break(_) => 12,
+            Self::Variant { .. } => 13,
+            Self::PriorityQueue(_) => 14,
+            Self::Grid(_) => 15,
+        }
+    }
+}
+
+impl From<BigInt> for Object {
+    fn from(value: BigInt) -> Self {
+        match value.to_i64() {
+            Some(v) => Self::Integer(v),
+            None => Self::BigInteger(value),
+        }
+    }
+}
+
+impl PartialOrd for Object {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Object {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::Integer(a), Self::BigInteger(b)) => BigInt::from(*a).cmp(b),
+            (Self::BigInteger(a), Self::Integer(b)) => a.cmp(&BigInt::from(*b)),
+            (Self::BigInteger(a), Self::BigInteger(b)) => a.cmp(b),
+            (Self::Decimal(a), Self::Decimal(b)) => a.cmp(b),
+            (Self::Boolean(a), Self::Boolean(b)) => a.cmp(b),
+            (Self::String(a), Self::String(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => a.cmp(b),
+            (Self::Set(a), Self::Set(b)) => a.cmp(b),
+            (Self::Dictionary(a), Self::Dictionary(b)) => a.cmp(b),
+            (Self::PriorityQueue(a), Self::PriorityQueue(b)) => a.cmp(b),
+            (Self::Grid(a), Self::Grid(b)) => a.cmp(b),
+            (Self::LazySequence(a), Self::LazySequence(b)) => a.cmp(b),
+            (Self::Function(a), Self::Function(b)) => a.cmp(b),
+            (Self::Return(a), Self::Return(b)) => a.cmp(b),
+            (Self::Break(a), Self::Break(b)) => a.cmp(b),
+            (
+                Self::Variant {
+                    type_name: a_type,
+                    name: a_name,
+                    fields: a_fields,
+                },
+                Self::Variant {
+                    type_name: b_type,
+                    name: b_name,
+                    fields: b_fields,
+                },
+            ) => (a_type, a_name, a_fields).cmp(&(b_type, b_name, b_fields)),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+// Integers beyond the i64 range can not be represented exactly by most consumers (e.g. JSON), so are serialized as a
+// single-entry map of `BIG_INTEGER_KEY` to their decimal string. The key begins with a NUL character, which can not be
+// written within a santa-lang string, so the encoding is reserved for BigIntegers.
+#[cfg(feature = "serde")]
+const BIG_INTEGER_KEY: &str = "\0BigInteger";
+
+#[cfg(feature = "serde")]
+fn serialize_big_integer<S>(value: &BigInt, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeMap;
+
+    let mut map = serializer.serialize_map(Some(1))?;
+    map.serialize_entry(BIG_INTEGER_KEY, &value.to_string())?;
+    map.end()
+}
+
+// Deserialization mirrors the untagged serialization, with values such as Functions never being produced. It is
+// implemented by hand (rather than derived) so that a serialized BigInteger can be told apart from a Dictionary.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Object {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ObjectVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct ObjectVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for ObjectVisitor {
+    type Value = Object;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a santa-lang value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Object, E> {
+        Ok(Object::Nil)
+    }
+
+    fn visit_none<E>(self) -> Result<Object, E> {
+        Ok(Object::Nil)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Object, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Object, E> {
+        Ok(Object::Boolean(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Object, E> {
+        Ok(Object::Integer(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Object, E> {
+        Ok(Object::from(BigInt::from(value)))
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<Object, E> {
+        Ok(Object::from(BigInt::from(value)))
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<Object, E> {
+        Ok(Object::from(BigInt::from(value)))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Object, E> {
+        Ok(Object::Decimal(OrderedFloat(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Object, E> {
+        Ok(Object::String(value.to_owned()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Object, E> {
+        Ok(Object::String(value))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Object, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut list = Vector::new();
+        while let Some(element) = seq.next_element()? {
+            list.push_back(element);
+        }
+        Ok(Object::List(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Object, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut dictionary: HashMap<Rc<Object>, Rc<Object>, BuildHasherDefault<DefaultHasher>> = HashMap::default();
+        while let Some((key, value)) = map.next_entry()? {
+            dictionary.insert(key, value);
+        }
+        if dictionary.len() == 1 {
+            if let Some(Object::String(digits)) = dictionary
+                .get(&Object::String(BIG_INTEGER_KEY.to_owned()))
+                .map(|value| &**value)
+            {
+                return digits
+                    .parse::<BigInt>()
+                    .map(Object::from)
+                    .map_err(serde::de::Error::custom);
+            }
+        }
+        Ok(Object::Dictionary(dictionary))
+    }
+}
+
+// Each value's layout is built once, with the width of its canonical form computed bottom-up from its
+// elements, so that deciding between an inline and multiline layout does not re-render the same subtree
+// at every level of nesting.
+enum Layout {
+    Flat(String),
+    Nested {
+        open: String,
+        entries: Vec<(Option<Layout>, Layout)>,
+        close: &'static str,
+        width: usize,
+    },
+}
+
+impl Layout {
+    fn of(object: &Object) -> Self {
+        let (open, entries, close): (String, Vec<(Option<Layout>, Layout)>, &'static str) = match object {
+            Object::List(v) if !v.is_empty() => (
+                "[".to_owned(),
+                v.iter().map(|element| (None, Self::of(element))).collect(),
+                "]",
+            ),
+            Object::Set(v) if !v.is_empty() => (
+                "{".to_owned(),
+                sorted_elements(v).map(|element| (None, Self::of(element))).collect(),
+                "}",
+            ),
+            Object::Dictionary(v) if !v.is_empty() => (
+                "#{".to_owned(),
+                sorted_entries(v)
+                    .map(|(key, value)| (Some(Self::of(key)), Self::of(value)))
+                    .collect(),
+                "}",
+            ),
+            Object::Grid(grid) if !grid.is_empty() => (
+                "Grid([".to_owned(),
+                grid.rows().map(|row| (None, Self::of(&Object::List(row)))).collect(),
+                "])",
+            ),
+            Object::Variant { name, fields, .. } if !fields.is_empty() => (
+                format!("{}(", name),
+                fields.iter().map(|field| (None, Self::of(field))).collect(),
+                ")",
+            ),
+            Object::Return(v) | Object::Break(v) => return Self::of(v),
+            _ => return Self::Flat(object.to_string()),
+        };
+
+        let width = open.chars().count()
+            + entries
+                .iter()
+                .map(|(key, value)| key.as_ref().map_or(0, |key| key.width() + 2) + value.width())
+                .sum::<usize>()
+            + (entries.len() - 1) * 2
+            + close.len();
+        Self::Nested {
+            open,
+            entries,
+            close,
+            width,
+        }
+    }
+
+    fn width(&self) -> usize {
+        match self {
+            Self::Flat(flat) => flat.chars().count(),
+            Self::Nested { width, .. } => *width,
+        }
+    }
+
+    fn flat(&self) -> String {
+        match self {
+            Self::Flat(flat) => flat.clone(),
+            Self::Nested {
+                open, entries, close, ..
+            } => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(key, value)| match key {
+                        Some(key) => format!("{}: {}", key.flat(), value.flat()),
+                        None => value.flat(),
+                    })
+                    .collect();
+                format!("{}{}{}", open, entries.join(", "), close)
+            }
+        }
+    }
+
+    fn render(&self, indent: usize, prefix: usize) -> String {
+        let Self::Nested {
+            open, entries, close, ..
+        } = self
+        else {
+            return self.flat();
+        };
+        if indent + prefix + self.width() <= PRETTY_WIDTH {
+            return self.flat();
+        }
+
+        let nested = indent + PRETTY_INDENT;
+        let padding = " ".repeat(nested);
+        let entries: Vec<String> = entries
+            .iter()
+            .map(|(key, value)| match key {
+                Some(key) => {
+                    let key = key.render(nested, 0);
+                    let value = value.render(nested, key.chars().count() + 2);
+                    format!("{}{}: {}", padding, key, value)
+                }
+                None => format!("{}{}", padding, value.render(nested, 0)),
+            })
+            .collect();
+        format!("{}\n{}\n{}{}", open, entries.join(",\n"), " ".repeat(indent), close)
+    }
+}
+
+// Sets and Dictionaries are displayed in `Ord` order, so that their string form does not depend on
+// hash iteration order (which may differ between runs and implementations).
+fn sorted_elements(set: &HashSet<Rc<Object>, BuildHasherDefault<DefaultHasher>>) -> impl Iterator<Item = &Rc<Object>> {
+    let mut elements: Vec<&Rc<Object>> = set.iter().collect();
+    elements.sort();
+    elements.into_iter()
+}
+
+fn sorted_entries(
+    map: &HashMap<Rc<Object>, Rc<Object>, BuildHasherDefault<DefaultHasher>>,
+) -> impl Iterator<Item = (&Rc<Object>, &Rc<Object>)> {
+    let mut entries: Vec<(&Rc<Object>, &Rc<Object>)> = map.iter().collect();
+    entries.sort_by_key(|(key, _)| *key);
+    entries.into_iter()
 }
 
 impl fmt::Display for Object {
@@ -95,6 +482,7 @@ impl fmt::Display for Object {
         let s = match self {
             Self::Nil => "nil".to_owned(),
             Self::Integer(v) => format!("{}", v),
+            Self::BigInteger(v) => format!("{}", v),
             Self::Decimal(v) => format!("{}", v),
             Self::Boolean(v) => format!("{}", v),
             Self::String(v) => format!("\"{}\"", v),
@@ -104,16 +492,25 @@ impl fmt::Display for Object {
                 format!("[{}]", elements.join(", "))
             }
             Self::Set(v) => {
-                let elements: Vec<String> = v.iter().map(|element| element.to_string()).collect();
+                let elements: Vec<String> = sorted_elements(v).map(|element| element.to_string()).collect();
                 format!("{{{}}}", elements.join(", "))
             }
             Self::Dictionary(v) => {
-                let formatted: Vec<String> = v.iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+                let formatted: Vec<String> = sorted_entries(v)
+                    .map(|(key, value)| format!("{}: {}", key, value))
+                    .collect();
                 format!("#{{{}}}", formatted.join(", "))
             }
+            Self::PriorityQueue(queue) => queue.to_string(),
+            Self::Grid(grid) => grid.to_string(),
             Self::LazySequence(sequence) => sequence.to_string(),
 
             Self::Function(function) => format!("{}", function),
+            Self::Variant { name, fields, .. } if fields.is_empty() => name.to_string(),
+            Self::Variant { name, fields, .. } => {
+                let formatted: Vec<String> = fields.iter().map(|field| field.to_string()).collect();
+                format!("{}({})", name, formatted.join(", "))
+            }
 
             Self::Placeholder => "_".to_owned(),
             Self::Return(v) => format!("{}", v),
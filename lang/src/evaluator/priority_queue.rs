@@ -0,0 +1,102 @@
+use crate::evaluator::Object;
+use im_rc::OrdMap;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+// A persistent min-priority queue, with each value ordered by its priority and then by insertion,
+// so that values of equal priority are dequeued in the order they were enqueued.
+// The insertion sequence is an implementation detail, and so is excluded from equality and ordering.
+#[derive(Debug, Clone, Default)]
+pub struct PriorityQueue {
+    entries: OrdMap<(Rc<Object>, u64), Rc<Object>>,
+    sequence: u64,
+}
+
+impl PriorityQueue {
+    pub fn enqueue(&self, value: Rc<Object>, priority: Rc<Object>) -> Self {
+        Self {
+            entries: self.entries.update((priority, self.sequence), value),
+            sequence: self.sequence + 1,
+        }
+    }
+
+    pub fn dequeue(&self) -> Option<(Rc<Object>, Self)> {
+        let (min, entries) = self.entries.without_min_with_key();
+        min.map(|(_, value)| {
+            (
+                value,
+                Self {
+                    entries,
+                    sequence: self.sequence,
+                },
+            )
+        })
+    }
+
+    pub fn peek(&self) -> Option<&Rc<Object>> {
+        self.entries.get_min().map(|(_, value)| value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Rc<Object>, &Rc<Object>)> {
+        self.entries.iter().map(|((priority, _), value)| (value, priority))
+    }
+}
+
+impl PartialEq for PriorityQueue {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for PriorityQueue {}
+
+impl PartialOrd for PriorityQueue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PriorityQueue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl Hash for PriorityQueue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for entry in self.iter() {
+            entry.hash(state);
+        }
+    }
+}
+
+impl fmt::Display for PriorityQueue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries: Vec<String> = self
+            .iter()
+            .map(|(value, priority)| format!("[{}, {}]", value, priority))
+            .collect();
+        write!(f, "PriorityQueue([{}])", entries.join(", "))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PriorityQueue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter().map(|(value, priority)| [value, priority]))
+    }
+}
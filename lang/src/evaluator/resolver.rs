@@ -0,0 +1,634 @@
+use crate::evaluator::{EnvironmentRef, Evaluator, RuntimeErr};
+use crate::lexer::Location;
+use crate::parser::ast::{Expression, ExpressionKind, Infix, MatchCase, Program, Statement, StatementKind, StringPart};
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+// Each scope mirrors an `Environment` which is created at runtime; the program (or section) itself,
+// blocks, closure calls and match cases which bind variables.
+struct Scope {
+    variables: HashMap<String, Variable>,
+    slots: usize,
+    wildcard: bool,
+}
+
+#[derive(Clone, Copy)]
+struct Variable {
+    binding: Binding,
+    declared: bool,
+}
+
+#[derive(Clone, Copy)]
+enum Binding {
+    Slot(usize),
+    Named,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Declaration {
+    Let,
+    Parameter,
+}
+
+enum Resolution {
+    Slot { depth: usize, slot: usize },
+    Named,
+    Unresolved,
+}
+
+struct Resolver<'a> {
+    evaluator: &'a Evaluator,
+    environment: EnvironmentRef,
+    scopes: Vec<Scope>,
+}
+
+// Assigns each variable a (depth, slot) address ahead of evaluation, so that lookups no longer need to
+// compare names along the environment chain. Variables which are only known by name at runtime (imports
+// and host-provided values) are left as identifiers, whilst any which can not be found are reported upfront.
+pub fn resolve(evaluator: &Evaluator, program: &Program, environment: EnvironmentRef) -> Result<Program, RuntimeErr> {
+    let slots = environment.borrow().slot_count();
+    let mut resolver = Resolver {
+        evaluator,
+        environment,
+        scopes: vec![],
+    };
+
+    resolver.begin_scope(&program.statements, slots);
+    let statements = resolver.statements(&program.statements)?;
+
+    Ok(Program {
+        statements,
+        source: program.source,
+    })
+}
+
+impl Resolver<'_> {
+    fn begin_scope(&mut self, statements: &[Statement], slots: usize) {
+        let mut scope = Scope {
+            variables: HashMap::new(),
+            slots,
+            wildcard: false,
+        };
+
+        // Variables are reserved upfront so that closures are able to refer to those declared after them.
+        for statement in statements {
+            match &statement.kind {
+                StatementKind::Expression(expression) | StatementKind::Attributed { expression, .. } => {
+                    if let ExpressionKind::Let { name, .. } | ExpressionKind::MutableLet { name, .. } = &expression.kind
+                    {
+                        let mut names = vec![];
+                        collect_pattern_names(name, &mut names);
+                        for name in names {
+                            if !scope.variables.contains_key(&name) {
+                                scope.variables.insert(
+                                    name,
+                                    Variable {
+                                        binding: Binding::Slot(scope.slots),
+                                        declared: false,
+                                    },
+                                );
+                                scope.slots += 1;
+                            }
+                        }
+                    }
+                }
+                StatementKind::Import { bindings: None, .. } => scope.wildcard = true,
+                _ => {}
+            }
+        }
+
+        self.scopes.push(scope);
+    }
+
+    fn begin_empty_scope(&mut self) {
+        self.begin_scope(&[], 0);
+    }
+
+    fn end_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    fn declare(&mut self, name: &str, declaration: Declaration, source: Location) -> Result<usize, RuntimeErr> {
+        let already_declared = || RuntimeErr {
+            message: format!("Variable '{}' has already been declared", name),
+            source,
+            trace: vec![],
+        };
+
+        if self.scopes.len() == 1 && self.environment.borrow().is_declared(name) {
+            return Err(already_declared());
+        }
+
+        let scope = self.scopes.last_mut().unwrap();
+        match scope.variables.get_mut(name) {
+            Some(Variable {
+                binding: Binding::Slot(slot),
+                declared,
+            }) if !*declared || declaration == Declaration::Parameter => {
+                *declared = true;
+                Ok(*slot)
+            }
+            Some(_) => Err(already_declared()),
+            None => {
+                let slot = scope.slots;
+                scope.slots += 1;
+                scope.variables.insert(
+                    name.to_owned(),
+                    Variable {
+                        binding: Binding::Slot(slot),
+                        declared: true,
+                    },
+                );
+                Ok(slot)
+            }
+        }
+    }
+
+    fn declare_named(&mut self, name: &str) {
+        self.scopes.last_mut().unwrap().variables.insert(
+            name.to_owned(),
+            Variable {
+                binding: Binding::Named,
+                declared: true,
+            },
+        );
+    }
+
+    fn lookup(&self, name: &str) -> Resolution {
+        for (depth, scope) in self.scopes.iter().rev().enumerate() {
+            if let Some(variable) = scope.variables.get(name) {
+                return match variable.binding {
+                    Binding::Slot(slot) => Resolution::Slot { depth, slot },
+                    Binding::Named => Resolution::Named,
+                };
+            }
+            if scope.wildcard {
+                return Resolution::Named;
+            }
+        }
+
+        let mut depth = self.scopes.len() - 1;
+        let mut environment = Some(Rc::clone(&self.environment));
+        while let Some(current) = environment {
+            let current = current.borrow();
+            if let Some(slot) = current.slot_of(name) {
+                return Resolution::Slot { depth, slot };
+            }
+            if current.is_declared(name) {
+                return Resolution::Named;
+            }
+            depth += 1;
+            environment = current.outer();
+        }
+
+        if self.evaluator.is_global(name) {
+            return Resolution::Named;
+        }
+
+        Resolution::Unresolved
+    }
+
+    fn statements(&mut self, statements: &[Statement]) -> Result<Vec<Statement>, RuntimeErr> {
+        statements.iter().map(|statement| self.statement(statement)).collect()
+    }
+
+    fn statement(&mut self, statement: &Statement) -> Result<Statement, RuntimeErr> {
+        let kind = match &statement.kind {
+            StatementKind::Return(value) => StatementKind::Return(Box::new(self.expression(value)?)),
+            StatementKind::Break(value) => StatementKind::Break(Box::new(self.expression(value)?)),
+            StatementKind::Expression(expression) => StatementKind::Expression(Box::new(self.expression(expression)?)),
+            StatementKind::Attributed {
+                name,
+                attributes,
+                expression,
+            } => StatementKind::Attributed {
+                name: name.to_owned(),
+                attributes: attributes.clone(),
+                expression: Box::new(self.expression(expression)?),
+            },
+            StatementKind::Block(statements) => {
+                self.begin_scope(statements, 0);
+                let statements = self.statements(statements);
+                self.end_scope();
+                StatementKind::Block(statements?)
+            }
+            // Sections are resolved when they are evaluated, as they are done so within their own environment.
+            StatementKind::Section { .. } | StatementKind::Comment(_) => statement.kind.clone(),
+            StatementKind::Import { bindings, .. } => {
+                for binding in bindings.iter().flatten() {
+                    self.declare_named(binding);
+                }
+                statement.kind.clone()
+            }
+            StatementKind::Type { variants, .. } => {
+                for variant in variants {
+                    self.declare_named(&variant.name);
+                }
+                statement.kind.clone()
+            }
+        };
+
+        Ok(Statement {
+            kind,
+            source: statement.source,
+            preceded_by_blank_line: statement.preceded_by_blank_line,
+            trailing_comment: statement.trailing_comment.clone(),
+        })
+    }
+
+    fn expressions(&mut self, expressions: &[Expression]) -> Result<Vec<Expression>, RuntimeErr> {
+        expressions
+            .iter()
+            .map(|expression| self.expression(expression))
+            .collect()
+    }
+
+    fn boxed(&mut self, expression: &Expression) -> Result<Box<Expression>, RuntimeErr> {
+        Ok(Box::new(self.expression(expression)?))
+    }
+
+    fn expression(&mut self, expression: &Expression) -> Result<Expression, RuntimeErr> {
+        let kind = match &expression.kind {
+            ExpressionKind::Identifier(name) => match self.lookup(name) {
+                Resolution::Slot { depth, slot } => ExpressionKind::ResolvedIdentifier {
+                    name: name.to_owned(),
+                    depth,
+                    slot,
+                },
+                Resolution::Named => expression.kind.clone(),
+                Resolution::Unresolved => {
+                    return Err(RuntimeErr {
+                        message: format!("Identifier can not be found: {}", name),
+                        source: expression.source,
+                        trace: vec![],
+                    });
+                }
+            },
+            ExpressionKind::Let { name, value } => {
+                let value = self.boxed(value)?;
+                ExpressionKind::Let {
+                    name: Box::new(self.pattern(name, Declaration::Let)?),
+                    value,
+                }
+            }
+            ExpressionKind::MutableLet { name, value } => {
+                let value = self.boxed(value)?;
+                ExpressionKind::MutableLet {
+                    name: Box::new(self.pattern(name, Declaration::Let)?),
+                    value,
+                }
+            }
+            ExpressionKind::Assign { name, value } => {
+                let value = self.boxed(value)?;
+                ExpressionKind::Assign {
+                    name: Box::new(self.assignment_target(name)?),
+                    value,
+                }
+            }
+            ExpressionKind::CompoundAssign { name, operator, value } => ExpressionKind::CompoundAssign {
+                name: self.boxed(name)?,
+                operator: self.operator(operator)?,
+                value: self.boxed(value)?,
+            },
+            ExpressionKind::List(elements) => ExpressionKind::List(self.expressions(elements)?),
+            ExpressionKind::Set(elements) => ExpressionKind::Set(self.expressions(elements)?),
+            ExpressionKind::Dictionary(entries) => ExpressionKind::Dictionary(
+                entries
+                    .iter()
+                    .map(|(key, value)| Ok((self.expression(key)?, self.expression(value)?)))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            ),
+            ExpressionKind::InclusiveRange { from, to } => ExpressionKind::InclusiveRange {
+                from: self.boxed(from)?,
+                to: self.boxed(to)?,
+            },
+            ExpressionKind::ExclusiveRange { from, until } => ExpressionKind::ExclusiveRange {
+                from: self.boxed(from)?,
+                until: self.boxed(until)?,
+            },
+            ExpressionKind::UnboundedRange { from } => ExpressionKind::UnboundedRange {
+                from: self.boxed(from)?,
+            },
+            ExpressionKind::Function { parameters, body } => {
+                self.begin_empty_scope();
+                let function = self.function(parameters, body);
+                self.end_scope();
+                function?
+            }
+            ExpressionKind::Index { left, index } => ExpressionKind::Index {
+                left: self.boxed(left)?,
+                index: self.boxed(index)?,
+            },
+            ExpressionKind::Call { function, arguments } => ExpressionKind::Call {
+                function: self.boxed(function)?,
+                arguments: self.expressions(arguments)?,
+            },
+            ExpressionKind::If {
+                condition,
+                consequence,
+                alternative,
+            } => ExpressionKind::If {
+                condition: self.boxed(condition)?,
+                consequence: Box::new(self.statement(consequence)?),
+                alternative: match alternative {
+                    Some(alternative) => Some(Box::new(self.statement(alternative)?)),
+                    None => None,
+                },
+            },
+            ExpressionKind::Match { subject, cases } => ExpressionKind::Match {
+                subject: self.boxed(subject)?,
+                cases: cases
+                    .iter()
+                    .map(|case| self.match_case(case))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            },
+            ExpressionKind::Prefix { operator, right } => ExpressionKind::Prefix {
+                operator: operator.clone(),
+                right: self.boxed(right)?,
+            },
+            ExpressionKind::Infix { operator, left, right } => {
+                // Placeholder operands are evaluated as closures, whose parameters are assigned by name.
+                let left_placeholder = matches!(left.kind, ExpressionKind::Placeholder);
+                let right_placeholder = matches!(right.kind, ExpressionKind::Placeholder);
+                if !left_placeholder && !right_placeholder {
+                    self.infix(operator, left, right)?
+                } else {
+                    self.begin_empty_scope();
+                    if left_placeholder {
+                        self.declare_named("a");
+                    }
+                    if right_placeholder {
+                        self.declare_named("b");
+                    }
+                    let infix = self.infix(operator, left, right);
+                    self.end_scope();
+                    infix?
+                }
+            }
+            ExpressionKind::FunctionThread { initial, functions } => ExpressionKind::FunctionThread {
+                initial: self.boxed(initial)?,
+                functions: self.expressions(functions)?,
+            },
+            ExpressionKind::FunctionComposition(functions) => {
+                ExpressionKind::FunctionComposition(self.expressions(functions)?)
+            }
+            ExpressionKind::InterpolatedString(parts) => ExpressionKind::InterpolatedString(
+                parts
+                    .iter()
+                    .map(|part| match part {
+                        StringPart::Literal(literal) => Ok(StringPart::Literal(literal.to_owned())),
+                        StringPart::Expression(expression) => Ok(StringPart::Expression(self.expression(expression)?)),
+                    })
+                    .collect::<Result<_, RuntimeErr>>()?,
+            ),
+            ExpressionKind::Spread(value) => ExpressionKind::Spread(self.boxed(value)?),
+            _ => expression.kind.clone(),
+        };
+
+        Ok(Expression {
+            kind,
+            source: expression.source,
+        })
+    }
+
+    fn operator(&mut self, operator: &Infix) -> Result<Infix, RuntimeErr> {
+        match operator {
+            Infix::Call(function) => Ok(Infix::Call(self.boxed(function)?)),
+            operator => Ok(operator.clone()),
+        }
+    }
+
+    fn infix(&mut self, operator: &Infix, left: &Expression, right: &Expression) -> Result<ExpressionKind, RuntimeErr> {
+        Ok(ExpressionKind::Infix {
+            operator: self.operator(operator)?,
+            left: self.boxed(left)?,
+            right: self.boxed(right)?,
+        })
+    }
+
+    fn function(&mut self, parameters: &[Expression], body: &Statement) -> Result<ExpressionKind, RuntimeErr> {
+        Ok(ExpressionKind::Function {
+            parameters: parameters
+                .iter()
+                .map(|parameter| self.pattern(parameter, Declaration::Parameter))
+                .collect::<Result<_, RuntimeErr>>()?,
+            body: Box::new(self.statement(body)?),
+        })
+    }
+
+    fn assignment_target(&mut self, target: &Expression) -> Result<Expression, RuntimeErr> {
+        let ExpressionKind::Identifier(name) = &target.kind else {
+            return self.expression(target);
+        };
+
+        let kind = match self.lookup(name) {
+            Resolution::Slot { depth, slot } => ExpressionKind::ResolvedIdentifier {
+                name: name.to_owned(),
+                depth,
+                slot,
+            },
+            Resolution::Named => target.kind.clone(),
+            Resolution::Unresolved => {
+                return Err(RuntimeErr {
+                    message: format!("Variable '{}' has not been declared", name),
+                    source: target.source,
+                    trace: vec![],
+                });
+            }
+        };
+
+        Ok(Expression {
+            kind,
+            source: target.source,
+        })
+    }
+
+    fn pattern(&mut self, pattern: &Expression, declaration: Declaration) -> Result<Expression, RuntimeErr> {
+        let kind = match &pattern.kind {
+            ExpressionKind::Identifier(name) => ExpressionKind::ResolvedIdentifier {
+                name: name.to_owned(),
+                depth: 0,
+                slot: self.declare(name, declaration, pattern.source)?,
+            },
+            ExpressionKind::RestIdentifier(name) => ExpressionKind::ResolvedRestIdentifier {
+                name: name.to_owned(),
+                slot: self.declare(name, declaration, pattern.source)?,
+            },
+            ExpressionKind::IdentifierListPattern(elements) => ExpressionKind::IdentifierListPattern(
+                elements
+                    .iter()
+                    .map(|element| self.pattern(element, declaration))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            ),
+            ExpressionKind::IdentifierDictionaryPattern(elements) => ExpressionKind::IdentifierDictionaryPattern(
+                elements
+                    .iter()
+                    .map(|element| self.pattern(element, declaration))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            ),
+            // Parameter keys are required to be string literals, whereas let keys are evaluated.
+            ExpressionKind::DictionaryEntryPattern { key, value } => ExpressionKind::DictionaryEntryPattern {
+                key: match declaration {
+                    Declaration::Let => self.boxed(key)?,
+                    Declaration::Parameter => key.clone(),
+                },
+                value: Box::new(self.pattern(value, declaration)?),
+            },
+            _ => pattern.kind.clone(),
+        };
+
+        Ok(Expression {
+            kind,
+            source: pattern.source,
+        })
+    }
+
+    fn match_case(&mut self, case: &MatchCase) -> Result<MatchCase, RuntimeErr> {
+        let binds = matches!(
+            case.pattern.kind,
+            ExpressionKind::Identifier(_)
+                | ExpressionKind::ListMatchPattern(_)
+                | ExpressionKind::DictionaryMatchPattern(_)
+                | ExpressionKind::VariantMatchPattern { .. }
+                | ExpressionKind::OrMatchPattern(_)
+                | ExpressionKind::BindingMatchPattern { .. }
+                | ExpressionKind::StringMatchPattern { .. }
+                | ExpressionKind::RegexMatchPattern { .. }
+        );
+
+        if !binds {
+            let pattern = match case.pattern.kind {
+                ExpressionKind::InclusiveRange { .. }
+                | ExpressionKind::ExclusiveRange { .. }
+                | ExpressionKind::UnboundedRange { .. } => *case.pattern.clone(),
+                _ => self.expression(&case.pattern)?,
+            };
+            return self.match_consequence(case, pattern);
+        }
+
+        self.begin_empty_scope();
+        let case = self
+            .match_pattern(&case.pattern)
+            .and_then(|pattern| self.match_consequence(case, pattern));
+        self.end_scope();
+        case
+    }
+
+    fn match_consequence(&mut self, case: &MatchCase, pattern: Expression) -> Result<MatchCase, RuntimeErr> {
+        Ok(MatchCase {
+            pattern: Box::new(pattern),
+            guard: match &case.guard {
+                Some(guard) => Some(self.expression(guard)?),
+                None => None,
+            },
+            consequence: Box::new(self.statement(&case.consequence)?),
+            trailing_comment: case.trailing_comment.clone(),
+        })
+    }
+
+    fn match_pattern(&mut self, pattern: &Expression) -> Result<Expression, RuntimeErr> {
+        let kind = match &pattern.kind {
+            ExpressionKind::Identifier(_) | ExpressionKind::RestIdentifier(_) => {
+                return self.pattern(pattern, Declaration::Let);
+            }
+            ExpressionKind::ListMatchPattern(elements) => ExpressionKind::ListMatchPattern(
+                elements
+                    .iter()
+                    .map(|element| self.match_pattern(element))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            ),
+            ExpressionKind::DictionaryMatchPattern(elements) => ExpressionKind::DictionaryMatchPattern(
+                elements
+                    .iter()
+                    .map(|element| self.match_pattern(element))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            ),
+            ExpressionKind::DictionaryEntryPattern { key, value } => ExpressionKind::DictionaryEntryPattern {
+                key: self.boxed(key)?,
+                value: Box::new(self.match_pattern(value)?),
+            },
+            ExpressionKind::VariantMatchPattern { name, fields } => ExpressionKind::VariantMatchPattern {
+                name: name.to_owned(),
+                fields: match fields {
+                    Some(fields) => Some(
+                        fields
+                            .iter()
+                            .map(|field| self.match_pattern(field))
+                            .collect::<Result<_, RuntimeErr>>()?,
+                    ),
+                    None => None,
+                },
+            },
+            ExpressionKind::OrMatchPattern(alternatives) => {
+                ExpressionKind::OrMatchPattern(self.match_alternatives(alternatives)?)
+            }
+            ExpressionKind::BindingMatchPattern { name, pattern } => ExpressionKind::BindingMatchPattern {
+                name: Box::new(self.pattern(name, Declaration::Let)?),
+                pattern: Box::new(self.match_pattern(pattern)?),
+            },
+            ExpressionKind::StringMatchPattern {
+                prefix,
+                remainder,
+                suffix,
+            } => ExpressionKind::StringMatchPattern {
+                prefix: prefix.clone(),
+                remainder: Box::new(self.match_pattern(remainder)?),
+                suffix: suffix.clone(),
+            },
+            ExpressionKind::RegexMatchPattern { pattern, captures } => ExpressionKind::RegexMatchPattern {
+                pattern: pattern.to_owned(),
+                captures: captures
+                    .iter()
+                    .map(|capture| self.pattern(capture, Declaration::Let))
+                    .collect::<Result<_, RuntimeErr>>()?,
+            },
+            ExpressionKind::InclusiveRange { .. }
+            | ExpressionKind::ExclusiveRange { .. }
+            | ExpressionKind::UnboundedRange { .. }
+            | ExpressionKind::Placeholder => pattern.kind.clone(),
+            _ => return self.expression(pattern),
+        };
+
+        Ok(Expression {
+            kind,
+            source: pattern.source,
+        })
+    }
+
+    // Each alternative binds the same variables (as enforced by the parser), which share a slot as only one
+    // alternative is ever matched upon.
+    fn match_alternatives(&mut self, alternatives: &[Expression]) -> Result<Vec<Expression>, RuntimeErr> {
+        let scope = self.scopes.last().unwrap();
+        let declared: HashSet<String> = scope
+            .variables
+            .iter()
+            .filter(|(_, variable)| variable.declared)
+            .map(|(name, _)| name.to_owned())
+            .collect();
+
+        let mut resolved = Vec::with_capacity(alternatives.len());
+        for alternative in alternatives {
+            for (name, variable) in self.scopes.last_mut().unwrap().variables.iter_mut() {
+                if !declared.contains(name) {
+                    variable.declared = false;
+                }
+            }
+            resolved.push(self.match_pattern(alternative)?);
+        }
+
+        Ok(resolved)
+    }
+}
+
+fn collect_pattern_names(pattern: &Expression, names: &mut Vec<String>) {
+    match &pattern.kind {
+        ExpressionKind::Identifier(name) | ExpressionKind::RestIdentifier(name) => names.push(name.to_owned()),
+        ExpressionKind::IdentifierListPattern(elements) | ExpressionKind::IdentifierDictionaryPattern(elements) => {
+            for element in elements {
+                collect_pattern_names(element, names);
+            }
+        }
+        ExpressionKind::DictionaryEntryPattern { value, .. } => collect_pattern_names(value, names),
+        _ => {}
+    }
+}
@@ -165,3 +165,135 @@ test_eval! {
         deeply_nested_three_levels
     )
 }
+
+test_eval! {
+    suite index_assignment;
+
+    ("let mut xs = [1, 2, 3]; xs[1] = 5; xs", "[1, 5, 3]", list_index_assignment),
+    ("let mut xs = [1, 2, 3]; xs[-1] = 5; xs", "[1, 2, 5]", negative_list_index_assignment),
+    ("let mut xs = [1]; xs[3] = 4; xs", "[1, nil, nil, 4]", list_index_assignment_beyond_length),
+    ("let mut xs = [1]; xs[-2] = 4;", "Unable to assign to List index -2, length is 1", negative_list_index_assignment_beyond_length),
+    ("let mut xs = [1, 2, 3]; xs[1] = 5", "5", index_assignment_evaluates_to_value),
+    (
+        r#"
+            let mut grid = [[".", "."], [".", "."]];
+            grid[1][0] = "x";
+            grid
+        "#,
+        "[[\".\", \".\"], [\"x\", \".\"]]",
+        nested_list_index_assignment
+    ),
+    (
+        r#"
+            let mut scores = #{"a": 1};
+            scores["b"] = 2;
+            scores
+        "#,
+        "#{\"a\": 1, \"b\": 2}",
+        dictionary_index_assignment
+    ),
+    (
+        r#"
+            let mut graph = #{"a": [1, 2], "b": [3]};
+            graph["a"][0] = 10;
+            graph["a"]
+        "#,
+        "[10, 2]",
+        nested_dictionary_list_index_assignment
+    ),
+    (
+        r#"
+            let original = [1, 2, 3];
+            let mut copy = original;
+            copy[0] = 100;
+            [original, copy]
+        "#,
+        "[[1, 2, 3], [100, 2, 3]]",
+        index_assignment_is_persistent
+    ),
+    (
+        r#"
+            let mut grid = [[0, 0], [0, 0]];
+            let mark = |y, x| { grid[y][x] = 1; };
+            mark(0, 1);
+            grid
+        "#,
+        "[[0, 1], [0, 0]]",
+        index_assignment_from_within_function
+    ),
+    ("let mut g = dense_grid(\"ab\\ncd\"); g[[1, 0]] = \"x\"; g", "Grid([[\"a\", \"x\"], [\"c\", \"d\"]])", grid_index_assignment),
+    ("let mut g = dense_grid(\"ab\"); g[[2, 0]] = \"x\";", "Unable to assign to Grid index [2, 0], size is 2x1", grid_index_assignment_out_of_bounds),
+    ("let xs = [1, 2, 3]; xs[0] = 5;", "Variable 'xs' is not mutable", unable_to_index_assign_variable_which_is_not_mutable),
+    ("xs[0] = 5;", "Identifier can not be found: xs", unable_to_index_assign_variable_which_has_not_been_declared),
+    ("let mut xs = \"abc\"; xs[0] = \"z\";", "Unable to perform index assignment, found: String[Integer]", unable_to_index_assign_string),
+    ("let mut xs = #{}; xs[#{}] = 1;", "Unable to use a Dictionary as a Dictionary key", unable_to_index_assign_unhashable_dictionary_key),
+    ("let mut xs = #{}; xs[\"a\"][\"b\"] = 1;", "Unable to perform index assignment, found: Nil[String]", unable_to_index_assign_missing_nested_collection),
+    ("[1, 2][0] = 5;", "Unable to assign to: ([1, 2][0])", unable_to_assign_to_unsupported_target),
+    ("1 = 5;", "Unable to assign to: 1", unable_to_assign_to_literal)
+}
+
+test_eval! {
+    suite compound_assignment;
+
+    ("let mut x = 10; x += 5; x", "15", plus_assignment),
+    ("let mut x = 10; x -= 5; x", "5", minus_assignment),
+    ("let mut x = 10; x *= 5; x", "50", asterisk_assignment),
+    ("let mut x = 10; x /= 4; x", "2", slash_assignment),
+    ("let mut x = 10; x %= 4; x", "2", modulo_assignment),
+    ("let mut x = 10; x += 5", "15", compound_assignment_evaluates_to_value),
+    ("let mut xs = [1]; xs += [2]; xs", "[1, 2]", list_plus_assignment),
+    ("let mut xs = {1}; xs += {2}; xs", "{1, 2}", set_plus_assignment),
+    ("let mut s = \"a\"; s += \"b\"; s", "\"ab\"", string_plus_assignment),
+    ("let mut x = 9223372036854775807; x += 1; x", "9223372036854775808", plus_assignment_promotes_integer),
+    (
+        r#"
+            let mut grid = [[1, 2], [3, 4]];
+            grid[1][0] *= 10;
+            grid
+        "#,
+        "[[1, 2], [30, 4]]",
+        nested_index_compound_assignment
+    ),
+    (
+        r#"
+            let mut counts = #{"a": 1};
+            counts["a"] += 1;
+            counts
+        "#,
+        "#{\"a\": 2}",
+        dictionary_index_compound_assignment
+    ),
+    (
+        r#"
+            let mut calls = 0;
+            let mut xs = [1, 2, 3];
+            let next = || { calls += 1; calls };
+            xs[next()] += 10;
+            [xs, calls]
+        "#,
+        "[[1, 12, 3], 1]",
+        index_compound_assignment_evaluates_index_once
+    ),
+    (
+        r#"
+            let mut row = 0;
+            let mut grid = [[1, 2], [3, 4]];
+            grid[row += 1][row] *= 10;
+            [grid, row]
+        "#,
+        "[[[1, 2], [3, 40]], 1]",
+        nested_index_compound_assignment_evaluates_indices_once
+    ),
+    (
+        r#"
+            let mut total = 0;
+            [1, 2, 3] |> each(|x| total += x);
+            total
+        "#,
+        "6",
+        compound_assignment_from_within_function
+    ),
+    ("let x = 1; x += 1;", "Variable 'x' is not mutable", unable_to_compound_assign_variable_which_is_not_mutable),
+    ("x += 1;", "Identifier can not be found: x", unable_to_compound_assign_variable_which_has_not_been_declared),
+    ("let mut x = 1; x += \"a\";", "Unsupported operation: Integer + String", unable_to_compound_assign_unsupported_operation)
+}
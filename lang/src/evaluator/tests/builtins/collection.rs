@@ -180,7 +180,9 @@ test_eval! {
     ("sum(#{})", "0", empty_dictionary),
     ("sum(#{1: 2, 3: 4})", "6", dictionary_with_elements),
     ("sum(0..0)", "0", empty_lazy_sequence),
-    ("sum(0..2)", "1", lazy_sequence_with_elements)
+    ("sum(0..2)", "1", lazy_sequence_with_elements),
+    ("sum([9223372036854775807, 1, 1])", "9223372036854775809", list_overflowing_integers),
+    ("sum([98765432109876543210, -98765432109876543210, 1])", "1", list_with_big_integers)
 }
 
 test_eval! {
@@ -194,7 +196,8 @@ test_eval! {
     ("max(#{1: 2, 3: 4})", "4", dictionary_with_elements),
     ("max(0..0)", "nil", empty_lazy_sequence),
     ("max(0..2)", "1", lazy_sequence_with_elements),
-    ("max(1, 2)", "2", multi_argument)
+    ("max(1, 2)", "2", multi_argument),
+    ("max([-98765432109876543210, 1, 98765432109876543210])", "98765432109876543210", list_with_big_integers)
 }
 
 test_eval! {
@@ -208,7 +211,8 @@ test_eval! {
     ("min(#{1: 2, 3: 4})", "2", dictionary_with_elements),
     ("min(0..0)", "nil", empty_lazy_sequence),
     ("min(0..2)", "0", lazy_sequence_with_elements),
-    ("min(1, 2)", "1", multi_argument)
+    ("min(1, 2)", "1", multi_argument),
+    ("min([98765432109876543210, 1, -98765432109876543210])", "-98765432109876543210", list_with_big_integers)
 }
 
 test_eval! {
@@ -257,7 +261,7 @@ test_eval! {
     ("set(\"\")", "{}", empty_string),
     ("set(\"ab\")", "{\"a\", \"b\"}", string_with_characters),
     ("set(0..0)", "{}", empty_lazy_sequence),
-    ("set(0..2)", "{1, 0}", lazy_sequence_with_elements)
+    ("set(0..2)", "{0, 1}", lazy_sequence_with_elements)
 }
 
 test_eval! {
@@ -268,7 +272,7 @@ test_eval! {
     ("dict(#{})", "#{}", empty_dictionary),
     ("dict(#{1: 2, 3: 4})", "#{1: 2, 3: 4}", dictionary_with_elements),
     ("zip(0..0, 0..1) |> dict", "#{}", empty_lazy_sequence),
-    ("zip(0..2, 1..3) |> dict", "#{1: 2, 0: 1}", lazy_sequence_with_elements)
+    ("zip(0..2, 1..3) |> dict", "#{0: 1, 1: 2}", lazy_sequence_with_elements)
 }
 
 test_eval! {
@@ -291,10 +295,37 @@ test_eval! {
     suite iterate;
 
     ("iterate(|[a, b]| [b, a + b], [0, 1]) |> skip(9) |> take(1)", "[[34, 55]]", fibonacci),
-    ("iterate(_ * 2, 1) |> take(5)", "[1, 2, 4, 8, 16]", doubles)
+    ("iterate(_ * 2, 1) |> take(5)", "[1, 2, 4, 8, 16]", doubles),
+    ("iterate(|n| if n < 3 { n + 1 } else { error(\"Oops\") }, 0) |> take(5)", "[0, 1, 2]", error_in_generator_ends_sequence)
     // TODO break in iterate?
 }
 
+test_eval! {
+    suite cycle_detect;
+
+    ("cycle_detect(|n| (n + 1) % 4, 0)", "#{\"length\": 4, \"start\": 0, \"states\": [0, 1, 2, 3]}", cycle_from_start),
+    ("cycle_detect(|n| if n < 3 { n + 1 } else { 1 }, 0)", "#{\"length\": 3, \"start\": 1, \"states\": [0, 1, 2, 3]}", cycle_after_prefix),
+    ("cycle_detect(|[a, b]| [b, (a + b) % 3], [0, 1]) |> get(\"length\")", "8", list_states),
+    ("cycle_detect(id, 5)", "#{\"length\": 1, \"start\": 0, \"states\": [5]}", fixed_point),
+    ("cycle_detect(|_| |x| x, 0)", "Unable to use a Function as a cycle state", unhashable_state),
+    ("cycle_detect(|n| if n < 3 { n + 1 } else { error(\"Oops\") }, 0)", "Oops", error_in_generator),
+    ("cycle_detect(1, 0)", "Unexpected argument: cycle_detect(Integer, Integer)", invalid_generator)
+}
+
+test_eval! {
+    suite cycle_state;
+    sut "let cycle = cycle_detect(|n| if n < 3 { n + 1 } else { 1 }, 0);";
+
+    ("cycle_state(2, cycle)", "2", within_states),
+    ("cycle |> cycle_state(1_000_000_000)", "1", beyond_states),
+    ("[4, 5, 6, 7] |> map(cycle_state(_, cycle))", "[1, 2, 3, 1]", repeating_states),
+    ("cycle_state(-1, cycle)", "Expected a non-negative index, found: -1", negative_index),
+    ("cycle_state(1, #{})", "Expected a cycle, as returned by cycle_detect", invalid_cycle),
+    ("cycle_state(5, #{\"start\": 0, \"length\": 0, \"states\": [1]})", "Expected a cycle, as returned by cycle_detect", empty_cycle),
+    ("cycle_state(5, #{\"start\": 1, \"length\": 2, \"states\": [1, 2]})", "Expected a cycle, as returned by cycle_detect", cycle_beyond_states),
+    ("cycle_state(5, #{\"start\": -1, \"length\": 2, \"states\": [1, 2]})", "Expected a cycle, as returned by cycle_detect", negative_start)
+}
+
 test_eval! {
     suite zip;
 
@@ -568,7 +599,7 @@ test_eval! {
     ("assoc(0, 3, [1, 2])", "[3, 2]", list_with_existing_element),
     ("assoc(0, 1, #{})", "#{0: 1}", empty_dictionary),
     ("assoc(1, 1, #{1: 2, 3: 4})", "#{1: 1, 3: 4}", dictionary_with_existing_entry),
-    ("assoc(0, 1, #{1: 2, 3: 4})", "#{1: 2, 3: 4, 0: 1}", dictionary_with_new_entry)
+    ("assoc(0, 1, #{1: 2, 3: 4})", "#{0: 1, 1: 2, 3: 4}", dictionary_with_new_entry)
 }
 
 test_eval! {
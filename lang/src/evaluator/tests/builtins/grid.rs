@@ -0,0 +1,121 @@
+test_eval! {
+    suite grid;
+
+    ("grid(\"#.\\n.#\")", "#{[0, 0]: \"#\", [0, 1]: \".\", [1, 0]: \".\", [1, 1]: \"#\"}", square),
+    ("grid(\"ab\\nc\")", "#{[0, 0]: \"a\", [0, 1]: \"c\", [1, 0]: \"b\"}", ragged_lines),
+    ("grid(\"\")", "#{}", empty_string),
+    ("grid(1)", "Unexpected argument: grid(Integer)", invalid_input)
+}
+
+test_eval! {
+    suite grid_filter;
+
+    ("grid_filter(\"#\", \"#.\\n.#\")", "#{[0, 0]: \"#\", [1, 1]: \"#\"}", characters),
+    ("\"S.#\\n.E.\" |> grid_filter(\"SE\")", "#{[0, 0]: \"S\", [1, 1]: \"E\"}", multiple_characters),
+    ("grid_filter(|c| int(c) > 4, \"19\\n53\")", "#{[0, 1]: \"5\", [1, 0]: \"9\"}", predicate),
+    ("grid_filter(1, \"#\")", "Unexpected argument: grid_filter(Integer, String)", invalid_filter)
+}
+
+test_eval! {
+    suite grid_bounds;
+
+    ("grid_bounds(grid(\"abc\\ndef\"))", "[[0, 0], [2, 1]]", dictionary),
+    ("grid_bounds({[3, -1], [-2, 4], [0, 0]})", "[[-2, -1], [3, 4]]", set),
+    ("grid_bounds([[1, 1]])", "[[1, 1], [1, 1]]", list),
+    ("grid_bounds(dense_grid(\"abc\\ndef\"))", "[[0, 0], [2, 1]]", dense_grid),
+    ("grid_bounds(#{})", "nil", empty_grid),
+    ("grid_bounds([[1, 2, 3]])", "Expected an [x, y] point, found: [1, 2, 3]", invalid_point)
+}
+
+test_eval! {
+    suite grid_string;
+
+    ("grid_string(grid(\"#.\\n.#\"))", "\"#.\n.#\"", dictionary),
+    ("grid_string(grid_filter(\"#\", \"#..\\n..#\"))", "\"#  \n  #\"", sparse_dictionary),
+    ("grid_string({[1, 1], [3, 2]})", "\"#..\n..#\"", set),
+    ("grid_string([[0, 0], [0, 1]])", "\"#\n#\"", list),
+    ("grid_string(#{[0, 0]: 1, [1, 0]: 2})", "\"12\"", non_string_cells),
+    ("grid_string(dense_grid([[\"#\", nil], [1, \".\"]]))", "\"# \n1.\"", dense_grid),
+    ("grid_string({})", "\"\"", empty_grid),
+    ("grid_string({[0, 0], [10000, 10000]})", "Unable to span a 10001x10001 grid, exceeding 10000000 cells", exceeding_area),
+    ("grid_string([[-9223372036854775807, 0], [9223372036854775807, 0]])", "Unable to span a 18446744073709551615x1 grid, exceeding 10000000 cells", exceeding_integer_range)
+}
+
+test_eval! {
+    suite neighbours;
+
+    ("neighbours([1, 1])", "[[1, 0], [2, 1], [1, 2], [0, 1]]", orthogonal),
+    ("diagonal_neighbours([1, 1])", "[[2, 0], [2, 2], [0, 2], [0, 0]]", diagonal),
+    ("all_neighbours([0, 0])", "[[-1, -1], [0, -1], [1, -1], [1, 0], [1, 1], [0, 1], [-1, 1], [-1, 0]]", all),
+    ("neighbours([1, 1]) |> map(vec_add([1, 1]))", "[[2, 1], [3, 2], [2, 3], [1, 2]]", with_vec_add),
+    ("neighbours([1])", "Expected an [x, y] point, found: [1]", invalid_point),
+    ("neighbours([9223372036854775807, 0])", "Unable to offset [9223372036854775807, 0] beyond the Integer range", overflowing_point),
+    ("neighbours(1)", "Unexpected argument: neighbours(Integer)", invalid_argument)
+}
+
+test_eval! {
+    suite dense_grid;
+
+    ("dense_grid(\"ab\\ncd\")", "Grid([[\"a\", \"b\"], [\"c\", \"d\"]])", string),
+    ("dense_grid(\"ab\\nc\")", "Grid([[\"a\", \"b\"], [\"c\", nil]])", ragged_lines_are_padded),
+    ("dense_grid([[1, 2, 3], [4, 5, 6]])", "Grid([[1, 2, 3], [4, 5, 6]])", list_of_rows),
+    ("dense_grid(#{[0, 0]: 1, [2, 1]: 2})", "Grid([[1, nil, nil], [nil, nil, 2]])", dictionary),
+    ("dense_grid(\"ab\\ncd\") |> dict |> dense_grid", "Grid([[\"a\", \"b\"], [\"c\", \"d\"]])", dictionary_round_trip),
+    ("dense_grid(\"\")", "Grid([])", empty_string),
+    ("dense_grid(\"ab\") |> type", "\"Grid\"", type_name),
+    ("dense_grid(#{[-1, 0]: 1})", "Unable to place a cell at [-1, 0] within a Grid", negative_point),
+    ("dense_grid(#{[0, 0]: 1, [10000, 10000]: 2})", "Unable to span a 10001x10001 grid, exceeding 10000000 cells", exceeding_area),
+    ("dense_grid([1, 2])", "Expected a List of rows, found: Integer", invalid_rows),
+    ("dense_grid(1)", "Unexpected argument: dense_grid(Integer)", invalid_input)
+}
+
+test_eval! {
+    suite dense_grid_indexing;
+
+    ("let g = dense_grid(\"ab\\ncd\"); [g[[0, 0]], g[[1, 0]], g[[0, 1]], g[[1, 1]]]", "[\"a\", \"b\", \"c\", \"d\"]", point),
+    ("dense_grid(\"ab\")[[2, 0]]", "nil", out_of_bounds),
+    ("dense_grid(\"ab\")[[-1, 0]]", "nil", negative_point),
+    ("dense_grid(\"ab\")[0]", "Expected an [x, y] Grid index, found: 0", invalid_index)
+}
+
+test_eval! {
+    suite dense_grid_updates;
+
+    ("dense_grid(\"ab\") |> assoc([0, 0], \"x\")", "Grid([[\"x\", \"b\"]])", assoc),
+    ("dense_grid([[1, 2]]) |> update([1, 0], _ + 10)", "Grid([[1, 12]])", update),
+    ("let g = dense_grid([[1, 2]]); let h = g |> assoc([0, 0], 5); [g, h]", "[Grid([[1, 2]]), Grid([[5, 2]])]", updates_are_persistent),
+    ("dense_grid(\"ab\") |> assoc([0, 1], \"x\")", "Unable to associate a value with Grid index [0, 1], size is 2x1", assoc_out_of_bounds),
+    ("dense_grid(\"ab\") |> update([5, 5], _ + 1)", "Unable to update Grid index [5, 5], size is 2x1", update_out_of_bounds)
+}
+
+test_eval! {
+    suite dense_grid_collection;
+
+    ("dense_grid([[1, 2], [3, 4]]) |> map(_ * 2)", "Grid([[2, 4], [6, 8]])", map),
+    ("dense_grid([[1, 2], [3, 4]]) |> map(|v, [x, y]| v + x * 10 + y * 100)", "Grid([[1, 12], [103, 114]])", map_with_point),
+    ("dense_grid(\"#.\\n.#\") |> filter(_ == \"#\")", "#{[0, 0]: \"#\", [1, 1]: \"#\"}", filter),
+    ("dense_grid([[1, 2], [3, 4]]) |> filter(|_, [x, _]| x == 1)", "#{[1, 0]: 2, [1, 1]: 4}", filter_with_point),
+    ("dense_grid([[1, 2], [3, 4]]) |> find(_ > 2)", "3", find),
+    ("dense_grid([[1, 2], [3, 4]]) |> find(_ > 4)", "nil", find_missing),
+    ("dense_grid([[1, 2], [3, 4]]) |> find(|v, [x, y]| x == 1 && y == 1)", "4", find_with_point),
+    ("dense_grid(\"#.\\n##\") |> count(_ == \"#\")", "3", count),
+    ("dense_grid([[1, 2], [3, 4]]) |> count(|_, [x, _]| x == 0)", "2", count_with_point),
+    ("dense_grid([[1, 2], [3, 4]]) |> count(|..args| size(args) == 1)", "4", count_without_point),
+    ("dense_grid([[1, 2], [3, 4]]) |> find(|..args| size(args) == 1)", "1", find_without_point),
+    ("dense_grid([[1, 2, 3], [4, 5, 6]]) |> size", "6", size),
+    ("dense_grid([[1, 2], [3, 4]]) |> list", "[[1, 2], [3, 4]]", list),
+    ("dense_grid([[1, 2], [3, 4]]) |> dict", "#{[0, 0]: 1, [0, 1]: 3, [1, 0]: 2, [1, 1]: 4}", dict),
+    ("{dense_grid([[1]]), dense_grid([[1]]), dense_grid([[2]])} |> size", "2", hashable)
+}
+
+test_eval! {
+    suite dense_grid_transformations;
+
+    ("dense_grid([[1, 2, 3], [4, 5, 6]]) |> transpose", "Grid([[1, 4], [2, 5], [3, 6]])", transpose),
+    ("dense_grid([[1, 2, 3], [4, 5, 6]]) |> rotate(1)", "Grid([[4, 1], [5, 2], [6, 3]])", rotate_clockwise),
+    ("dense_grid([[1, 2, 3], [4, 5, 6]]) |> rotate(-1)", "Grid([[3, 6], [2, 5], [1, 4]])", rotate_counter_clockwise),
+    ("dense_grid([[1, 2, 3], [4, 5, 6]]) |> rotate(2)", "Grid([[6, 5, 4], [3, 2, 1]])", rotate_half_turn),
+    ("let g = dense_grid([[1, 2], [3, 4]]); g |> rotate(4) == g", "true", rotate_full_turn),
+    ("dense_grid(\"ab\\ncd\") |> transpose |> grid_string", "\"ac\nbd\"", transpose_to_string),
+    ("transpose([1])", "Unexpected argument: transpose(List)", invalid_argument)
+}
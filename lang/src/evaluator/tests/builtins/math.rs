@@ -4,7 +4,9 @@ test_eval! {
     ("abs(1)", "1", positive_integer),
     ("abs(-1)", "1", negative_integer),
     ("abs(1.5)", "1.5", positive_decimal),
-    ("abs(-1.5)", "1.5", negative_decimal)
+    ("abs(-1.5)", "1.5", negative_decimal),
+    ("abs(-9223372036854775808)", "9223372036854775808", minimum_integer),
+    ("abs(-98765432109876543210)", "98765432109876543210", negative_big_integer)
 }
 
 test_eval! {
@@ -29,3 +29,48 @@ test_eval! {
     ("id(\"\")", "\"\"", string)
 }
 
+test_eval! {
+    suite error;
+
+    ("error(\"Something went wrong\")", "Something went wrong", raises_message),
+    ("let f = |x| if x > 1 { error(\"Too large\") } else { x }; f(2)", "Too large", raises_within_closure),
+    ("error(1)", "Unexpected argument: error(Integer)", non_string_message)
+}
+
+test_eval! {
+    suite r#try;
+
+    ("try(|| 1 + 2)", "#{\"value\": 3}", successful_value),
+    ("try(|| error(\"Oops\"))[\"error\"][\"message\"]", "\"Oops\"", raised_error_message),
+    ("try(|| error(\"Oops\"))[\"error\"][\"location\"][\"start\"]", "7", raised_error_location_start),
+    ("try(|| error(\"Oops\"))[\"error\"][\"location\"][\"end\"]", "12", raised_error_location_end),
+    ("try(|| [1] + \"a\")[\"error\"][\"message\"]", "\"Unsupported operation: List + String\"", builtin_error_message),
+    ("try(|| [1][\"a\"])[\"error\"] != nil", "true", index_error),
+    ("let f = |n| if n == 0 { error(\"Bottom\") } else { f(n - 1) }; try(|| f(10))[\"error\"][\"message\"]", "\"Bottom\"", unwinds_nested_calls),
+    ("let f = |n| if n == 0 { error(\"Bottom\") } else { f(n - 1) }; map(|n| try(|| f(n))[\"error\"][\"message\"], [1, 2, 3])", "[\"Bottom\", \"Bottom\", \"Bottom\"]", recovers_repeatedly),
+    ("try(|| { return 1; 2 })", "#{\"value\": 1}", early_return),
+    ("let result = try(|| error(\"Oops\")); match result { #{error} { \"recovered: \" + error[\"message\"] } #{value} { value } }", "\"recovered: Oops\"", match_on_result),
+    ("try(|| try(|| error(\"Inner\"))[\"error\"][\"message\"])", "#{\"value\": \"Inner\"}", nested_try),
+    ("try(1)", "Unexpected argument: try(Integer)", non_function),
+    ("try(|x| x)", "Expected a Function without parameters, found one with 1", function_with_parameters),
+    ("try(|x, y| x + y)", "Expected a Function without parameters, found one with 2", function_with_multiple_parameters)
+}
+
+test_eval! {
+    suite assert;
+
+    ("assert(1 == 1)", "nil", passing),
+    ("assert([1])", "nil", passing_truthy_value),
+    ("assert(1 == 2)", "Assertion failed: expected true, actual false", failing),
+    ("assert([])", "Assertion failed: expected true, actual []", failing_falsy_value),
+    ("try(|| assert(false))", "Assertion failed: expected true, actual false", not_recovered)
+}
+
+test_eval! {
+    suite assert_eq;
+
+    ("assert_eq(3, 1 + 2)", "nil", passing),
+    ("assert_eq([1, 2], [1] + [2])", "nil", passing_collection),
+    ("assert_eq(3, 1 + 1)", "Assertion failed: expected 3, actual 2", failing),
+    ("assert_eq(\"a\", 1)", "Assertion failed: expected \"a\", actual 1", failing_differing_types)
+}
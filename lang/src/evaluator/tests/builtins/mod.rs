@@ -1,5 +1,8 @@
 mod bitwise;
 mod collection;
+mod grid;
 mod math;
 mod miscellaneous;
+mod queue;
+mod search;
 mod string;
@@ -0,0 +1,66 @@
+test_eval! {
+    suite priority_queue;
+
+    ("priority_queue([])", "PriorityQueue([])", empty_list),
+    ("priority_queue([[\"b\", 2], [\"a\", 1], [\"c\", 3]])", "PriorityQueue([[\"a\", 1], [\"b\", 2], [\"c\", 3]])", list_of_pairs),
+    ("priority_queue([[\"a\", 1], [\"b\", 1]])", "PriorityQueue([[\"a\", 1], [\"b\", 1]])", equal_priorities_in_insertion_order),
+    ("priority_queue([1, 2])", "Expected a [value, priority] pair, found: 1", invalid_entry),
+    ("priority_queue(priority_queue([[\"a\", 1]]))", "PriorityQueue([[\"a\", 1]])", priority_queue)
+}
+
+test_eval! {
+    suite enqueue;
+
+    ("enqueue(\"a\", 1, priority_queue([]))", "PriorityQueue([[\"a\", 1]])", empty_queue),
+    ("priority_queue([[\"b\", 2]]) |> enqueue(\"a\", 1)", "PriorityQueue([[\"a\", 1], [\"b\", 2]])", queue_with_entries),
+    ("let q = priority_queue([]); enqueue(\"a\", 1, q); q", "PriorityQueue([])", is_persistent),
+    ("enqueue(\"a\", 1, [])", "Unexpected argument: enqueue(String, Integer, List)", invalid_queue)
+}
+
+test_eval! {
+    suite dequeue;
+
+    ("priority_queue([[\"b\", 2], [\"a\", 1]]) |> dequeue", "[\"a\", PriorityQueue([[\"b\", 2]])]", queue_with_entries),
+    ("priority_queue([[\"a\", 1], [\"b\", 1]]) |> dequeue |> first", "\"a\"", equal_priorities_in_insertion_order),
+    ("priority_queue([]) |> dequeue", "Unable to dequeue from an empty PriorityQueue", empty_queue)
+}
+
+test_eval! {
+    suite peek;
+
+    ("priority_queue([[\"b\", 2], [\"a\", 1]]) |> peek", "\"a\"", queue_with_entries),
+    ("priority_queue([]) |> peek", "nil", empty_queue)
+}
+
+test_eval! {
+    suite collection;
+
+    ("priority_queue([[\"b\", 2], [\"a\", 1]]) |> size", "2", size),
+    ("priority_queue([[\"b\", 2], [\"a\", 1]]) |> list", "[[\"a\", 1], [\"b\", 2]]", list),
+    ("if priority_queue([]) { 1 } else { 2 }", "2", falsey_empty_queue),
+    ("if priority_queue([[\"a\", 1]]) { 1 } else { 2 }", "1", truthy_queue),
+    ("priority_queue([[\"a\", 1], [\"b\", 2]]) == enqueue(\"a\", 1, priority_queue([[\"b\", 2]]))", "true", equality),
+    ("priority_queue([[\"a\", 1]]) == priority_queue([[\"a\", 2]])", "false", inequality),
+    ("type(priority_queue([]))", "\"PriorityQueue\"", type_name)
+}
+
+test_eval! {
+    suite shortest_path;
+
+    (r#"
+        let graph = #{"a": [["b", 7], ["c", 2]], "b": [["d", 1]], "c": [["b", 3], ["d", 8]], "d": []};
+        let queue = priority_queue([[["a", 0], 0]]);
+        [queue, #{}] |> iterate(|[queue, distances]| {
+            if !queue { return [queue, distances] };
+            let [[node, distance], rest] = dequeue(queue);
+            if distances `includes?` node {
+                [rest, distances]
+            } else {
+                let next = graph[node] |> fold(rest) |acc, [neighbour, cost]| {
+                    acc |> enqueue([neighbour, distance + cost], distance + cost)
+                };
+                [next, distances |> assoc(node, distance)]
+            }
+        }) |> find(|[queue, _]| !queue) |> second
+    "#, "#{\"a\": 0, \"b\": 5, \"c\": 2, \"d\": 6}", dijkstra)
+}
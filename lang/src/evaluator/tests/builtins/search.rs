@@ -0,0 +1,47 @@
+test_eval! {
+    suite bfs;
+    sut "let graph = #{1: [2, 3], 2: [4], 3: [4, 5], 4: [6], 5: [6], 6: [], 7: [1]};";
+
+    ("bfs(1, |n| graph[n], 6)", "#{\"distance\": 3, \"path\": [1, 2, 4, 6], \"visited\": #{1: 0, 2: 1, 3: 1, 4: 2, 5: 2, 6: 3}}", goal_value),
+    ("bfs(1, |n| graph[n], _ > 4) |> get(\"path\")", "[1, 3, 5]", goal_predicate),
+    ("bfs(1, |n| graph[n], 1) |> get(\"path\")", "[1]", start_is_goal),
+    ("bfs(1, |n| graph[n], 7)", "#{\"distance\": nil, \"path\": nil, \"visited\": #{1: 0, 2: 1, 3: 1, 4: 2, 5: 2, 6: 3}}", unreachable_goal),
+    ("bfs([0, 0], |[x, y]| [[x + 1, y], [x, y + 1]], [2, 1]) |> get(\"distance\")", "3", list_states),
+    ("bfs(#{\"n\": 0}, |#{n}| [#{\"n\": n + 1}], #{\"n\": 3}) |> get(\"distance\")", "3", dictionary_states),
+    ("bfs(1, |n| set(graph[n]), 6) |> get(\"distance\")", "3", set_neighbours),
+    ("bfs(1, |n| #{1: [3, 2], 2: [4], 3: [4], 4: []}[n], 4) |> get(\"path\")", "[1, 3, 4]", ties_broken_by_discovery_order),
+    ("bfs(1, |n| n, 6)", "Expected neighbours to be a List or Set, found: Integer", invalid_neighbours),
+    ("bfs(1, |n| [|x| x], 6)", "Unable to use a Function as a search state", unhashable_state),
+    ("bfs(#{\"f\": |x| x}, |n| [], 6)", "Unable to use a Dictionary as a search state", unhashable_dictionary_state),
+    ("bfs(1, 2, 6)", "Unexpected argument: bfs(Integer, Integer, Integer)", invalid_arguments)
+}
+
+test_eval! {
+    suite dijkstra;
+    sut "let graph = #{\"a\": [[\"b\", 7], [\"c\", 2]], \"b\": [[\"d\", 1]], \"c\": [[\"b\", 3], [\"d\", 8]], \"d\": []};";
+
+    ("dijkstra(\"a\", |n| graph[n], \"d\")", "#{\"distance\": 6, \"path\": [\"a\", \"c\", \"b\", \"d\"], \"visited\": #{\"a\": 0, \"b\": 5, \"c\": 2, \"d\": 6}}", shortest_path),
+    ("dijkstra(\"a\", |n| graph[n], |_| false) |> get(\"visited\")", "#{\"a\": 0, \"b\": 5, \"c\": 2, \"d\": 6}", all_distances),
+    ("dijkstra(\"a\", |n| [\"b\"], \"b\")", "Expected a [state, cost] pair, found: \"b\"", invalid_pair),
+    ("dijkstra(\"a\", |n| #{\"a\": [[\"c\", 1], [\"b\", 1]], \"b\": [[\"d\", 1]], \"c\": [[\"d\", 1]], \"d\": []}[n], \"d\") |> get(\"path\")", "[\"a\", \"c\", \"d\"]", ties_broken_by_discovery_order),
+    ("dijkstra(\"a\", |n| #{\"a\": [[\"b\", 9], [\"c\", 1]], \"b\": [[\"d\", 1]], \"c\": [[\"b\", 1]], \"d\": []}[n], \"d\")", "#{\"distance\": 3, \"path\": [\"a\", \"c\", \"b\", \"d\"], \"visited\": #{\"a\": 0, \"b\": 2, \"c\": 1, \"d\": 3}}", revisits_state_at_lower_cost),
+    ("dijkstra(\"a\", |n| set(graph[n]), \"d\") |> get(\"distance\")", "6", set_neighbours),
+    ("dijkstra(\"a\", |n| [[\"b\", 1.5]], \"b\")", "Expected an Integer cost, found: Decimal", invalid_cost),
+    ("dijkstra(\"a\", |n| [[\"b\", -1]], \"b\")", "Expected a non-negative cost, found: -1", negative_cost),
+    ("dijkstra(\"a\", |n| [[n + \"a\", 9223372036854775807]], |_| false)", "Search distance exceeds the Integer range", overflowing_distance),
+    ("dijkstra(\"a\", |n| [[\"b\", 9223372036854775808]], \"b\")", "Search cost exceeds the Integer range, found: 9223372036854775808", overflowing_cost)
+}
+
+test_eval! {
+    suite a_star;
+    sut "let neighbours = |[x, y]| [[[x + 1, y], 1], [[x, y + 1], 1], [[x - 1, y], 1], [[x, y - 1], 1]] |> filter(|[[x, y]]| x >= 0 && y >= 0 && x < 10 && y < 10 && !(x == 5 && y < 8));";
+
+    ("a_star([0, 0], neighbours, [9, 0], |[x, y]| abs(9 - x) + abs(y)) |> get(\"distance\")", "25", manhattan_heuristic),
+    ("a_star([0, 0], neighbours, [9, 0], |[x, y]| 0) |> get(\"distance\")", "25", zero_heuristic),
+    (
+        "let graph = #{\"s\": [[\"a\", 1], [\"b\", 1]], \"a\": [[\"c\", 1]], \"b\": [[\"c\", 3]], \"c\": [[\"g\", 3]], \"g\": []}; a_star(\"s\", |n| graph[n], \"g\", |n| if n == \"a\" { 4 } else { 0 })",
+        "#{\"distance\": 5, \"path\": [\"s\", \"a\", \"c\", \"g\"], \"visited\": #{\"a\": 1, \"b\": 1, \"c\": 2, \"g\": 5, \"s\": 0}}",
+        reopens_settled_state_with_inconsistent_heuristic
+    ),
+    ("a_star([0, 0], neighbours, [9, 0], |_| nil)", "Expected an Integer cost, found: Nil", invalid_heuristic)
+}
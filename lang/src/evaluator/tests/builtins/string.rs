@@ -12,7 +12,9 @@ test_eval! {
     ("int(\"1\")", "1", positive_integer_string),
     ("int(\"-1.5\")", "-2", negative_decimal_string),
     ("int(\"1.5\")", "2", positive_decimal_string),
-    ("int(\"abc\")", "0", invalid_string)
+    ("int(\"abc\")", "0", invalid_string),
+    ("int(\"98765432109876543210\")", "98765432109876543210", big_integer_string),
+    ("int(98765432109876543210)", "98765432109876543210", big_integer)
 }
 
 test_eval! {
@@ -21,7 +23,8 @@ test_eval! {
     ("ints(\"abc\")", "[]", no_ints),
     ("ints(\"1 2 3\")", "[1, 2, 3]", space_seperators),
     ("ints(\"1,2,3\")", "[1, 2, 3]", comma_seperators),
-    ("ints(\"1a 2b 3c\")", "[1, 2, 3]", letter_seperators)
+    ("ints(\"1a 2b 3c\")", "[1, 2, 3]", letter_seperators),
+    ("ints(\"1 -98765432109876543210\")", "[1, -98765432109876543210]", big_integers)
 }
 
 test_eval! {
@@ -54,7 +54,24 @@ fn modulo_by_zero() {
 #[test]
 fn trace() {
     let source = r#"
-        let a = || unknown
+        let a = || nil()
+        let b = || a() + 1
+        let c = || b() + 1
+        c()
+    "#;
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    let actual = format!("{:?}", evaluator.evaluate(&program));
+
+    expect![[r#"Err(RuntimeErr { message: "Expected a Function, found: Nil", source: 20..23, trace: [45..46, 72..73, 88..89] })"#]].assert_eq(&actual);
+}
+
+#[test]
+fn trace_through_tail_calls() {
+    let source = r#"
+        let a = || nil()
         let b = || a()
         let c = || b()
         c()
@@ -65,5 +82,332 @@ fn trace() {
     let mut evaluator = crate::evaluator::Evaluator::new();
     let actual = format!("{:?}", evaluator.evaluate(&program));
 
-    expect![[r#"Err(RuntimeErr { message: "Identifier can not be found: unknown", source: 20..27, trace: [47..48, 70..71, 82..83] })"#]].assert_eq(&actual);
+    expect![[r#"Err(RuntimeErr { message: "Expected a Function, found: Nil", source: 20..23, trace: [45..46] })"#]]
+        .assert_eq(&actual);
+}
+
+#[test]
+fn unknown_identifier_is_reported_before_evaluation() {
+    let source = r#"
+        let a = || unknown
+        a
+    "#;
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    let actual = format!("{:?}", evaluator.evaluate(&program));
+
+    expect![[r#"Err(RuntimeErr { message: "Identifier can not be found: unknown", source: 20..27, trace: [] })"#]]
+        .assert_eq(&actual);
+}
+
+#[test]
+fn unknown_assignment_target_is_reported_before_evaluation() {
+    let source = r#"
+        let f = |x| { total = x }
+        f
+    "#;
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    let actual = format!("{:?}", evaluator.evaluate(&program));
+
+    expect![[r#"Err(RuntimeErr { message: "Variable 'total' has not been declared", source: 23..28, trace: [] })"#]]
+        .assert_eq(&actual);
+}
+
+#[test]
+fn maximum_frame_depth_exceeded() {
+    let source = r#"
+        let f = |n| 1 + f(n + 1);
+        f(0)
+    "#;
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_max_frame_depth(10);
+    let actual = match evaluator.evaluate(&program) {
+        Err(error) => format!("{} {:?} {}", error.message, error.source, error.trace.len()),
+        Ok(value) => format!("{}", value),
+    };
+
+    expect!["Maximum frame depth of 10 exceeded 25..26 10"].assert_eq(&actual);
+}
+
+#[test]
+fn evaluator_is_reusable_after_maximum_frame_depth_exceeded() {
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_max_frame_depth(50);
+
+    let source = "let f = |n| 1 + f(n + 1); f(0)";
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    assert!(evaluator.evaluate(&program).is_err());
+
+    let source = "let g = |n| if n == 0 { 0 } else { 1 + g(n - 1) }; g(3)";
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let actual = format!("{:?}", evaluator.evaluate(&program));
+
+    expect!["Ok(Integer(3))"].assert_eq(&actual);
+}
+
+#[test]
+fn maximum_frame_depth_counts_calls_alike_in_both_backends() {
+    let within = "let f = |n| { let m = n - 1; if n == 0 { 0 } else { let r = f(m); r + 1 } }; f(49)";
+    let beyond = "let f = |n| { let m = n - 1; if n == 0 { 0 } else { let r = f(m); r + 1 } }; f(50)";
+
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let actual: Vec<String> = [within, beyond]
+            .iter()
+            .map(|source| {
+                let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+                let program = parser.parse().unwrap();
+                let mut evaluator = crate::evaluator::Evaluator::new();
+                evaluator.set_backend(backend);
+                evaluator.set_max_frame_depth(50);
+                match evaluator.evaluate(&program) {
+                    Err(error) => error.message,
+                    Ok(value) => format!("{}", value),
+                }
+            })
+            .collect();
+
+        expect![[r#"["49", "Maximum frame depth of 50 exceeded"]"#]].assert_eq(&format!("{:?}", actual));
+    }
+}
+
+#[test]
+fn deep_recursion_within_default_frame_depth() {
+    let source = "let f = |n| if n == 0 { 0 } else { 1 + f(n - 1) }; f(19000)";
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let mut evaluator = crate::evaluator::Evaluator::new();
+        evaluator.set_backend(backend);
+        let actual = format!("{:?}", evaluator.evaluate(&program));
+
+        expect!["Ok(Integer(19000))"].assert_eq(&actual);
+    }
+}
+
+#[test]
+fn deep_recursion_through_builtins_within_default_frame_depth() {
+    let source = "let f = |n| if n == 0 { 0 } else { 1 + ([n - 1] |> map(f) |> first) }; f(9000)";
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let mut evaluator = crate::evaluator::Evaluator::new();
+        evaluator.set_backend(backend);
+        let actual = format!("{:?}", evaluator.evaluate(&program));
+
+        expect!["Ok(Integer(9000))"].assert_eq(&actual);
+    }
+}
+
+#[test]
+fn bytecode_backend_reports_error_with_trace() {
+    let source = r#"
+        let add = |a, b| a + b();
+        let apply = |x| add(x, 1) * 2;
+        map(apply, [1])
+    "#;
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_backend(crate::evaluator::Backend::Bytecode);
+    let actual = format!("{:?}", evaluator.evaluate(&program));
+
+    expect![[r#"Err(RuntimeErr { message: "Expected a Function, found: Integer", source: 30..31, trace: [59..62, 82..85, 82..85] })"#]].assert_eq(&actual);
+}
+
+#[test]
+fn bytecode_backend_bounds_frame_depth() {
+    let source = "let f = |n| 1 + f(n + 1); f(0)";
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_backend(crate::evaluator::Backend::Bytecode);
+    evaluator.set_max_frame_depth(10);
+    let actual = match evaluator.evaluate(&program) {
+        Err(error) => format!("{} {:?} {}", error.message, error.source, error.trace.len()),
+        Ok(value) => format!("{}", value),
+    };
+
+    expect!["Maximum frame depth of 10 exceeded 16..17 10"].assert_eq(&actual);
+}
+
+#[test]
+fn bytecode_backend_evaluates_tail_calls_in_constant_frame_depth() {
+    let source = "let count = |n, acc| if n == 0 { acc } else { count(n - 1, acc + 1) }; count(10000, 0)";
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_backend(crate::evaluator::Backend::Bytecode);
+    evaluator.set_max_frame_depth(10);
+    let actual = format!("{:?}", evaluator.evaluate(&program));
+
+    expect!["Ok(Integer(10000))"].assert_eq(&actual);
+}
+
+#[test]
+fn maximum_evaluation_steps_exceeded() {
+    let source = "1.. |> map(_ + 1) |> sum";
+
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+        let program = parser.parse().unwrap();
+        let mut evaluator = crate::evaluator::Evaluator::new();
+        evaluator.set_backend(backend);
+        evaluator.set_max_steps(Some(100));
+        let actual = match evaluator.evaluate(&program) {
+            Err(error) => error.message,
+            Ok(value) => format!("{}", value),
+        };
+
+        expect!["Maximum evaluation steps of 100 exceeded"].assert_eq(&actual);
+    }
+}
+
+#[test]
+fn maximum_evaluation_steps_exceeded_without_calls() {
+    let sources = [
+        "1.. |> size",
+        "includes?(1.., 0)",
+        "1.. |> sum",
+        "zip(1.., 1..) |> size",
+    ];
+
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let actual = sources
+            .iter()
+            .map(|source| {
+                let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+                let program = parser.parse().unwrap();
+                let mut evaluator = crate::evaluator::Evaluator::new();
+                evaluator.set_backend(backend);
+                evaluator.set_max_steps(Some(100));
+                match evaluator.evaluate(&program) {
+                    Err(error) => error.message,
+                    Ok(value) => format!("{}", value),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        expect![[r#"
+            Maximum evaluation steps of 100 exceeded
+            Maximum evaluation steps of 100 exceeded
+            Maximum evaluation steps of 100 exceeded
+            Maximum evaluation steps of 100 exceeded"#]]
+        .assert_eq(&actual);
+    }
+}
+
+#[test]
+fn interrupt_halts_evaluation_without_calls() {
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new("includes?(1.., 0)"));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_interrupt(Some(std::rc::Rc::new(|| true)));
+    let actual = match evaluator.evaluate(&program) {
+        Err(error) => error.message,
+        Ok(value) => format!("{}", value),
+    };
+
+    expect!["Evaluation was interrupted"].assert_eq(&actual);
+    assert!(evaluator.take_interrupted());
+}
+
+#[test]
+fn maximum_evaluation_steps_apply_to_each_evaluation() {
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_max_steps(Some(100));
+
+    for _ in 0..3 {
+        let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new("1..=10 |> map(_ + 1) |> sum"));
+        let program = parser.parse().unwrap();
+        let actual = format!("{:?}", evaluator.evaluate(&program));
+
+        expect!["Ok(Integer(65))"].assert_eq(&actual);
+    }
+}
+
+#[test]
+fn only_halting_errors_within_lazy_sequences_are_propagated() {
+    let sources = [
+        "1..5 |> map(|x| x + \"a\") |> count(_ > 1)",
+        "1..5 |> map(|x| x + \"a\") |> reduce(+)",
+        "[..(1..5 |> map(|x| x + \"a\"))]",
+        "1..5 |> map(|x| 1.. |> sum) |> count(_ > 1)",
+        "[..(1..5 |> map(|x| 1.. |> sum))]",
+    ];
+
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let actual = sources
+            .iter()
+            .map(|source| {
+                let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+                let program = parser.parse().unwrap();
+                let mut evaluator = crate::evaluator::Evaluator::new();
+                evaluator.set_backend(backend);
+                evaluator.set_max_steps(Some(100));
+                match evaluator.evaluate(&program) {
+                    Err(error) => error.message,
+                    Ok(value) => format!("{}", value),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        expect![[r#"
+            0
+            Unable to reduce an empty LazySequence
+            []
+            Maximum evaluation steps of 100 exceeded
+            Maximum evaluation steps of 100 exceeded"#]]
+        .assert_eq(&actual);
+    }
+}
+
+#[test]
+fn maximum_evaluation_steps_exceeded_is_not_recoverable() {
+    let source = r#"try(|| 1.. |> map(_ + 1) |> sum)"#;
+
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    evaluator.set_max_steps(Some(100));
+    let actual = match evaluator.evaluate(&program) {
+        Err(error) => error.message,
+        Ok(value) => format!("{}", value),
+    };
+
+    expect!["Maximum evaluation steps of 100 exceeded"].assert_eq(&actual);
 }
@@ -205,6 +205,136 @@ test_eval! {
         "#,
         "15",
         currying_with_dictionary_parameter
+    ),
+    (
+        r#"
+            let fn = |a, [b, c], d| [a, b, c, d];
+            let partial = fn(_, [2, 3]);
+            [partial(1)(4), partial(5, 6)]
+        "#,
+        "[[1, 2, 3, 4], [5, 2, 3, 6]]",
+        placeholder_partial_application_with_list_parameter
+    ),
+    (
+        r#"
+            let fn = || later * 2;
+            let later = 21;
+            fn()
+        "#,
+        "42",
+        closure_referencing_variable_declared_after_it
+    ),
+    (
+        r#"
+            let x = 1;
+            let fn = || x;
+            let result = if true { let x = 2; [x, fn()] };
+            result
+        "#,
+        "[2, 1]",
+        closure_referencing_shadowed_variable
+    ),
+    ("let f = fold(_, +); [f(10, [1, 2]), f(0)([3])]", "[13, 3]", builtin_placeholder_partial_application),
+    ("fold(_, +)", "|initial, collection| { [builtin] }", builtin_partial_application_display),
+    ("zip([1, 2])([3, 4], [5, 6])", "[[1, 3, 5], [2, 4, 6]]", builtin_partial_application_with_rest_parameter)
+}
+
+test_eval! {
+    suite tail_calls;
+
+    (
+        r#"
+            let count = |n, total| if n == 0 { total } else { count(n - 1, total + 1) };
+            count(100000, 0);
+        "#,
+        "100000",
+        self_recursion_within_if_else
+    ),
+    (
+        r#"
+            let count = |n, total| match n {
+                0 { total }
+                _ { count(n - 1, total + 1) }
+            };
+            count(100000, 0);
+        "#,
+        "100000",
+        self_recursion_within_match
+    ),
+    (
+        r#"
+            let count = |n, total| {
+                if n == 0 { return total; }
+                let next = n - 1;
+                if next % 2 == 0 { count(next, total + 1) } else { return count(next, total + 1) }
+            };
+            count(100000, 0);
+        "#,
+        "100000",
+        self_recursion_after_early_return
+    ),
+    (
+        r#"
+            let is_even = |n| if n == 0 { true } else { is_odd(n - 1) };
+            let is_odd = |n| { if n == 0 { false } else { is_even(n - 1) } };
+            [is_even(100000), is_odd(100001), is_even(7)];
+        "#,
+        "[true, true, false]",
+        mutual_recursion
+    ),
+    (
+        r#"
+            let add = |a, b| a + b;
+            let apply = |n| add(n);
+            apply(1)(2);
+        "#,
+        "3",
+        tail_call_with_partial_application
+    ),
+    (
+        r#"
+            let total = |xs| sum(xs);
+            total([1, 2, 3]);
+        "#,
+        "6",
+        tail_call_to_builtin
+    ),
+    (
+        r#"
+            let double = memoize |n| n * 2;
+            let apply = |n| { double(n) };
+            [apply(2), apply(2)];
+        "#,
+        "[4, 4]",
+        tail_call_to_memoized_closure
+    ),
+    (
+        r#"
+            let mut calls = 0;
+            let double = memoize |n| { calls += 1; n * 2 };
+            let apply = |n| { double(n) };
+            [apply(2), apply(2), apply(2), calls];
+        "#,
+        "[4, 4, 4, 1]",
+        tail_call_to_memoized_closure_caches_result
+    ),
+    (
+        r#"
+            let mut calls = 0;
+            let countdown = memoize |n| { calls += 1; if n == 0 { 0 } else { countdown(n - 1) } };
+            [countdown(5), countdown(3), countdown(5), calls];
+        "#,
+        "[0, 0, 0, 6]",
+        memoized_self_recursion_through_tail_calls_caches_each_call
+    ),
+    (
+        r#"
+            let count = |n| if n == 0 { 0 } else { 1 + count(n - 1) };
+            let result = count(10);
+            result + 1;
+        "#,
+        "11",
+        non_tail_recursion_is_evaluated_in_place
     )
 }
 
@@ -214,12 +344,10 @@ fn external_function() {
     let hello_function: ExternalFnDef = (
         "hello".to_owned(),
         vec![ExpressionKind::Identifier("name".to_owned())],
-        Rc::new(
-            move |arguments: Arguments, _source: Location| match &**arguments.get("name").unwrap() {
-                Object::String(name) => Ok(Rc::new(Object::String(hello_template.replace("{}", name)))),
-                _ => Ok(Rc::new(Object::Nil)),
-            },
-        ),
+        Rc::new(move |arguments: &Arguments, _source: Location| match &*arguments[0] {
+            Object::String(name) => Ok(Rc::new(Object::String(hello_template.replace("{}", name)))),
+            _ => Ok(Rc::new(Object::Nil)),
+        }),
     );
 
     let source = "hello(\"world\");";
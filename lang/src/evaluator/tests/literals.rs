@@ -1,10 +1,15 @@
+use expect_test::{Expect, expect};
+
 test_eval! {
     suite integer;
 
     ("5", "5", single_number),
     ("125", "125", multi_number),
     ("1_000_000", "1000000", with_underscore_seperators),
-    ("-5", "-5", negative)
+    ("-5", "-5", negative),
+    ("9_223_372_036_854_775_808", "9223372036854775808", big_integer),
+    ("-9223372036854775809", "-9223372036854775809", negative_big_integer),
+    ("-9223372036854775808", "-9223372036854775808", minimum_integer)
 }
 
 test_eval! {
@@ -60,9 +65,9 @@ test_eval! {
     suite set;
 
     ("{1, 2, 3}", "{1, 2, 3}", homogeneous),
-    ("{1, 2, 3, 1, 2, 4}", "{1, 2, 4, 3}", homogeneous_with_duplicates),
-    ("{1, \"3\", 2.25, {1}, [2], true}", "{1, \"3\", 2.25, {1}, [2], true}", heterogeneous),
-    ("{1, \"3\", 2.25, {1}, [2], true, 2.25, {1}, [2]}", "{1, \"3\", 2.25, {1}, [2], true}", heterogeneous_with_duplicates),
+    ("{1, 2, 3, 1, 2, 4}", "{1, 2, 3, 4}", homogeneous_with_duplicates),
+    ("{1, \"3\", 2.25, {1}, [2], true}", "{1, 2.25, true, \"3\", [2], {1}}", heterogeneous),
+    ("{1, \"3\", 2.25, {1}, [2], true, 2.25, {1}, [2]}", "{1, 2.25, true, \"3\", [2], {1}}", heterogeneous_with_duplicates),
     ("{|a| a}", "Unable to include a Function within an Set", function_disallowed),
     ("{1..5}", "Unable to include a BoundedRange within an Set", lazy_sequence_disallowed)
 }
@@ -70,10 +75,10 @@ test_eval! {
 test_eval! {
     suite dictionary;
 
-    ("#{\"1\": 1, \"2\": 2, \"3\": 3}", "#{\"3\": 3, \"1\": 1, \"2\": 2}", homogeneous),
-    ("#{\"1\": 1, \"2\": 2, \"3\": 3, \"1\": 4}", "#{\"3\": 3, \"1\": 4, \"2\": 2}", homogeneous_with_duplicates),
-    ("#{1: true, \"2\": {nil}, 3.0: [1..5], {1}: #{1: 2}}", "#{1: true, 3: [1..5], {1}: #{1: 2}, \"2\": {nil}}", heterogeneous),
-    ("#{1: true, \"2\": {nil}, 3.0: [1..5], {1}: #{1: 2}, {1}: 2}", "#{1: true, 3: [1..5], {1}: 2, \"2\": {nil}}", heterogeneous_with_duplicates),
+    ("#{\"1\": 1, \"2\": 2, \"3\": 3}", "#{\"1\": 1, \"2\": 2, \"3\": 3}", homogeneous),
+    ("#{\"1\": 1, \"2\": 2, \"3\": 3, \"1\": 4}", "#{\"1\": 4, \"2\": 2, \"3\": 3}", homogeneous_with_duplicates),
+    ("#{1: true, \"2\": {nil}, 3.0: [1..5], {1}: #{1: 2}}", "#{1: true, 3: [1..5], \"2\": {nil}, {1}: #{1: 2}}", heterogeneous),
+    ("#{1: true, \"2\": {nil}, 3.0: [1..5], {1}: #{1: 2}, {1}: 2}", "#{1: true, 3: [1..5], \"2\": {nil}, {1}: 2}", heterogeneous_with_duplicates),
     ("#{(|a| a): 1}", "Unable to use a Function as a Dictionary key", function_key_disallowed),
     ("#{1..5: 1}", "Unable to use a BoundedRange as a Dictionary key", lazy_sequence_key_disallowed)
 }
@@ -164,3 +169,92 @@ test_eval! {
     ("if let x = 1 { x }", "1", true_let_assignment),
     ("if let x = 0 { x }", "nil", false_let_assignment)
 }
+
+test_eval! {
+    suite interpolated_string;
+
+    (r#"$"Hello, world!""#, r#""Hello, world!""#, without_interpolation),
+    (r#"let x = 1; $"x={x}""#, r#""x=1""#, identifier),
+    (r#"let g = [1, 2]; $"size={size(g)}, sum={g |> sum}""#, r#""size=2, sum=3""#, expressions),
+    (r#"let name = "santa"; $"Hello, {name}!""#, r#""Hello, santa!""#, unquoted_string),
+    (r#"$"{[1, "a"]} {#{1: {2}}} {nil}""#, r#""[1, "a"] #{1: {2}} nil""#, display_of_collections),
+    (r#"$"{if true { "a" } else { "b" }}""#, r#""a""#, nested_braces),
+    (r#"$"outer {$"inner {1 + 1}"}""#, r#""outer inner 2""#, nested_interpolated_string),
+    (r#"$"\{x\} {"\"quoted\""}\n""#, "\"{x} \"quoted\"\n\"", escaped_characters),
+    (r#"$"{unknown}""#, "Identifier can not be found: unknown", unknown_identifier)
+}
+
+#[test]
+fn pretty_display_of_small_values() {
+    assert_pretty(
+        r#"#{"b": {3, 1, 2}, "a": [1, 2]}"#,
+        expect![[r#"#{"a": [1, 2], "b": {1, 2, 3}}"#]],
+    );
+}
+
+#[test]
+fn pretty_display_of_large_nested_values() {
+    assert_pretty(
+        r#"#{"numbers": 1..=12 |> map(_ * 1000) |> list, "words": {"santa", "elf", "reindeer", "sleigh", "present", "chimney"}, "empty": []}"#,
+        expect![[r##"
+            #{
+              "empty": [],
+              "numbers": [
+                1000,
+                2000,
+                3000,
+                4000,
+                5000,
+                6000,
+                7000,
+                8000,
+                9000,
+                10000,
+                11000,
+                12000
+              ],
+              "words": {"chimney", "elf", "present", "reindeer", "santa", "sleigh"}
+            }"##]],
+    );
+}
+
+#[test]
+fn pretty_display_of_dense_grid() {
+    assert_pretty(
+        r#"dense_grid(1..=6 |> map(|y| 1..=12 |> map(|x| x * y * 100) |> list) |> list)"#,
+        expect![[r#"
+            Grid([
+              [100, 200, 300, 400, 500, 600, 700, 800, 900, 1000, 1100, 1200],
+              [200, 400, 600, 800, 1000, 1200, 1400, 1600, 1800, 2000, 2200, 2400],
+              [300, 600, 900, 1200, 1500, 1800, 2100, 2400, 2700, 3000, 3300, 3600],
+              [400, 800, 1200, 1600, 2000, 2400, 2800, 3200, 3600, 4000, 4400, 4800],
+              [500, 1000, 1500, 2000, 2500, 3000, 3500, 4000, 4500, 5000, 5500, 6000],
+              [600, 1200, 1800, 2400, 3000, 3600, 4200, 4800, 5400, 6000, 6600, 7200]
+            ])"#]],
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn big_integer_serialization_round_trips() {
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(
+        r#"[99999999999999999999, -99999999999999999999, 1, "99999999999999999999", #{"BigInteger": "99999999999999999999"}]"#,
+    ));
+    let program = parser.parse().unwrap();
+    let value = crate::evaluator::Evaluator::new().evaluate(&program).unwrap();
+
+    let serialized = serde_json::to_string(&value).unwrap();
+    expect![[r#"[{"\u0000BigInteger":"99999999999999999999"},{"\u0000BigInteger":"-99999999999999999999"},1,"99999999999999999999",{"BigInteger":"99999999999999999999"}]"#]]
+        .assert_eq(&serialized);
+
+    let deserialized: crate::evaluator::Object = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(*value, deserialized);
+}
+
+fn assert_pretty(source: &str, expected: Expect) {
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().unwrap();
+    let actual = crate::evaluator::Evaluator::new().evaluate(&program).unwrap().pretty();
+
+    expected.assert_eq(&actual);
+}
@@ -64,6 +64,25 @@ test_eval! {
     ("sut(5)", "\"4\"", unbounded_range)
 }
 
+test_eval! {
+    suite big_integer_ranges;
+
+    sut r#"
+        let sut = |x| match x {
+            [1..=99999999999999999999] { "1" },
+            1..99999999999999999999 { "2" }
+            99999999999999999999.. { "3" }
+            _ { "4" }
+        };
+    "#;
+
+    ("sut([5])", "\"1\"", list_with_big_integer_bound),
+    ("sut(5)", "\"2\"", integer_within_big_integer_bound),
+    ("sut(99999999999999999998)", "\"2\"", big_integer_within_big_integer_bound),
+    ("sut(99999999999999999999)", "\"3\"", big_integer_within_unbounded_range),
+    ("sut(0)", "\"4\"", integer_outside_big_integer_bounds)
+}
+
 test_eval! {
     suite guards;
 
@@ -322,3 +341,113 @@ test_eval! {
         explicit_key_with_rest
     )
 }
+
+test_eval! {
+    suite or_patterns;
+
+    ("match \"R\" { \"L\" | \"R\" { \"turn\" } _ { \"other\" } }", "\"turn\"", literals),
+    ("match 7 { 1..3 | 5..=8 { \"in\" } _ { \"out\" } }", "\"in\"", ranges),
+    ("match \"a\" { 1..3 | 5 { \"in\" } _ { \"out\" } }", "\"out\"", ranges_only_match_integers),
+    ("match [1, 2] { [x, 1] | [1, x] { x } }", "2", bindings_of_failed_alternative_are_discarded),
+    ("match [\"b\", 3] { [\"a\" | \"b\", n] { n } }", "3", nested_within_list),
+    ("match #{\"dir\": \"D\"} { #{\"dir\": \"U\" | \"D\"} { \"vertical\" } _ { \"horizontal\" } }", "\"vertical\"", nested_within_dictionary),
+    ("match 4 { 1 | 2 | 3 { \"low\" } n if n > 3 { \"high\" } }", "\"high\"", falls_through_to_next_case),
+    ("match 2 { 1 | 2 if false { \"guarded\" } _ { \"other\" } }", "\"other\"", with_guard),
+    ("let f = match [1, 2] { [x, 1] | [1, x] { || x } }; f()", "2", binding_captured_by_closure)
+}
+
+test_eval! {
+    suite binding_patterns;
+
+    ("match [1, 2] { p @ [x, y] { [p, x + y] } }", "[[1, 2], 3]", whole_subject),
+    ("match [[1, 2], 3] { [p @ [a, _], b] { [p, a, b] } }", "[[1, 2], 1, 3]", nested_within_list),
+    ("match \"R\" { d @ \"L\" | \"R\" { d } }", "\"R\"", over_alternatives),
+    ("match [3, 4] { p @ [x, _] if x > 5 { p } _ { \"small\" } }", "\"small\"", with_guard)
+}
+
+test_eval! {
+    suite type_patterns;
+
+    sut r#"
+        type Shape = Circle(radius) | Square(side);
+        let sut = |x| match x {
+            Integer { "integer" }
+            Decimal { "decimal" }
+            String { "string" }
+            Boolean { "boolean" }
+            Nil { "nil" }
+            List { "list" }
+            Set { "set" }
+            Dictionary { "dictionary" }
+            Function { "function" }
+            Circle { "circle" }
+            Shape { "shape" }
+            _ { "other" }
+        };
+    "#;
+
+    ("sut(1)", "\"integer\"", integer),
+    ("sut(1.5)", "\"decimal\"", decimal),
+    ("sut(\"a\")", "\"string\"", string),
+    ("sut(true)", "\"boolean\"", boolean),
+    ("sut(nil)", "\"nil\"", nil),
+    ("sut([1])", "\"list\"", list),
+    ("sut({1})", "\"set\"", set),
+    ("sut(#{1: 2})", "\"dictionary\"", dictionary),
+    ("sut(_ + 1)", "\"function\"", function),
+    ("sut(Circle(1))", "\"circle\"", variant_name),
+    ("sut(Square(1))", "\"shape\"", declared_type_name),
+    ("sut(1..)", "\"other\"", unmatched_type),
+    ("match [1, \"a\"] { [Integer, String] { \"ok\" } }", "\"ok\"", nested_within_list),
+    ("match 7 { n @ Integer if n > 5 { n * 2 } _ { 0 } }", "14", bound_with_guard),
+    ("match 2 { Integer | Decimal { \"number\" } }", "\"number\"", alternatives)
+}
+
+test_eval! {
+    suite string_patterns;
+
+    sut r#"
+        let sut = |line| match line {
+            "move " ++ distance { ["move", int(distance)] }
+            "(" ++ inner ++ ")" { ["group", inner] }
+            name ++ ".txt" { ["file", name] }
+            "noop" ++ _ { ["noop"] }
+            _ { "unknown" }
+        };
+    "#;
+
+    ("sut(\"move 10\")", "[\"move\", 10]", prefix),
+    ("sut(\"(a, b)\")", "[\"group\", \"a, b\"]", prefix_and_suffix),
+    ("sut(\"input.txt\")", "[\"file\", \"input\"]", suffix),
+    ("sut(\"noop\")", "[\"noop\"]", empty_remainder),
+    ("sut(\"jump 10\")", "\"unknown\"", no_match),
+    ("sut(10)", "\"unknown\"", non_string_subject),
+    ("match \"(\" { \"(\" ++ inner ++ \")\" { inner } _ { \"overlapping\" } }", "\"overlapping\"", overlapping_prefix_and_suffix),
+    ("match [\"add 5\", 2] { [\"add \" ++ n, m] { int(n) + m } }", "7", nested_within_list),
+    ("match \"move 5\" { \"move \" ++ n if int(n) > 3 { \"far\" } _ { \"near\" } }", "\"far\"", with_guard)
+}
+
+test_eval! {
+    suite regex_patterns;
+
+    sut r#"
+        let sut = |line| match line {
+            r"(?<dir>[LR])(?<n>\d+)" { [dir, int(n)] }
+            r"move (?<n>\d+) from (?<from>\d+) to (?<to>\d+)" { [n, from, to] }
+            r"(?<sign>-)?(?<value>\d+)\.(\d+)" { [sign, value] }
+            r"noop" { "noop" }
+            _ { "unknown" }
+        };
+    "#;
+
+    ("sut(\"L10\")", "[\"L\", 10]", named_captures),
+    ("sut(\"move 1 from 2 to 3\")", "[\"1\", \"2\", \"3\"]", multiple_captures),
+    ("sut(\"12.5\")", "[nil, \"12\"]", unmatched_optional_capture),
+    ("sut(\"noop\")", "\"noop\"", without_captures),
+    ("sut(\"L10 extra\")", "\"unknown\"", must_match_entire_string),
+    ("sut([\"L10\"])", "\"unknown\"", non_string_subject),
+    ("match [\"R5\", 1] { [r\"R(?<n>\\d+)\", m] { int(n) + m } }", "6", nested_within_list),
+    ("match \"a\" { r\"a|ab\" { \"matched\" } _ { \"unmatched\" } }", "\"matched\"", alternation_is_anchored),
+    ("match \"ab\" { r\"a|ab\" { \"matched\" } _ { \"unmatched\" } }", "\"matched\"", alternation_matches_longer),
+    ("lines(\"L1\\nR20\") |> map(|line| match line { r\"(?<d>[LR])(?<n>\\d+)\" { d + n } })", "[\"L1\", \"R20\"]", reused_across_calls)
+}
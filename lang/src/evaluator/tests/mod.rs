@@ -6,12 +6,15 @@ macro_rules! test_eval {
                 fn $case() {
                     let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new($source));
                     let program = parser.parse().unwrap();
-                    let mut evaluator = crate::evaluator::Evaluator::new();
-                    let actual = match evaluator.evaluate(&program) {
-                        Ok(value) => value.to_string(),
-                        Err(error) => error.message,
-                    };
-                    assert_eq!($expected, actual);
+                    for backend in [crate::evaluator::Backend::TreeWalking, crate::evaluator::Backend::Bytecode] {
+                        let mut evaluator = crate::evaluator::Evaluator::new();
+                        evaluator.set_backend(backend);
+                        let actual = match evaluator.evaluate(&program) {
+                            Ok(value) => value.to_string(),
+                            Err(error) => error.message,
+                        };
+                        assert_eq!($expected, actual, "{:?} backend", backend);
+                    }
                 }
             )*
         }
@@ -24,12 +27,15 @@ macro_rules! test_eval {
                     let source = format!("{}{}", $sut, $source);
                     let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(&source));
                     let program = parser.parse().unwrap();
-                    let mut evaluator = crate::evaluator::Evaluator::new();
-                    let actual = match evaluator.evaluate(&program) {
-                        Ok(value) => value.to_string(),
-                        Err(error) => error.message,
-                    };
-                    assert_eq!($expected, actual);
+                    for backend in [crate::evaluator::Backend::TreeWalking, crate::evaluator::Backend::Bytecode] {
+                        let mut evaluator = crate::evaluator::Evaluator::new();
+                        evaluator.set_backend(backend);
+                        let actual = match evaluator.evaluate(&program) {
+                            Ok(value) => value.to_string(),
+                            Err(error) => error.message,
+                        };
+                        assert_eq!($expected, actual, "{:?} backend", backend);
+                    }
                 }
             )*
         }
@@ -45,3 +51,4 @@ mod literals;
 mod matches;
 mod operators;
 mod sections;
+mod types;
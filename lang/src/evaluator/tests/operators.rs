@@ -11,8 +11,14 @@ test_eval! {
     ("[1, 2, 3] + [4, 5, 6]", "[1, 2, 3, 4, 5, 6]", list_list),
     ("[1, 2, 3] + {4}", "[1, 2, 3, 4]", list_set),
     ("#{1: 2} + #{2: 3}", "#{1: 2, 2: 3}", dictionary_dictionary),
-    ("{1, 2, 3} + {2, 3, 4}", "{1, 2, 4, 3}", set_set),
-    ("{1, 2, 3} + [2, 3, 4]", "{1, 2, 4, 3}", set_list),
+    ("{1, 2, 3} + {2, 3, 4}", "{1, 2, 3, 4}", set_set),
+    ("{1, 2, 3} + [2, 3, 4]", "{1, 2, 3, 4}", set_list),
+    ("9223372036854775807 + 1", "9223372036854775808", integer_overflow),
+    ("9223372036854775807 + 1.5", "9223372036854775808", integer_decimal_overflow),
+    ("9223372036854775808 + 1", "9223372036854775809", big_integer_integer),
+    ("9223372036854775808 + -9223372036854775808", "0", big_integer_demotion),
+    ("9223372036854775808 + 1.5", "9223372036854775809", big_integer_decimal),
+    ("\"a\" + 9223372036854775808", "\"a9223372036854775808\"", string_big_integer),
     ("+(1, 2)", "3", function_call)
 }
 
@@ -27,6 +33,9 @@ test_eval! {
     ("[1, 2, 3] - {3}", "[1, 2]", list_set),
     ("{1, 2, 3} - {2, 3, 4}", "{1}", set_set),
     ("{1, 2, 3} - [2, 3, 4]", "{1}", set_list),
+    ("-9223372036854775808 - 1", "-9223372036854775809", integer_overflow),
+    ("-9223372036854775808 - 1.5", "-9223372036854775809", integer_decimal_overflow),
+    ("9223372036854775808 - 1", "9223372036854775807", big_integer_demotion),
     ("let x = -; x(2, 1)", "1", function_call)
 }
 
@@ -39,6 +48,9 @@ test_eval! {
     ("1.5 * 3", "4.5", decimal_integer),
     ("\"a\" * 3", "\"aaa\"", string_integer),
     ("[1, 2] * 2", "[1, 2, 1, 2]", list_integer),
+    ("4294967296 * 4294967296", "18446744073709551616", integer_overflow),
+    ("4294967296 * 4294967296.5", "18446744073709551616", integer_decimal_overflow),
+    ("18446744073709551616 * 18446744073709551616", "340282366920938463463374607431768211456", big_integer_big_integer),
     ("*(2, 2)", "4", function_call)
 }
 
@@ -52,6 +64,10 @@ test_eval! {
     ("6 / 3.2", "1", integer_decimal),
     ("5.4 / 3.2", "1.6875", decimal_decimal),
     ("4.5 / 2", "2.25", decimal_integer),
+    ("-9223372036854775808 / -1", "9223372036854775808", integer_overflow),
+    ("18446744073709551616 / 4294967296", "4294967296", big_integer_demotion),
+    ("-18446744073709551617 / 4294967296", "-4294967297", big_integer_negative_dividend),
+    ("18446744073709551616 / 0", "Division by zero", big_integer_division_by_zero),
     ("/(4, 2)", "2", function_call)
 }
 
@@ -62,6 +78,9 @@ test_eval! {
     ("5 % -2", "-1", positive_negative),
     ("-5 % 4", "3", negative_positive),
     ("-5 % -4", "-1", negative_negative),
+    ("-9223372036854775808 % -1", "0", integer_overflow),
+    ("18446744073709551617 % 10", "7", big_integer_integer),
+    ("-18446744073709551617 % 10", "3", negative_big_integer_integer),
     ("%(5, 2)", "1", function_call)
 }
 
@@ -86,6 +105,10 @@ test_eval! {
     ("{1, 2, 3} == {1, 2, 3}", "true", true_set_set),
     ("{1, 2, 3} == {1, 2, 3, 2}", "true", true_set_set_with_duplicates),
     ("{1, 2, 3} == {2, 3, 4}", "false", false_set_set),
+    ("9223372036854775808 == 9223372036854775807 + 1", "true", true_big_integer_big_integer),
+    ("9223372036854775808 - 1 == 9223372036854775807", "true", true_demoted_big_integer_integer),
+    ("{9223372036854775808, 9223372036854775807 + 1}", "{9223372036854775808}", big_integer_set_element),
+    ("#{9223372036854775808: 1}[9223372036854775807 + 1]", "1", big_integer_dictionary_key),
     ("==(1, 1)", "true", function_call)
 }
 
@@ -120,6 +143,9 @@ test_eval! {
     ("2 < 1", "false", false_integer_integer),
     ("1.5 < 2.5", "true", true_decimal_decimal),
     ("2.5 < 1.5", "false", false_decimal_decimal),
+    ("1 < 9223372036854775808", "true", true_integer_big_integer),
+    ("-9223372036854775809 < 1", "true", true_negative_big_integer_integer),
+    ("9223372036854775809 < 9223372036854775808", "false", false_big_integer_big_integer),
     ("<(1, 2)", "true", function_call)
 }
 
@@ -142,6 +168,8 @@ test_eval! {
     ("1 > 2", "false", false_integer_integer),
     ("2.5 > 1.5", "true", true_decimal_decimal),
     ("1.5 > 2.5", "false", false_decimal_decimal),
+    ("9223372036854775808 > 1", "true", true_big_integer_integer),
+    ("-9223372036854775809 > 1", "false", false_negative_big_integer_integer),
     (">(2, 1)", "true", function_call)
 }
 
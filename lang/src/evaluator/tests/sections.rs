@@ -155,6 +155,104 @@ fn multiple_sections_with_same_name() {
     );
 }
 
+#[test]
+fn section_attributes() {
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new("@slow @timeout(5000) test: 1"));
+    let mut evaluator = crate::evaluator::Evaluator::new();
+    let environment = crate::evaluator::Environment::new();
+
+    evaluator
+        .evaluate_with_environment(&parser.parse().expect("Ok"), std::rc::Rc::clone(&environment))
+        .expect("Ok");
+
+    let sections = environment.borrow().get_sections_with_attributes("test");
+    let attributes: Vec<String> = sections[0].1.iter().map(|attribute| attribute.to_string()).collect();
+    assert_eq!(vec!["@slow", "@timeout(5000)"], attributes);
+}
+
+#[test]
+fn let_binding_attributes() {
+    assert_binding_attributes(
+        r#"
+            @input("sample.txt")
+            @timeout(5000)
+            let solve = |input| input;
+            let other = 1;
+        "#,
+        "solve",
+        expect![[r#"
+            [
+                Attribute {
+                    name: "input",
+                    arguments: [
+                        Expression {
+                            kind: String(
+                                "sample.txt",
+                            ),
+                            source: 20..32,
+                        },
+                    ],
+                    source: 13..33,
+                },
+                Attribute {
+                    name: "timeout",
+                    arguments: [
+                        Expression {
+                            kind: Integer(
+                                "5000",
+                            ),
+                            source: 55..59,
+                        },
+                    ],
+                    source: 46..60,
+                },
+            ]"#]],
+    );
+}
+
+#[test]
+fn let_binding_without_attributes() {
+    assert_binding_attributes("let other = 1;", "other", expect!["[]"]);
+}
+
+#[test]
+fn mutable_let_binding_attributes() {
+    assert_binding_attributes(
+        "@memo let mut value = 1; value = 2; value",
+        "value",
+        expect![[r#"
+            [
+                Attribute {
+                    name: "memo",
+                    arguments: [],
+                    source: 0..1,
+                },
+            ]"#]],
+    );
+}
+
+fn assert_binding_attributes(source: &str, name: &str, expected: Expect) {
+    let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
+    let program = parser.parse().expect("Ok");
+
+    for backend in [
+        crate::evaluator::Backend::TreeWalking,
+        crate::evaluator::Backend::Bytecode,
+    ] {
+        let mut evaluator = crate::evaluator::Evaluator::new();
+        evaluator.set_backend(backend);
+        let outer = crate::evaluator::Environment::new();
+        let environment = crate::evaluator::Environment::from(std::rc::Rc::clone(&outer));
+
+        evaluator
+            .evaluate_with_environment(&program, std::rc::Rc::clone(&outer))
+            .expect("Ok");
+
+        let actual = format!("{:#?}", environment.borrow().get_binding_attributes(name));
+        expected.assert_eq(&actual);
+    }
+}
+
 fn assert_section(source: &str, name: &str, expected: Expect) {
     let mut parser = crate::parser::Parser::new(crate::lexer::Lexer::new(source));
     let program = parser.parse();
@@ -0,0 +1,65 @@
+test_eval! {
+    suite types;
+
+    ("type Instr = Move(dx, dy) | Stop; [Move(1, 2), Stop]", "[Move(1, 2), Stop]", variant_construction),
+    ("type Instr = Move(dx, dy) | Stop; Move(1)(2)", "Move(1, 2)", curried_constructor),
+    ("type Instr = Move(dx, dy) | Stop; Move(_, 2)(1)", "Move(1, 2)", placeholder_constructor),
+    ("type Instr = Move(dx, dy) | Stop; Move", "|dx, dy| { [constructor] }", constructor_display),
+    ("type Instr = Move(dx, dy) | Stop; [type(Move(1, 2)), type(Stop)]", "[\"Instr\", \"Instr\"]", variant_type_name),
+    (
+        "type Instr = Move(dx, dy) | Stop; [Move(1, 2) == Move(1, 2), Move(1, 2) == Move(2, 1), Stop == Stop]",
+        "[true, false, true]",
+        structural_equality
+    ),
+    ("type Instr = Move(dx, dy) | Stop; size({Move(1, 2), Move(1, 2), Stop})", "2", structural_hashing),
+    (
+        "type A = Same; let b = if true { type B = Same; Same }; [Same == b, type(b)]",
+        "[false, \"B\"]",
+        variants_of_distinct_types_are_not_equal
+    ),
+    ("type A = Same; type B = Same;", "Variable 'Same' has already been declared", redeclared_variant),
+    ("type Dir = Turn(dir); 1..4 |> map(Turn) |> list", "[Turn(1), Turn(2), Turn(3)]", constructor_as_function),
+    ("type Instr = Move(dx, dy) | Stop; Move(1, 2, 3)", "Move(1, 2)", surplus_arguments_are_ignored)
+}
+
+test_eval! {
+    suite type_matches;
+
+    sut r#"
+        type Instr = Move(dx, dy) | Turn(dir) | Stop;
+        let sut = |instr| match instr {
+            Move(0, 0) { "stay" }
+            Move(dx, dy) if dx > dy { ["right", dx, dy] }
+            Move(dx, dy) { ["move", dx, dy] }
+            Turn([a, ..rest]) { ["turns", a, rest] }
+            Turn { "turn" }
+            Stop { "stop" }
+            _ { "other" }
+        };
+    "#;
+
+    ("sut(Move(0, 0))", "\"stay\"", literal_fields),
+    ("sut(Move(5, 1))", "[\"right\", 5, 1]", guarded_fields),
+    ("sut(Move(1, 5))", "[\"move\", 1, 5]", identifier_fields),
+    ("sut(Turn([1, 2, 3]))", "[\"turns\", 1, [2, 3]]", nested_list_field),
+    ("sut(Turn(90))", "\"turn\"", name_only),
+    ("sut(Stop)", "\"stop\"", nullary_variant),
+    ("sut([\"move\", 1, 2])", "\"other\"", non_variant_subject),
+    (
+        "match [Stop, Move(1, [2, 3])] { [Stop, Move(a, [b, ..c])] { [a, b, c] } }",
+        "[1, 2, [3]]",
+        nested_within_list
+    ),
+    ("match #{\"at\": Move(1, 2)} { #{\"at\": Move(x, y)} { x + y } }", "3", nested_within_dictionary),
+    ("match 42 { X { X + 1 } }", "43", undeclared_name_binds_subject),
+    (
+        "type A = Same; let [b, check] = if true { type B = Same; [Same, |x| match x { Same { \"b\" } _ { \"other\" } }] }; [check(Same), check(b)]",
+        "[\"other\", \"b\"]",
+        variants_of_distinct_types_do_not_match
+    ),
+    (
+        "type A = Same; let b = if true { type B = Same; Same }; match b { Same { \"a\" } _ { \"other\" } }",
+        "\"other\"",
+        global_variant_of_distinct_type_does_not_match
+    )
+}
@@ -0,0 +1,1550 @@
+use crate::evaluator::vm::instruction::{Chunk, Instruction, RangePattern};
+use crate::evaluator::vm::{Capture, Prototype};
+use crate::evaluator::{Function, Object};
+use crate::lexer::Location;
+use crate::parser::ast::{
+    Expression, ExpressionKind, Infix, MatchCase, Prefix, Program, Statement, StatementKind, StringPart,
+};
+use crate::parser::compile_regex_pattern;
+use ordered_float::OrderedFloat;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy)]
+enum Variable {
+    Slot(u32),
+    Cell(u32),
+    Upvalue(u32),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Binding {
+    Let { mutable: bool },
+    Parameter,
+    Match,
+}
+
+enum Key {
+    Constant(u32),
+    Slot(u32),
+}
+
+struct Local {
+    name: String,
+    variable: Variable,
+    mutable: bool,
+    declared: bool,
+}
+
+// The temporaries holding each collection along an index assignment target, and the index into it.
+struct IndexTarget<'a> {
+    name: &'a str,
+    source: Location,
+    collections: Vec<u32>,
+    keys: Vec<u32>,
+    sources: Vec<Location>,
+}
+
+struct FunctionState {
+    chunk: Chunk,
+    scopes: Vec<Vec<Local>>,
+    locals: u32,
+    cells: u32,
+    captures: Vec<Capture>,
+    captured_names: HashSet<String>,
+    program: bool,
+}
+
+struct Compiler {
+    functions: Vec<FunctionState>,
+}
+
+pub fn compile_program(program: &Program) -> Rc<Prototype> {
+    let mut captured_names = HashSet::new();
+    for statement in &program.statements {
+        collect_statement(statement, false, &mut captured_names);
+    }
+
+    let mut compiler = Compiler { functions: vec![] };
+    compiler.functions.push(FunctionState {
+        chunk: Chunk::default(),
+        scopes: vec![],
+        locals: 0,
+        cells: 0,
+        captures: vec![],
+        captured_names,
+        program: true,
+    });
+    compiler.compile_statements(&program.statements, false);
+    compiler.emit(Instruction::Return, program.source);
+
+    Rc::new(compiler.end_function(vec![]))
+}
+
+impl Compiler {
+    fn function(&mut self) -> &mut FunctionState {
+        self.functions.last_mut().unwrap()
+    }
+
+    fn emit(&mut self, instruction: Instruction, source: Location) -> usize {
+        self.function().chunk.emit(instruction, source)
+    }
+
+    fn emit_constant(&mut self, object: Object, source: Location) {
+        let constant = self.function().chunk.add_constant(Rc::new(object));
+        self.emit(Instruction::Constant(constant), source);
+    }
+
+    fn string_constant(&mut self, value: &str) -> u32 {
+        self.function()
+            .chunk
+            .add_constant(Rc::new(Object::String(value.to_owned())))
+    }
+
+    fn emit_error(&mut self, message: String, source: Location) {
+        let message = self.function().chunk.add_name(&message);
+        self.emit(Instruction::Error(message), source);
+    }
+
+    fn patch_jump(&mut self, position: usize) {
+        self.function().chunk.patch_jump(position);
+    }
+
+    fn end_function(&mut self, parameters: Vec<Expression>) -> Prototype {
+        let function = self.functions.pop().unwrap();
+        Prototype {
+            parameters,
+            chunk: function.chunk,
+            locals: function.locals as usize,
+            cells: function.cells as usize,
+            captures: function.captures,
+        }
+    }
+
+    fn temporary(&mut self) -> u32 {
+        let function = self.function();
+        function.locals += 1;
+        function.locals - 1
+    }
+
+    fn allocate(&mut self, name: &str) -> Variable {
+        let function = self.function();
+        if function.captured_names.contains(name) {
+            function.cells += 1;
+            Variable::Cell(function.cells - 1)
+        } else {
+            function.locals += 1;
+            Variable::Slot(function.locals - 1)
+        }
+    }
+
+    // Closures may refer to variables declared later within an enclosing block (i.e. mutual recursion), so
+    // these are reserved up front and only become visible to the block itself once they have been declared.
+    fn begin_scope(&mut self, statements: &[Statement]) {
+        self.function().scopes.push(vec![]);
+
+        for statement in statements {
+            if let StatementKind::Expression(expression) | StatementKind::Attributed { expression, .. } =
+                &statement.kind
+            {
+                match &expression.kind {
+                    ExpressionKind::Let { name, .. } => self.reserve(name, false),
+                    ExpressionKind::MutableLet { name, .. } => self.reserve(name, true),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn end_scope(&mut self) {
+        self.function().scopes.pop();
+    }
+
+    fn reserve(&mut self, pattern: &Expression, mutable: bool) {
+        let mut names = vec![];
+        collect_pattern_names(pattern, &mut names);
+
+        for name in names {
+            let Some(scope) = self.function().scopes.last() else {
+                return;
+            };
+            if scope.iter().any(|local| local.name == name) {
+                continue;
+            }
+            let variable = self.allocate(&name);
+            self.function().scopes.last_mut().unwrap().push(Local {
+                name,
+                variable,
+                mutable,
+                declared: false,
+            });
+        }
+    }
+
+    fn resolve(&mut self, depth: usize, name: &str, crossed: bool) -> Option<(Variable, bool)> {
+        for scope in self.functions[depth].scopes.iter().rev() {
+            for local in scope.iter().rev() {
+                if local.name == name && (local.declared || crossed) {
+                    return Some((local.variable, local.mutable));
+                }
+            }
+        }
+
+        if depth == 0 {
+            return None;
+        }
+
+        let (variable, mutable) = self.resolve(depth - 1, name, true)?;
+        let capture = match variable {
+            Variable::Cell(index) => Capture::Cell(index),
+            Variable::Upvalue(index) => Capture::Upvalue(index),
+            Variable::Slot(_) => return None,
+        };
+        let captures = &mut self.functions[depth].captures;
+        let index = match captures.iter().position(|existing| *existing == capture) {
+            Some(index) => index,
+            None => {
+                captures.push(capture);
+                captures.len() - 1
+            }
+        };
+
+        Some((Variable::Upvalue(index as u32), mutable))
+    }
+
+    fn emit_get(&mut self, variable: Variable, source: Location) {
+        let instruction = match variable {
+            Variable::Slot(slot) => Instruction::GetLocal(slot),
+            Variable::Cell(index) => Instruction::GetCell(index),
+            Variable::Upvalue(index) => Instruction::GetUpvalue(index),
+        };
+        self.emit(instruction, source);
+    }
+
+    fn emit_set(&mut self, variable: Variable, source: Location) {
+        let instruction = match variable {
+            Variable::Slot(slot) => Instruction::SetLocal(slot),
+            Variable::Cell(index) => Instruction::SetCell(index),
+            Variable::Upvalue(index) => Instruction::SetUpvalue(index),
+        };
+        self.emit(instruction, source);
+    }
+
+    fn declare(&mut self, name: &str, binding: Binding, source: Location) {
+        let mutable = matches!(binding, Binding::Let { mutable: true });
+
+        if self.function().scopes.is_empty() {
+            let name = self.function().chunk.add_name(name);
+            self.emit(Instruction::DeclareGlobal { name, mutable }, source);
+            return;
+        }
+
+        let scope = self.function().scopes.last_mut().unwrap();
+        let existing = scope.iter_mut().rev().find(|local| local.name == name);
+        let variable = match (existing, binding) {
+            (Some(local), Binding::Parameter) => local.variable,
+            (Some(local), _) if local.declared => {
+                self.emit_error(format!("Variable '{}' has already been declared", name), source);
+                return;
+            }
+            (Some(local), _) => {
+                local.declared = true;
+                local.mutable = mutable;
+                local.variable
+            }
+            (None, _) => {
+                let variable = self.allocate(name);
+                self.function().scopes.last_mut().unwrap().push(Local {
+                    name: name.to_owned(),
+                    variable,
+                    mutable,
+                    declared: true,
+                });
+                variable
+            }
+        };
+
+        self.emit_set(variable, source);
+    }
+
+    fn compile_statements(&mut self, statements: &[Statement], tail: bool) {
+        let mut empty = true;
+
+        for (index, statement) in statements.iter().enumerate() {
+            if let StatementKind::Comment(_) = statement.kind {
+                continue;
+            }
+            if !empty {
+                self.emit(Instruction::Pop, statement.source);
+            }
+            self.compile_statement(statement, tail && index == statements.len() - 1);
+            empty = false;
+        }
+
+        if empty {
+            let source = statements.first().map_or(Location { start: 0, end: 0 }, |s| s.source);
+            self.emit_constant(Object::Nil, source);
+        }
+    }
+
+    fn compile_statement(&mut self, statement: &Statement, tail: bool) {
+        let source = statement.source;
+
+        match &statement.kind {
+            StatementKind::Return(value) => {
+                if self.function().program {
+                    self.compile_expression(value, false);
+                    self.emit(Instruction::ReturnSignal, source);
+                } else {
+                    self.compile_expression(value, true);
+                    self.emit(Instruction::Return, source);
+                }
+            }
+            StatementKind::Break(value) => {
+                self.compile_expression(value, false);
+                self.emit(Instruction::Break, source);
+            }
+            StatementKind::Comment(_) => self.emit_constant(Object::Nil, source),
+            StatementKind::Section { name, body, attributes } => {
+                let chunk = &mut self.function().chunk;
+                chunk
+                    .sections
+                    .push((name.to_owned(), Rc::new(*body.clone()), attributes.clone()));
+                let index = (chunk.sections.len() - 1) as u32;
+                self.emit(Instruction::Section(index), source);
+            }
+            StatementKind::Import { path, bindings } => {
+                let chunk = &mut self.function().chunk;
+                chunk.imports.push((path.to_owned(), bindings.clone()));
+                let index = (chunk.imports.len() - 1) as u32;
+                self.emit(Instruction::Import(index), source);
+            }
+            StatementKind::Type { name, variants } => {
+                for variant in variants {
+                    self.emit_constant(Function::constructor(name, variant), source);
+                    self.declare(&variant.name, Binding::Let { mutable: false }, source);
+                    self.emit(Instruction::Pop, source);
+                }
+                self.emit_constant(Object::Nil, source);
+            }
+            StatementKind::Attributed {
+                name,
+                attributes,
+                expression,
+            } => {
+                self.compile_expression(expression, false);
+                let chunk = &mut self.function().chunk;
+                chunk.binding_attributes.push((name.to_owned(), attributes.clone()));
+                let index = (chunk.binding_attributes.len() - 1) as u32;
+                self.emit(Instruction::BindingAttributes(index), source);
+            }
+            StatementKind::Expression(expression) => self.compile_expression(expression, tail),
+            StatementKind::Block(statements) => {
+                self.begin_scope(statements);
+                self.compile_statements(statements, tail);
+                self.end_scope();
+            }
+        }
+    }
+
+    fn compile_expressions(&mut self, expressions: &[Expression]) -> Option<u32> {
+        if !expressions
+            .iter()
+            .any(|expression| matches!(expression.kind, ExpressionKind::Spread(_)))
+        {
+            for expression in expressions {
+                self.compile_expression(expression, false);
+            }
+            return Some(expressions.len() as u32);
+        }
+
+        let source = expressions[0].source;
+        self.emit(Instruction::List(0), source);
+        for expression in expressions {
+            if let ExpressionKind::Spread(value) = &expression.kind {
+                self.compile_expression(value, false);
+                self.emit(Instruction::Extend, expression.source);
+            } else {
+                self.compile_expression(expression, false);
+                self.emit(Instruction::Append, expression.source);
+            }
+        }
+        None
+    }
+
+    fn compile_expression(&mut self, expression: &Expression, tail: bool) {
+        let source = expression.source;
+
+        match &expression.kind {
+            ExpressionKind::Let { name, value } => self.compile_let(name, value, false),
+            ExpressionKind::MutableLet { name, value } => self.compile_let(name, value, true),
+            ExpressionKind::Assign { name, value } => {
+                self.compile_expression(value, false);
+                self.compile_store(name);
+            }
+            ExpressionKind::CompoundAssign { name, operator, value }
+                if matches!(name.kind, ExpressionKind::Index { .. }) =>
+            {
+                self.compile_compound_assign_index(name, operator, value);
+            }
+            ExpressionKind::CompoundAssign { name, operator, value } => {
+                self.compile_expression(name, false);
+                self.compile_expression(value, false);
+                self.emit(operator_instruction(operator), source);
+                self.compile_store(name);
+            }
+            ExpressionKind::Identifier(name) => match self.resolve(self.functions.len() - 1, name, false) {
+                Some((variable, _)) => self.emit_get(variable, source),
+                None => {
+                    let name = self.function().chunk.add_name(name);
+                    self.emit(Instruction::GetGlobal(name), source);
+                }
+            },
+            ExpressionKind::Integer(value) => self.emit_constant(Object::from_integer_literal(value), source),
+            ExpressionKind::Decimal(value) => {
+                let value = value.replace('_', "").parse::<OrderedFloat<f64>>().unwrap();
+                self.emit_constant(Object::Decimal(value), source);
+            }
+            ExpressionKind::String(value) => self.emit_constant(Object::String(value.to_owned()), source),
+            ExpressionKind::InterpolatedString(parts) => {
+                for part in parts {
+                    match part {
+                        StringPart::Literal(literal) => self.emit_constant(Object::String(literal.to_owned()), source),
+                        StringPart::Expression(expression) => self.compile_expression(expression, false),
+                    }
+                }
+                self.emit(Instruction::Interpolate(parts.len() as u32), source);
+            }
+            ExpressionKind::Boolean(value) => self.emit_constant(Object::Boolean(*value), source),
+            ExpressionKind::Nil => self.emit_constant(Object::Nil, source),
+            ExpressionKind::Placeholder => self.emit_constant(Object::Placeholder, source),
+            ExpressionKind::If {
+                condition,
+                consequence,
+                alternative,
+            } => {
+                self.compile_expression(condition, false);
+                let otherwise = self.emit(Instruction::JumpIfFalse(0), source);
+                self.compile_statement(consequence, tail);
+                let end = self.emit(Instruction::Jump(0), source);
+                self.patch_jump(otherwise);
+                match alternative {
+                    Some(alternative) => self.compile_statement(alternative, tail),
+                    None => self.emit_constant(Object::Nil, source),
+                }
+                self.patch_jump(end);
+            }
+            ExpressionKind::Match { subject, cases } => self.compile_match(subject, cases, tail),
+            ExpressionKind::Function { parameters, body } => self.compile_function(parameters, body, source),
+            ExpressionKind::Call { function, arguments } => {
+                self.compile_expression(function, false);
+                match self.compile_expressions(arguments) {
+                    Some(count) if tail => self.emit(Instruction::TailCall(count), function.source),
+                    Some(count) => self.emit(Instruction::Call(count), function.source),
+                    None => self.emit(Instruction::CallSpread, function.source),
+                };
+            }
+            ExpressionKind::List(elements) => {
+                if let Some(count) = self.compile_expressions(elements) {
+                    self.emit(Instruction::List(count), source);
+                }
+            }
+            ExpressionKind::Set(elements) => match self.compile_expressions(elements) {
+                Some(count) => {
+                    self.emit(Instruction::Set(count), source);
+                }
+                None => {
+                    self.emit(Instruction::ListToSet, source);
+                }
+            },
+            ExpressionKind::Dictionary(entries) => {
+                for (key, value) in entries {
+                    self.compile_expression(key, false);
+                    self.emit(Instruction::CheckKey, key.source);
+                    self.compile_expression(value, false);
+                }
+                self.emit(Instruction::Dictionary(entries.len() as u32), source);
+            }
+            ExpressionKind::Index { left, index } => {
+                self.compile_expression(left, false);
+                self.compile_expression(index, false);
+                self.emit(Instruction::Index, index.source);
+            }
+            ExpressionKind::FunctionThread { initial, functions } => {
+                self.compile_expression(initial, false);
+                for function in functions {
+                    self.compile_expression(function, false);
+                    self.emit(Instruction::Swap, function.source);
+                    self.emit(Instruction::Call(1), function.source);
+                }
+            }
+            ExpressionKind::FunctionComposition(functions) => {
+                for function in functions {
+                    self.compile_expression(function, false);
+                    self.emit(Instruction::CheckFunction, function.source);
+                }
+                self.emit(Instruction::Compose(functions.len() as u32), source);
+            }
+            ExpressionKind::InclusiveRange { from, to } => {
+                self.compile_expression(from, false);
+                self.compile_expression(to, false);
+                self.emit(Instruction::InclusiveRange, source);
+            }
+            ExpressionKind::ExclusiveRange { from, until } => {
+                self.compile_expression(from, false);
+                self.compile_expression(until, false);
+                self.emit(Instruction::ExclusiveRange, source);
+            }
+            ExpressionKind::UnboundedRange { from } => {
+                self.compile_expression(from, false);
+                self.emit(Instruction::UnboundedRange, source);
+            }
+            ExpressionKind::Infix { left, operator, right } => self.compile_infix(left, operator, right, source),
+            ExpressionKind::Prefix { operator, right } => {
+                self.compile_expression(right, false);
+                match operator {
+                    Prefix::Bang => self.emit(Instruction::Not, right.source),
+                    Prefix::Minus => self.emit(Instruction::Negate, right.source),
+                };
+            }
+            ExpressionKind::Spread(_) => self.emit_error("Unable to spread within this context".to_owned(), source),
+            // Patterns are compiled as part of the let binding, parameter list or match case they appear within.
+            ExpressionKind::RestIdentifier(_)
+            | ExpressionKind::IdentifierListPattern(_)
+            | ExpressionKind::ListMatchPattern(_)
+            | ExpressionKind::IdentifierDictionaryPattern(_)
+            | ExpressionKind::DictionaryMatchPattern(_)
+            | ExpressionKind::DictionaryEntryPattern { .. }
+            | ExpressionKind::VariantMatchPattern { .. }
+            | ExpressionKind::OrMatchPattern(_)
+            | ExpressionKind::StringMatchPattern { .. }
+            | ExpressionKind::RegexMatchPattern { .. }
+            | ExpressionKind::BindingMatchPattern { .. } => {
+                self.emit_error(format!("Unexpected pattern: {}", expression), source)
+            }
+            // Programs are compiled as parsed, with the compiler resolving identifiers itself.
+            ExpressionKind::ResolvedIdentifier { .. } | ExpressionKind::ResolvedRestIdentifier { .. } => {
+                unreachable!("Resolved identifiers are not compiled")
+            }
+        }
+    }
+
+    fn compile_let(&mut self, name: &Expression, value: &Expression, mutable: bool) {
+        self.reserve(name, mutable);
+        self.compile_expression(value, false);
+
+        let binding = Binding::Let { mutable };
+        match &name.kind {
+            ExpressionKind::Identifier(id) => self.declare(id, binding, name.source),
+            ExpressionKind::IdentifierListPattern(pattern) => {
+                let subject = self.temporary();
+                self.emit(Instruction::SetLocal(subject), name.source);
+                self.destructure_list(pattern, subject, binding, name.source);
+            }
+            ExpressionKind::IdentifierDictionaryPattern(pattern) => {
+                let subject = self.temporary();
+                self.emit(Instruction::SetLocal(subject), name.source);
+                self.destructure_dictionary(pattern, subject, binding, name.source);
+            }
+            _ => self.emit_error(format!("Unexpected Let identifier, found: {}", name.kind), name.source),
+        }
+    }
+
+    // Stores the value on top of the stack into the assignment target, leaving it on the stack.
+    fn compile_store(&mut self, target: &Expression) {
+        match &target.kind {
+            ExpressionKind::Identifier(name) => self.compile_store_identifier(name, target.source),
+            ExpressionKind::Index { .. } => self.compile_store_index(target),
+            _ => self.emit_error(format!("Unable to assign to: {}", target), target.source),
+        }
+    }
+
+    fn compile_store_identifier(&mut self, name: &str, source: Location) {
+        match self.resolve(self.functions.len() - 1, name, false) {
+            Some((variable, true)) => self.emit_set(variable, source),
+            Some((_, false)) => self.emit_error(format!("Variable '{}' is not mutable", name), source),
+            None => {
+                let name = self.function().chunk.add_name(name);
+                self.emit(Instruction::AssignGlobal(name), source);
+            }
+        }
+    }
+
+    fn compile_store_index(&mut self, target: &Expression) {
+        let value = self.temporary();
+        self.emit(Instruction::SetLocal(value), target.source);
+        self.emit(Instruction::Pop, target.source);
+
+        if let Some(index_target) = self.compile_index_target(target) {
+            self.compile_store_index_target(&index_target, value, target.source);
+        }
+    }
+
+    // The target's collection and indices are evaluated once, so that a compound assignment
+    // (i.e. `xs[f()] += 1`) reads from and writes to the very same location.
+    fn compile_compound_assign_index(&mut self, target: &Expression, operator: &Infix, value: &Expression) {
+        let Some(index_target) = self.compile_index_target(target) else {
+            return;
+        };
+
+        let last = index_target.keys.len() - 1;
+        self.emit(Instruction::GetLocal(index_target.collections[last]), target.source);
+        self.emit(Instruction::GetLocal(index_target.keys[last]), target.source);
+        self.emit(Instruction::Index, target.source);
+        self.compile_expression(value, false);
+        self.emit(operator_instruction(operator), target.source);
+
+        let assigned = self.temporary();
+        self.emit(Instruction::SetLocal(assigned), target.source);
+        self.emit(Instruction::Pop, target.source);
+        self.compile_store_index_target(&index_target, assigned, target.source);
+    }
+
+    fn compile_index_target<'b>(&mut self, target: &'b Expression) -> Option<IndexTarget<'b>> {
+        let mut indices = vec![];
+        let mut root = target;
+        while let ExpressionKind::Index { left, index } = &root.kind {
+            indices.push(index);
+            root = left;
+        }
+        indices.reverse();
+
+        let ExpressionKind::Identifier(name) = &root.kind else {
+            self.emit_error(format!("Unable to assign to: {}", target), target.source);
+            return None;
+        };
+
+        let collections: Vec<u32> = indices.iter().map(|_| self.temporary()).collect();
+        let keys: Vec<u32> = indices.iter().map(|_| self.temporary()).collect();
+
+        self.compile_expression(root, false);
+        self.emit(Instruction::SetLocal(collections[0]), root.source);
+        self.emit(Instruction::Pop, root.source);
+        for (position, index) in indices.iter().enumerate() {
+            self.compile_expression(index, false);
+            self.emit(Instruction::SetLocal(keys[position]), index.source);
+            self.emit(Instruction::Pop, index.source);
+            if position < indices.len() - 1 {
+                self.emit(Instruction::GetLocal(collections[position]), index.source);
+                self.emit(Instruction::GetLocal(keys[position]), index.source);
+                self.emit(Instruction::Index, index.source);
+                self.emit(Instruction::SetLocal(collections[position + 1]), index.source);
+                self.emit(Instruction::Pop, index.source);
+            }
+        }
+
+        Some(IndexTarget {
+            name,
+            source: root.source,
+            collections,
+            keys,
+            sources: indices.iter().map(|index| index.source).collect(),
+        })
+    }
+
+    fn compile_store_index_target(&mut self, target: &IndexTarget, value: u32, source: Location) {
+        self.emit(Instruction::GetLocal(value), source);
+        for position in (0..target.keys.len()).rev() {
+            let index_source = target.sources[position];
+            let assigned = self.temporary();
+            self.emit(Instruction::SetLocal(assigned), index_source);
+            self.emit(Instruction::Pop, index_source);
+            self.emit(Instruction::GetLocal(target.collections[position]), index_source);
+            self.emit(Instruction::GetLocal(target.keys[position]), index_source);
+            self.emit(Instruction::GetLocal(assigned), index_source);
+            self.emit(Instruction::SetIndex, index_source);
+        }
+
+        self.compile_store_identifier(target.name, target.source);
+        self.emit(Instruction::Pop, source);
+        self.emit(Instruction::GetLocal(value), source);
+    }
+
+    fn compile_infix(&mut self, left: &Expression, operator: &Infix, right: &Expression, source: Location) {
+        // Operators with placeholder operands are sugar for closures, i.e. `_ + 1` is `|a| a + 1`.
+        let identifier = |name: &str, source: Location| Expression {
+            kind: ExpressionKind::Identifier(name.to_owned()),
+            source,
+        };
+        let placeholder_closure = match (&left.kind, &right.kind) {
+            (ExpressionKind::Placeholder, ExpressionKind::Placeholder) => Some((
+                vec![identifier("a", left.source), identifier("b", right.source)],
+                identifier("a", left.source),
+                identifier("b", right.source),
+            )),
+            (ExpressionKind::Placeholder, _) => Some((
+                vec![identifier("a", left.source)],
+                identifier("a", left.source),
+                right.clone(),
+            )),
+            (_, ExpressionKind::Placeholder) => Some((
+                vec![identifier("b", left.source)],
+                left.clone(),
+                identifier("b", left.source),
+            )),
+            _ => None,
+        };
+        if let Some((parameters, left, right)) = placeholder_closure {
+            let body = Statement {
+                kind: StatementKind::Expression(Box::new(Expression {
+                    kind: ExpressionKind::Infix {
+                        left: Box::new(left),
+                        operator: operator.clone(),
+                        right: Box::new(right),
+                    },
+                    source,
+                })),
+                source,
+                preceded_by_blank_line: false,
+                trailing_comment: None,
+            };
+            self.compile_function(&parameters, &body, source);
+            return;
+        }
+
+        match operator {
+            Infix::And | Infix::Or => {
+                let is_and = matches!(operator, Infix::And);
+                self.compile_expression(left, false);
+                if !is_and {
+                    self.emit(Instruction::Not, source);
+                }
+                let short_circuit_left = self.emit(Instruction::JumpIfFalse(0), source);
+                self.compile_expression(right, false);
+                if !is_and {
+                    self.emit(Instruction::Not, source);
+                }
+                let short_circuit_right = self.emit(Instruction::JumpIfFalse(0), source);
+                self.emit_constant(Object::Boolean(is_and), source);
+                let end = self.emit(Instruction::Jump(0), source);
+                self.patch_jump(short_circuit_left);
+                self.patch_jump(short_circuit_right);
+                self.emit_constant(Object::Boolean(!is_and), source);
+                self.patch_jump(end);
+            }
+            Infix::Call(function) => {
+                self.compile_expression(function, false);
+                self.compile_expression(left, false);
+                self.compile_expression(right, false);
+                self.emit(Instruction::Call(2), function.source);
+            }
+            _ => {
+                self.compile_expression(left, false);
+                self.compile_expression(right, false);
+                self.emit(operator_instruction(operator), source);
+            }
+        }
+    }
+
+    fn compile_function(&mut self, parameters: &[Expression], body: &Statement, source: Location) {
+        let mut captured_names = HashSet::new();
+        collect_statement(body, false, &mut captured_names);
+
+        self.functions.push(FunctionState {
+            chunk: Chunk::default(),
+            scopes: vec![vec![]],
+            locals: parameters.len() as u32,
+            cells: 0,
+            captures: vec![],
+            captured_names,
+            program: false,
+        });
+
+        for (position, parameter) in parameters.iter().enumerate() {
+            let position = position as u32;
+            match &parameter.kind {
+                ExpressionKind::Identifier(name) | ExpressionKind::RestIdentifier(name) => {
+                    let variable = if self.function().captured_names.contains(name) {
+                        let variable = self.allocate(name);
+                        self.emit(Instruction::GetLocal(position), parameter.source);
+                        self.emit_set(variable, parameter.source);
+                        self.emit(Instruction::Pop, parameter.source);
+                        variable
+                    } else {
+                        Variable::Slot(position)
+                    };
+                    self.function().scopes.last_mut().unwrap().push(Local {
+                        name: name.to_owned(),
+                        variable,
+                        mutable: false,
+                        declared: true,
+                    });
+                }
+                ExpressionKind::IdentifierListPattern(pattern) => {
+                    self.destructure_list(pattern, position, Binding::Parameter, parameter.source);
+                }
+                ExpressionKind::IdentifierDictionaryPattern(pattern) => {
+                    self.destructure_dictionary(pattern, position, Binding::Parameter, parameter.source);
+                }
+                ExpressionKind::Placeholder => {}
+                _ => self.emit_error(
+                    format!("Unexpected parameter, found: {}", parameter.kind),
+                    parameter.source,
+                ),
+            }
+        }
+
+        self.compile_statement(body, true);
+        self.emit(Instruction::Return, body.source);
+
+        let prototype = Rc::new(self.end_function(parameters.to_vec()));
+        let chunk = &mut self.function().chunk;
+        chunk.prototypes.push(prototype);
+        let index = (chunk.prototypes.len() - 1) as u32;
+        self.emit(Instruction::Closure(index), source);
+    }
+
+    fn bind(&mut self, name: &str, binding: Binding, source: Location) {
+        self.declare(name, binding, source);
+        self.emit(Instruction::Pop, source);
+    }
+
+    fn destructure_list(&mut self, pattern: &[Expression], subject: u32, binding: Binding, source: Location) {
+        let argument = matches!(binding, Binding::Parameter);
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit(Instruction::ExpectList { argument }, source);
+
+        for (position, element) in pattern.iter().enumerate() {
+            let position = position as u32;
+            match &element.kind {
+                ExpressionKind::Identifier(name) => {
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    self.emit(Instruction::ListElement(position), element.source);
+                    self.bind(name, binding, element.source);
+                }
+                ExpressionKind::RestIdentifier(name) => {
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    self.emit(Instruction::ListRest(position), element.source);
+                    self.bind(name, binding, element.source);
+                    break;
+                }
+                ExpressionKind::Placeholder => {}
+                ExpressionKind::IdentifierListPattern(next_pattern) => {
+                    let next_subject = self.temporary();
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    self.emit(Instruction::ListElementOrEmpty(position), element.source);
+                    self.emit(Instruction::SetLocal(next_subject), element.source);
+                    self.emit(Instruction::Pop, element.source);
+                    self.destructure_list(next_pattern, next_subject, binding, element.source);
+                }
+                _ => {
+                    self.emit_error(
+                        format!("Unexpected List destructing pattern, found: {}", element.kind),
+                        element.source,
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    fn destructure_dictionary(&mut self, pattern: &[Expression], subject: u32, binding: Binding, source: Location) {
+        let argument = matches!(binding, Binding::Parameter);
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit(Instruction::ExpectDictionary { argument }, source);
+
+        let mut keys = vec![];
+        for element in pattern {
+            match &element.kind {
+                ExpressionKind::Identifier(name) => {
+                    let key = self.string_constant(name);
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    self.emit(Instruction::Constant(key), element.source);
+                    self.emit(Instruction::DictionaryGet, element.source);
+                    self.bind(name, binding, element.source);
+                    keys.push(Key::Constant(key));
+                }
+                ExpressionKind::DictionaryEntryPattern { key, value } => {
+                    let key = match (&key.kind, binding) {
+                        (ExpressionKind::String(name), Binding::Parameter) => Key::Constant(self.string_constant(name)),
+                        (_, Binding::Parameter) => {
+                            self.emit_error(
+                                "Dictionary pattern key must be a string literal in function parameters".to_owned(),
+                                key.source,
+                            );
+                            break;
+                        }
+                        _ => {
+                            let slot = self.temporary();
+                            self.compile_expression(key, false);
+                            self.emit(Instruction::SetLocal(slot), key.source);
+                            self.emit(Instruction::Pop, key.source);
+                            Key::Slot(slot)
+                        }
+                    };
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    self.emit_key(&key, element.source);
+                    self.emit(Instruction::DictionaryGet, element.source);
+                    keys.push(key);
+
+                    match &value.kind {
+                        ExpressionKind::Identifier(name) => self.bind(name, binding, value.source),
+                        ExpressionKind::Placeholder => {
+                            self.emit(Instruction::Pop, value.source);
+                        }
+                        ExpressionKind::IdentifierListPattern(next_pattern) => {
+                            let next_subject = self.temporary();
+                            self.emit(Instruction::SetLocal(next_subject), value.source);
+                            self.emit(Instruction::Pop, value.source);
+                            self.destructure_list(next_pattern, next_subject, binding, value.source);
+                        }
+                        ExpressionKind::IdentifierDictionaryPattern(next_pattern) => {
+                            let next_subject = self.temporary();
+                            self.emit(Instruction::SetLocal(next_subject), value.source);
+                            self.emit(Instruction::Pop, value.source);
+                            self.destructure_dictionary(next_pattern, next_subject, binding, value.source);
+                        }
+                        _ => {
+                            let message = if argument {
+                                format!("Invalid parameter binding: {}", value.kind)
+                            } else {
+                                format!("Invalid dictionary pattern binding: {}", value.kind)
+                            };
+                            self.emit_error(message, value.source);
+                            break;
+                        }
+                    }
+                }
+                ExpressionKind::RestIdentifier(name) => {
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    for key in &keys {
+                        self.emit_key(key, element.source);
+                    }
+                    self.emit(Instruction::DictionaryRest(keys.len() as u32), element.source);
+                    self.bind(name, binding, element.source);
+                    break;
+                }
+                ExpressionKind::Placeholder => {}
+                _ => {
+                    let message = if argument {
+                        format!("Unexpected Dictionary pattern parameter: {}", element.kind)
+                    } else {
+                        format!("Unexpected Dictionary destructuring pattern: {}", element.kind)
+                    };
+                    self.emit_error(message, element.source);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn emit_key(&mut self, key: &Key, source: Location) {
+        match key {
+            Key::Constant(constant) => self.emit(Instruction::Constant(*constant), source),
+            Key::Slot(slot) => self.emit(Instruction::GetLocal(*slot), source),
+        };
+    }
+
+    fn compile_match(&mut self, subject: &Expression, cases: &[MatchCase], tail: bool) {
+        let subject_slot = self.temporary();
+        self.compile_expression(subject, false);
+        self.emit(Instruction::SetLocal(subject_slot), subject.source);
+        self.emit(Instruction::Pop, subject.source);
+
+        let mut ends = vec![];
+        for case in cases {
+            let pattern = &case.pattern;
+            let mut failures = vec![];
+            self.function().scopes.push(vec![]);
+
+            self.match_case_pattern(pattern, subject_slot, &mut failures);
+
+            if let Some(guard) = &case.guard {
+                self.compile_expression(guard, false);
+                failures.push(self.emit(Instruction::JumpIfFalse(0), guard.source));
+            }
+
+            self.compile_statement(&case.consequence, tail);
+            ends.push(self.emit(Instruction::Jump(0), case.consequence.source));
+            self.end_scope();
+
+            for failure in failures {
+                self.patch_jump(failure);
+            }
+        }
+
+        self.emit_constant(Object::Nil, subject.source);
+        for end in ends {
+            self.patch_jump(end);
+        }
+    }
+
+    fn match_case_pattern(&mut self, pattern: &Expression, subject: u32, failures: &mut Vec<usize>) {
+        match &pattern.kind {
+            ExpressionKind::Identifier(name) => {
+                self.emit(Instruction::GetLocal(subject), pattern.source);
+                self.bind(name, Binding::Match, pattern.source);
+            }
+            ExpressionKind::Placeholder => {}
+            ExpressionKind::ListMatchPattern(elements) => {
+                self.match_list(elements, subject, pattern.source, failures);
+            }
+            ExpressionKind::DictionaryMatchPattern(elements) => {
+                self.match_dictionary(elements, subject, pattern.source, failures);
+            }
+            ExpressionKind::VariantMatchPattern { name, fields } => {
+                self.match_variant(name, fields, subject, pattern.source, failures);
+            }
+            ExpressionKind::OrMatchPattern(alternatives) => {
+                self.match_alternatives(alternatives, failures, |compiler, alternative, failures| {
+                    compiler.match_case_pattern(alternative, subject, failures)
+                });
+            }
+            ExpressionKind::BindingMatchPattern { name, pattern } => {
+                self.emit(Instruction::GetLocal(subject), name.source);
+                self.match_element(name, true, failures);
+                self.match_case_pattern(pattern, subject, failures);
+            }
+            ExpressionKind::StringMatchPattern {
+                prefix,
+                remainder,
+                suffix,
+            } => {
+                self.match_string(prefix, remainder, suffix, subject, pattern.source, failures);
+            }
+            ExpressionKind::RegexMatchPattern {
+                pattern: regex,
+                captures,
+            } => {
+                self.match_regex(regex, captures, subject, pattern.source, failures);
+            }
+            ExpressionKind::InclusiveRange { .. }
+            | ExpressionKind::ExclusiveRange { .. }
+            | ExpressionKind::UnboundedRange { .. } => {
+                self.match_range(pattern, subject, true, failures);
+            }
+            _ => self.match_literal(pattern, subject, failures),
+        }
+    }
+
+    // Each alternative binds the same variables (as enforced by the parser), so those bound by an alternative
+    // which fails to match are declared afresh by the next.
+    fn match_alternatives(
+        &mut self,
+        alternatives: &[Expression],
+        failures: &mut Vec<usize>,
+        mut match_alternative: impl FnMut(&mut Self, &Expression, &mut Vec<usize>),
+    ) {
+        let declared = self.function().scopes.last().map_or(0, |scope| scope.len());
+        let (last, alternatives) = alternatives.split_last().unwrap();
+
+        let mut matches = vec![];
+        for alternative in alternatives {
+            let mut alternative_failures = vec![];
+            match_alternative(self, alternative, &mut alternative_failures);
+            matches.push(self.emit(Instruction::Jump(0), alternative.source));
+            for failure in alternative_failures {
+                self.patch_jump(failure);
+            }
+            if let Some(scope) = self.function().scopes.last_mut() {
+                for local in scope.iter_mut().skip(declared) {
+                    local.declared = false;
+                }
+            }
+        }
+
+        match_alternative(self, last, failures);
+        for matched in matches {
+            self.patch_jump(matched);
+        }
+    }
+
+    fn match_list(&mut self, pattern: &[Expression], subject: u32, source: Location, failures: &mut Vec<usize>) {
+        let rest_position = pattern
+            .iter()
+            .position(|element| matches!(element.kind, ExpressionKind::RestIdentifier(_)));
+        let (before, after) = match rest_position {
+            Some(position) => (position, pattern.len() - position - 1),
+            None => (pattern.len(), 0),
+        };
+
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit(
+            Instruction::MatchList {
+                length: (before + after) as u32,
+                rest: rest_position.is_some(),
+            },
+            source,
+        );
+        failures.push(self.emit(Instruction::JumpIfFalse(0), source));
+
+        for (position, element) in pattern.iter().take(before).enumerate() {
+            self.emit(Instruction::GetLocal(subject), element.source);
+            self.emit(Instruction::ListElement(position as u32), element.source);
+            self.match_element(element, true, failures);
+        }
+
+        if let Some(rest_position) = rest_position {
+            for (offset, element) in pattern.iter().skip(rest_position + 1).enumerate() {
+                self.emit(Instruction::GetLocal(subject), element.source);
+                self.emit(Instruction::ListElementFromEnd((after - offset) as u32), element.source);
+                self.match_element(element, true, failures);
+            }
+
+            let rest = &pattern[rest_position];
+            if let ExpressionKind::RestIdentifier(name) = &rest.kind {
+                self.emit(Instruction::GetLocal(subject), rest.source);
+                self.emit(
+                    Instruction::ListSlice {
+                        start: before as u32,
+                        end: after as u32,
+                    },
+                    rest.source,
+                );
+                self.bind(name, Binding::Match, rest.source);
+            }
+        }
+    }
+
+    fn match_dictionary(&mut self, pattern: &[Expression], subject: u32, source: Location, failures: &mut Vec<usize>) {
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit(Instruction::MatchDictionary, source);
+        failures.push(self.emit(Instruction::JumpIfFalse(0), source));
+
+        let mut keys = vec![];
+        for element in pattern {
+            match &element.kind {
+                ExpressionKind::Identifier(name) => {
+                    let key = Key::Constant(self.string_constant(name));
+                    self.match_key(&key, subject, element.source, failures);
+                    self.bind(name, Binding::Match, element.source);
+                    keys.push(key);
+                }
+                ExpressionKind::DictionaryEntryPattern { key, value } => {
+                    let slot = self.temporary();
+                    self.compile_expression(key, false);
+                    self.emit(Instruction::SetLocal(slot), key.source);
+                    self.emit(Instruction::Pop, key.source);
+                    let key = Key::Slot(slot);
+                    self.match_key(&key, subject, element.source, failures);
+                    self.match_element(value, false, failures);
+                    keys.push(key);
+                }
+                ExpressionKind::RestIdentifier(name) => {
+                    self.emit(Instruction::GetLocal(subject), element.source);
+                    for key in &keys {
+                        self.emit_key(key, element.source);
+                    }
+                    self.emit(Instruction::DictionaryRest(keys.len() as u32), element.source);
+                    self.bind(name, Binding::Match, element.source);
+                }
+                ExpressionKind::Placeholder => {}
+                _ => {
+                    self.emit_error(
+                        format!("Unexpected dictionary match pattern: {}", element.kind),
+                        element.source,
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    fn match_variant(
+        &mut self,
+        name: &str,
+        pattern: &Option<Vec<Expression>>,
+        subject: u32,
+        source: Location,
+        failures: &mut Vec<usize>,
+    ) {
+        // Locally declared constructors are pushed beneath the subject, otherwise they are looked up globally
+        let local = self.resolve(self.functions.len() - 1, name, false);
+        if let Some((variable, _)) = local {
+            self.emit_get(variable, source);
+        }
+        let name = self.function().chunk.add_name(name);
+        let length = pattern.as_ref().map(|fields| fields.len() as u32);
+        self.emit(Instruction::GetLocal(subject), source);
+        match local {
+            Some(_) => self.emit(Instruction::MatchLocalVariant { name, length }, source),
+            None => self.emit(Instruction::MatchVariant { name, length }, source),
+        };
+        failures.push(self.emit(Instruction::JumpIfFalse(0), source));
+
+        for (position, field) in pattern.iter().flatten().enumerate() {
+            self.emit(Instruction::GetLocal(subject), field.source);
+            self.emit(Instruction::VariantField(position as u32), field.source);
+            self.match_element(field, true, failures);
+        }
+    }
+
+    fn match_string(
+        &mut self,
+        prefix: &Option<String>,
+        remainder: &Expression,
+        suffix: &Option<String>,
+        subject: u32,
+        source: Location,
+        failures: &mut Vec<usize>,
+    ) {
+        let chunk = &mut self.function().chunk;
+        let prefix = prefix.as_deref().map(|prefix| chunk.add_name(prefix));
+        let suffix = suffix.as_deref().map(|suffix| chunk.add_name(suffix));
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit(Instruction::MatchString { prefix, suffix }, source);
+        let extracted = self.match_extracted(source, failures);
+        self.emit(Instruction::GetLocal(extracted), remainder.source);
+        self.match_element(remainder, false, failures);
+    }
+
+    fn match_regex(
+        &mut self,
+        pattern: &str,
+        captures: &[Expression],
+        subject: u32,
+        source: Location,
+        failures: &mut Vec<usize>,
+    ) {
+        let regex = match compile_regex_pattern(pattern) {
+            Ok(regex) => regex,
+            Err(_) => {
+                self.emit_error(format!("Failed to compile regex pattern: {}", pattern), source);
+                return;
+            }
+        };
+
+        let chunk = &mut self.function().chunk;
+        chunk.regexes.push(regex);
+        let regex = (chunk.regexes.len() - 1) as u32;
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit(Instruction::MatchRegex(regex), source);
+        let extracted = self.match_extracted(source, failures);
+        for (position, capture) in captures.iter().enumerate() {
+            self.emit(Instruction::GetLocal(extracted), capture.source);
+            self.emit(Instruction::ListElement(position as u32), capture.source);
+            self.match_element(capture, false, failures);
+        }
+    }
+
+    // String and regex patterns leave what they extract from the subject on the stack (or nil should it not
+    // match), which is kept aside within a slot for the nested patterns to be matched against.
+    fn match_extracted(&mut self, source: Location, failures: &mut Vec<usize>) -> u32 {
+        let extracted = self.temporary();
+        self.emit(Instruction::SetLocal(extracted), source);
+        self.emit_constant(Object::Nil, source);
+        self.emit(Instruction::NotEqual, source);
+        failures.push(self.emit(Instruction::JumpIfFalse(0), source));
+        extracted
+    }
+
+    // Leaves the value held under the key on the stack, once it has been established that the key exists.
+    fn match_key(&mut self, key: &Key, subject: u32, source: Location, failures: &mut Vec<usize>) {
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit_key(key, source);
+        self.emit(Instruction::MatchKey, source);
+        failures.push(self.emit(Instruction::JumpIfFalse(0), source));
+        self.emit(Instruction::GetLocal(subject), source);
+        self.emit_key(key, source);
+        self.emit(Instruction::DictionaryGet, source);
+    }
+
+    // Matches the value on top of the stack against a nested pattern, consuming it.
+    fn match_element(&mut self, pattern: &Expression, ranges: bool, failures: &mut Vec<usize>) {
+        match &pattern.kind {
+            ExpressionKind::Placeholder => {
+                self.emit(Instruction::Pop, pattern.source);
+            }
+            ExpressionKind::Identifier(name) => self.bind(name, Binding::Match, pattern.source),
+            ExpressionKind::ListMatchPattern(elements) => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_list(elements, slot, pattern.source, failures);
+            }
+            ExpressionKind::DictionaryMatchPattern(elements) => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_dictionary(elements, slot, pattern.source, failures);
+            }
+            ExpressionKind::VariantMatchPattern { name, fields } => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_variant(name, fields, slot, pattern.source, failures);
+            }
+            ExpressionKind::StringMatchPattern {
+                prefix,
+                remainder,
+                suffix,
+            } => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_string(prefix, remainder, suffix, slot, pattern.source, failures);
+            }
+            ExpressionKind::RegexMatchPattern {
+                pattern: regex,
+                captures,
+            } => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_regex(regex, captures, slot, pattern.source, failures);
+            }
+            ExpressionKind::OrMatchPattern(alternatives) => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_alternatives(alternatives, failures, |compiler, alternative, failures| {
+                    compiler.emit(Instruction::GetLocal(slot), alternative.source);
+                    compiler.match_element(alternative, ranges, failures);
+                });
+            }
+            ExpressionKind::BindingMatchPattern {
+                name,
+                pattern: next_pattern,
+            } => {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.emit(Instruction::GetLocal(slot), name.source);
+                self.match_element(name, ranges, failures);
+                self.emit(Instruction::GetLocal(slot), next_pattern.source);
+                self.match_element(next_pattern, ranges, failures);
+            }
+            ExpressionKind::InclusiveRange { .. }
+            | ExpressionKind::ExclusiveRange { .. }
+            | ExpressionKind::UnboundedRange { .. }
+                if ranges =>
+            {
+                let slot = self.temporary();
+                self.emit(Instruction::SetLocal(slot), pattern.source);
+                self.emit(Instruction::Pop, pattern.source);
+                self.match_range(pattern, slot, false, failures);
+            }
+            _ => {
+                self.compile_expression(pattern, false);
+                self.emit(Instruction::Equal, pattern.source);
+                failures.push(self.emit(Instruction::JumpIfFalse(0), pattern.source));
+            }
+        }
+    }
+
+    fn match_literal(&mut self, pattern: &Expression, subject: u32, failures: &mut Vec<usize>) {
+        self.emit(Instruction::GetLocal(subject), pattern.source);
+        self.compile_expression(pattern, false);
+        self.emit(Instruction::Equal, pattern.source);
+        failures.push(self.emit(Instruction::JumpIfFalse(0), pattern.source));
+    }
+
+    fn match_range(&mut self, pattern: &Expression, subject: u32, strict: bool, failures: &mut Vec<usize>) {
+        let integer = |expression: &Expression| match &expression.kind {
+            ExpressionKind::Integer(value) => Some(Object::from_integer_literal(value)),
+            _ => None,
+        };
+        let range = match &pattern.kind {
+            ExpressionKind::InclusiveRange { from, to } => match (integer(from), integer(to)) {
+                (Some(from), Some(to)) => RangePattern::Inclusive(from, to),
+                _ => RangePattern::Any,
+            },
+            ExpressionKind::ExclusiveRange { from, until } => match (integer(from), integer(until)) {
+                (Some(from), Some(until)) => RangePattern::Exclusive(from, until),
+                _ => RangePattern::Any,
+            },
+            ExpressionKind::UnboundedRange { from } => match integer(from) {
+                Some(from) => RangePattern::Unbounded(from),
+                None => RangePattern::Any,
+            },
+            _ => unreachable!(),
+        };
+
+        let chunk = &mut self.function().chunk;
+        chunk.ranges.push(range);
+        let range = (chunk.ranges.len() - 1) as u32;
+        self.emit(Instruction::GetLocal(subject), pattern.source);
+        self.emit(Instruction::MatchRange { range, strict }, pattern.source);
+        failures.push(self.emit(Instruction::JumpIfFalse(0), pattern.source));
+    }
+}
+
+fn operator_instruction(operator: &Infix) -> Instruction {
+    match operator {
+        Infix::Plus => Instruction::Add,
+        Infix::Minus => Instruction::Subtract,
+        Infix::Asterisk => Instruction::Multiply,
+        Infix::Slash => Instruction::Divide,
+        Infix::Modulo => Instruction::Modulo,
+        Infix::Equal => Instruction::Equal,
+        Infix::NotEqual => Instruction::NotEqual,
+        Infix::LessThan => Instruction::LessThan,
+        Infix::LessThanEqual => Instruction::LessThanEqual,
+        Infix::GreaterThan => Instruction::GreaterThan,
+        Infix::GreaterThanEqual => Instruction::GreaterThanEqual,
+        Infix::Or | Infix::And | Infix::Call(_) => unreachable!(),
+    }
+}
+
+fn collect_pattern_names(pattern: &Expression, names: &mut Vec<String>) {
+    match &pattern.kind {
+        ExpressionKind::Identifier(name) | ExpressionKind::RestIdentifier(name) => names.push(name.to_owned()),
+        ExpressionKind::IdentifierListPattern(elements) | ExpressionKind::IdentifierDictionaryPattern(elements) => {
+            for element in elements {
+                collect_pattern_names(element, names);
+            }
+        }
+        ExpressionKind::DictionaryEntryPattern { value, .. } => collect_pattern_names(value, names),
+        _ => {}
+    }
+}
+
+// Variables referenced from within nested closures must outlive the frame which declares them, so any
+// identifier used inside a nested closure is stored within a shared cell as opposed to a stack slot.
+fn collect_statement(statement: &Statement, nested: bool, names: &mut HashSet<String>) {
+    match &statement.kind {
+        StatementKind::Return(expression)
+        | StatementKind::Break(expression)
+        | StatementKind::Expression(expression)
+        | StatementKind::Attributed { expression, .. } => collect_expression(expression, nested, names),
+        StatementKind::Block(statements) => {
+            for statement in statements {
+                collect_statement(statement, nested, names);
+            }
+        }
+        StatementKind::Comment(_)
+        | StatementKind::Section { .. }
+        | StatementKind::Import { .. }
+        | StatementKind::Type { .. } => {}
+    }
+}
+
+fn collect_expression(expression: &Expression, nested: bool, names: &mut HashSet<String>) {
+    let mut collect = |expression: &Expression| collect_expression(expression, nested, names);
+
+    match &expression.kind {
+        ExpressionKind::Identifier(name)
+        | ExpressionKind::RestIdentifier(name)
+        | ExpressionKind::ResolvedIdentifier { name, .. }
+        | ExpressionKind::ResolvedRestIdentifier { name, .. } => {
+            if nested {
+                names.insert(name.to_owned());
+            }
+        }
+        ExpressionKind::Let { name, value }
+        | ExpressionKind::MutableLet { name, value }
+        | ExpressionKind::Assign { name, value }
+        | ExpressionKind::CompoundAssign { name, value, .. } => {
+            collect(name);
+            collect(value);
+        }
+        ExpressionKind::List(elements)
+        | ExpressionKind::Set(elements)
+        | ExpressionKind::FunctionComposition(elements)
+        | ExpressionKind::IdentifierListPattern(elements)
+        | ExpressionKind::ListMatchPattern(elements)
+        | ExpressionKind::IdentifierDictionaryPattern(elements)
+        | ExpressionKind::DictionaryMatchPattern(elements) => {
+            for element in elements {
+                collect(element);
+            }
+        }
+        ExpressionKind::VariantMatchPattern { name, fields } => {
+            // The pattern refers to its constructor to compare the variant's type
+            if nested {
+                names.insert(name.to_owned());
+            }
+            for field in fields.iter().flatten() {
+                collect_expression(field, nested, names);
+            }
+        }
+        ExpressionKind::OrMatchPattern(alternatives) => {
+            for alternative in alternatives {
+                collect(alternative);
+            }
+        }
+        ExpressionKind::StringMatchPattern { remainder, .. } => collect(remainder),
+        ExpressionKind::RegexMatchPattern { captures, .. } => {
+            for capture in captures {
+                collect(capture);
+            }
+        }
+        ExpressionKind::BindingMatchPattern { name, pattern } => {
+            collect(name);
+            collect(pattern);
+        }
+        ExpressionKind::Dictionary(entries) => {
+            for (key, value) in entries {
+                collect(key);
+                collect(value);
+            }
+        }
+        ExpressionKind::InclusiveRange { from, to: until } | ExpressionKind::ExclusiveRange { from, until } => {
+            collect(from);
+            collect(until);
+        }
+        ExpressionKind::UnboundedRange { from: value }
+        | ExpressionKind::Spread(value)
+        | ExpressionKind::Prefix { right: value, .. } => collect(value),
+        ExpressionKind::Index { left, index }
+        | ExpressionKind::DictionaryEntryPattern {
+            key: left,
+            value: index,
+        } => {
+            collect(left);
+            collect(index);
+        }
+        ExpressionKind::Call { function, arguments } => {
+            collect(function);
+            for argument in arguments {
+                collect(argument);
+            }
+        }
+        ExpressionKind::FunctionThread { initial, functions } => {
+            collect(initial);
+            for function in functions {
+                collect(function);
+            }
+        }
+        ExpressionKind::InterpolatedString(parts) => {
+            for part in parts {
+                if let StringPart::Expression(expression) = part {
+                    collect(expression);
+                }
+            }
+        }
+        ExpressionKind::Function { parameters, body } => {
+            for parameter in parameters {
+                collect_expression(parameter, true, names);
+            }
+            collect_statement(body, true, names);
+        }
+        ExpressionKind::If {
+            condition,
+            consequence,
+            alternative,
+        } => {
+            collect(condition);
+            collect_statement(consequence, nested, names);
+            if let Some(alternative) = alternative {
+                collect_statement(alternative, nested, names);
+            }
+        }
+        ExpressionKind::Match { subject, cases } => {
+            collect(subject);
+            for case in cases {
+                collect_expression(&case.pattern, nested, names);
+                if let Some(guard) = &case.guard {
+                    collect_expression(guard, nested, names);
+                }
+                collect_statement(&case.consequence, nested, names);
+            }
+        }
+        ExpressionKind::Infix { left, operator, right } => {
+            let nested = nested
+                || matches!(left.kind, ExpressionKind::Placeholder)
+                || matches!(right.kind, ExpressionKind::Placeholder);
+            collect_expression(left, nested, names);
+            collect_expression(right, nested, names);
+            if let Infix::Call(function) = operator {
+                collect_expression(function, nested, names);
+            }
+        }
+        ExpressionKind::Integer(_)
+        | ExpressionKind::Decimal(_)
+        | ExpressionKind::String(_)
+        | ExpressionKind::Boolean(_)
+        | ExpressionKind::Placeholder
+        | ExpressionKind::Nil => {}
+    }
+}
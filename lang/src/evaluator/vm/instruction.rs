@@ -0,0 +1,153 @@
+use crate::evaluator::Object;
+use crate::evaluator::vm::Prototype;
+use crate::lexer::Location;
+use crate::parser::ast::{Attribute, Section};
+use regex::Regex;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Constant(u32),
+    Pop,
+    Swap,
+
+    GetLocal(u32),
+    SetLocal(u32),
+    GetCell(u32),
+    SetCell(u32),
+    GetUpvalue(u32),
+    SetUpvalue(u32),
+    GetGlobal(u32),
+    DeclareGlobal { name: u32, mutable: bool },
+    AssignGlobal(u32),
+
+    Jump(u32),
+    JumpIfFalse(u32),
+
+    Closure(u32),
+    Call(u32),
+    TailCall(u32),
+    CallSpread,
+    Return,
+    ReturnSignal,
+    Break,
+
+    List(u32),
+    Append,
+    Extend,
+    Set(u32),
+    ListToSet,
+    CheckKey,
+    Dictionary(u32),
+    Index,
+    SetIndex,
+
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanEqual,
+    GreaterThan,
+    GreaterThanEqual,
+    Not,
+    Negate,
+
+    InclusiveRange,
+    ExclusiveRange,
+    UnboundedRange,
+    Interpolate(u32),
+    CheckFunction,
+    Compose(u32),
+
+    ExpectList { argument: bool },
+    ExpectDictionary { argument: bool },
+    ListElement(u32),
+    ListElementOrEmpty(u32),
+    ListElementFromEnd(u32),
+    ListRest(u32),
+    ListSlice { start: u32, end: u32 },
+    DictionaryGet,
+    DictionaryRest(u32),
+    MatchList { length: u32, rest: bool },
+    MatchDictionary,
+    MatchKey,
+    MatchRange { range: u32, strict: bool },
+    MatchVariant { name: u32, length: Option<u32> },
+    MatchLocalVariant { name: u32, length: Option<u32> },
+    VariantField(u32),
+    MatchString { prefix: Option<u32>, suffix: Option<u32> },
+    MatchRegex(u32),
+
+    Section(u32),
+    BindingAttributes(u32),
+    Import(u32),
+    Error(u32),
+}
+
+// Range bounds are integer literals, which may exceed the i64 range.
+#[derive(Debug, Clone)]
+pub enum RangePattern {
+    Inclusive(Object, Object),
+    Exclusive(Object, Object),
+    Unbounded(Object),
+    Any,
+}
+
+impl RangePattern {
+    pub fn contains(&self, value: &Object) -> bool {
+        match self {
+            Self::Inclusive(from, to) => (from..=to).contains(&value),
+            Self::Exclusive(from, until) => (from..until).contains(&value),
+            Self::Unbounded(from) => (from..).contains(&value),
+            Self::Any => true,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Chunk {
+    pub code: Vec<Instruction>,
+    pub locations: Vec<Location>,
+    pub constants: Vec<Rc<Object>>,
+    pub names: Vec<String>,
+    pub prototypes: Vec<Rc<Prototype>>,
+    pub ranges: Vec<RangePattern>,
+    pub regexes: Vec<Regex>,
+    pub sections: Vec<(String, Rc<Section>, Vec<Attribute>)>,
+    pub binding_attributes: Vec<(String, Vec<Attribute>)>,
+    pub imports: Vec<(String, Option<Vec<String>>)>,
+}
+
+impl Chunk {
+    pub fn emit(&mut self, instruction: Instruction, source: Location) -> usize {
+        self.code.push(instruction);
+        self.locations.push(source);
+        self.code.len() - 1
+    }
+
+    pub fn patch_jump(&mut self, position: usize) {
+        let target = self.code.len() as u32;
+        self.code[position] = match self.code[position] {
+            Instruction::Jump(_) => Instruction::Jump(target),
+            Instruction::JumpIfFalse(_) => Instruction::JumpIfFalse(target),
+            instruction => unreachable!("{:?} is not a jump", instruction),
+        };
+    }
+
+    pub fn add_constant(&mut self, constant: Rc<Object>) -> u32 {
+        self.constants.push(constant);
+        (self.constants.len() - 1) as u32
+    }
+
+    pub fn add_name(&mut self, name: &str) -> u32 {
+        if let Some(position) = self.names.iter().position(|existing| existing == name) {
+            return position as u32;
+        }
+        self.names.push(name.to_owned());
+        (self.names.len() - 1) as u32
+    }
+}
@@ -0,0 +1,883 @@
+mod compiler;
+mod instruction;
+
+use crate::evaluator::function::{Function, MemoizedCache, with_growable_stack};
+use crate::evaluator::lazy_sequence::LazySequence;
+use crate::evaluator::matcher;
+use crate::evaluator::vm::instruction::{Chunk, Instruction};
+use crate::evaluator::{EnvironmentErr, EnvironmentRef, Evaluation, Evaluator, Frame, Object, RuntimeErr};
+use crate::lexer::Location;
+use crate::parser::ast::{Expression, ExpressionKind, Program};
+use im_rc::{HashMap, HashSet, Vector};
+use num_bigint::BigInt;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Cell = Rc<RefCell<Rc<Object>>>;
+
+#[derive(Debug)]
+pub struct Prototype {
+    pub parameters: Vec<Expression>,
+    pub chunk: Chunk,
+    pub locals: usize,
+    pub cells: usize,
+    pub captures: Vec<Capture>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Capture {
+    Cell(u32),
+    Upvalue(u32),
+}
+
+// Compiled closures share the `Object` and `Function` representations with the tree-walking evaluator, so
+// builtins, lazy sequences and function composition can invoke them without knowing how they are executed.
+#[derive(Debug)]
+pub struct Closure {
+    prototype: Rc<Prototype>,
+    upvalues: Vec<Cell>,
+    globals: EnvironmentRef,
+    arguments: Vec<Option<Rc<Object>>>,
+    cache: Option<MemoizedCache>,
+}
+
+impl Closure {
+    pub fn parameters(&self) -> Vec<&Expression> {
+        self.prototype
+            .parameters
+            .iter()
+            .enumerate()
+            .filter(|(position, _)| !matches!(self.arguments.get(*position), Some(Some(_))))
+            .map(|(_, parameter)| parameter)
+            .collect()
+    }
+
+    pub fn is_memoized(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    pub fn memoized(&self) -> Self {
+        Self {
+            prototype: Rc::clone(&self.prototype),
+            upvalues: self.upvalues.clone(),
+            globals: Rc::clone(&self.globals),
+            arguments: self.arguments.clone(),
+            cache: Some(Rc::new(RefCell::new(std::collections::HashMap::default()))),
+        }
+    }
+
+    // Mirrors the partial application rules of tree-walking closures: placeholders leave their parameter
+    // unbound, a rest parameter consumes all remaining arguments, and surplus arguments are ignored.
+    fn bind(&self, arguments: Vec<Rc<Object>>) -> Result<Vec<Rc<Object>>, Vec<Option<Rc<Object>>>> {
+        let parameters = &self.prototype.parameters;
+
+        if self.arguments.is_empty()
+            && arguments.len() >= parameters.len()
+            && !arguments
+                .iter()
+                .take(parameters.len())
+                .any(|argument| **argument == Object::Placeholder)
+            && !parameters
+                .iter()
+                .any(|parameter| matches!(parameter.kind, ExpressionKind::RestIdentifier(_)))
+        {
+            let mut arguments = arguments;
+            arguments.truncate(parameters.len());
+            return Ok(arguments);
+        }
+
+        let mut bound = if self.arguments.is_empty() {
+            vec![None; parameters.len()]
+        } else {
+            self.arguments.clone()
+        };
+        let unbound: Vec<usize> = (0..bound.len()).filter(|position| bound[*position].is_none()).collect();
+
+        for (position, (parameter, argument)) in unbound.into_iter().zip(arguments.iter()).enumerate() {
+            if **argument == Object::Placeholder {
+                continue;
+            }
+            if let ExpressionKind::RestIdentifier(_) = parameters[parameter].kind {
+                bound[parameter] = Some(Rc::new(Object::List(
+                    arguments.iter().skip(position).cloned().collect(),
+                )));
+                break;
+            }
+            bound[parameter] = Some(Rc::clone(argument));
+        }
+
+        if bound.iter().any(Option::is_none) {
+            return Err(bound);
+        }
+
+        Ok(bound.into_iter().flatten().collect())
+    }
+}
+
+struct CallFrame {
+    closure: Rc<Closure>,
+    ip: usize,
+    base: usize,
+    cells: Vec<Cell>,
+    memoize: Option<Vec<Rc<Object>>>,
+    program: bool,
+}
+
+struct Vm {
+    stack: Vec<Rc<Object>>,
+    frames: Vec<CallFrame>,
+    nil: Rc<Object>,
+}
+
+pub fn execute(evaluator: &mut Evaluator, program: &Program) -> Evaluation {
+    let prototype = compiler::compile_program(program);
+    let closure = Rc::new(Closure {
+        prototype,
+        upvalues: vec![],
+        globals: evaluator.environment(),
+        arguments: vec![],
+        cache: None,
+    });
+
+    let mut vm = Vm::new();
+    vm.enter(closure, vec![], None, true);
+    vm.run(evaluator)
+}
+
+// Builtins re-enter the VM to call compiled closures, each within a new `Vm` on the native stack.
+pub fn call(
+    evaluator: &mut Evaluator,
+    closure: &Rc<Closure>,
+    arguments: Vec<Rc<Object>>,
+    source: Location,
+) -> Evaluation {
+    with_growable_stack(|| {
+        let mut vm = Vm::new();
+        vm.call_closure(evaluator, closure, arguments, source, false)?;
+
+        if vm.frames.is_empty() {
+            return Ok(vm.pop());
+        }
+
+        vm.run(evaluator)
+    })
+}
+
+impl Vm {
+    fn new() -> Self {
+        Self {
+            stack: Vec::with_capacity(256),
+            frames: vec![],
+            nil: Rc::new(Object::Nil),
+        }
+    }
+
+    fn pop(&mut self) -> Rc<Object> {
+        self.stack.pop().unwrap()
+    }
+
+    fn peek(&self) -> &Rc<Object> {
+        self.stack.last().unwrap()
+    }
+
+    fn enter(
+        &mut self,
+        closure: Rc<Closure>,
+        arguments: Vec<Rc<Object>>,
+        memoize: Option<Vec<Rc<Object>>>,
+        program: bool,
+    ) {
+        let base = self.stack.len();
+        let prototype = &closure.prototype;
+
+        self.stack.extend(arguments);
+        self.stack.resize(base + prototype.locals, Rc::clone(&self.nil));
+        let cells = (0..prototype.cells)
+            .map(|_| Rc::new(RefCell::new(Rc::clone(&self.nil))))
+            .collect();
+
+        self.frames.push(CallFrame {
+            closure,
+            ip: 0,
+            base,
+            cells,
+            memoize,
+            program,
+        });
+    }
+
+    fn call_value(
+        &mut self,
+        evaluator: &mut Evaluator,
+        function: Rc<Object>,
+        arguments: Vec<Rc<Object>>,
+        source: Location,
+        tail: bool,
+    ) -> Result<(), RuntimeErr> {
+        match &*function {
+            Object::Function(Function::Compiled(closure)) => {
+                self.call_closure(evaluator, closure, arguments, source, tail)
+            }
+            Object::Function(function) => {
+                let result = function.apply(evaluator, arguments, source)?;
+                self.stack.push(result);
+                Ok(())
+            }
+            _ => Err(RuntimeErr {
+                message: format!("Expected a Function, found: {}", function.name()),
+                source,
+                trace: evaluator.get_trace(),
+            }),
+        }
+    }
+
+    fn call_closure(
+        &mut self,
+        evaluator: &mut Evaluator,
+        closure: &Rc<Closure>,
+        arguments: Vec<Rc<Object>>,
+        source: Location,
+        tail: bool,
+    ) -> Result<(), RuntimeErr> {
+        let memoize = match &closure.cache {
+            Some(cache) => {
+                if let Some(result) = cache.borrow().get(&arguments) {
+                    self.stack.push(Rc::clone(result));
+                    return Ok(());
+                }
+                Some(arguments.clone())
+            }
+            None => None,
+        };
+
+        let arguments = match closure.bind(arguments) {
+            Ok(arguments) => arguments,
+            Err(bound) => {
+                self.stack
+                    .push(Rc::new(Object::Function(Function::Compiled(Rc::new(Closure {
+                        prototype: Rc::clone(&closure.prototype),
+                        upvalues: closure.upvalues.clone(),
+                        globals: Rc::clone(&closure.globals),
+                        arguments: bound,
+                        cache: None,
+                    })))));
+                return Ok(());
+            }
+        };
+
+        // A call in tail position replaces the calling frame, unless its result has yet to be memoized.
+        if tail && memoize.is_none() {
+            if let Some(frame) = self.frames.last() {
+                if !frame.program && frame.memoize.is_none() {
+                    let frame = self.frames.pop().unwrap();
+                    self.stack.truncate(frame.base);
+                    evaluator.pop_frame();
+                }
+            }
+        }
+
+        evaluator.push_frame(Frame::ClosureCall {
+            source,
+            environment: Rc::clone(&closure.globals),
+        })?;
+        self.enter(Rc::clone(closure), arguments, memoize, false);
+
+        Ok(())
+    }
+
+    fn return_value(&mut self, evaluator: &mut Evaluator, value: Rc<Object>) -> Option<Rc<Object>> {
+        let frame = self.frames.pop().unwrap();
+        self.stack.truncate(frame.base);
+
+        if let (Some(arguments), Some(cache)) = (frame.memoize, &frame.closure.cache) {
+            cache.borrow_mut().insert(arguments, Rc::clone(&value));
+        }
+        if !frame.program {
+            evaluator.pop_frame();
+        }
+
+        if self.frames.is_empty() {
+            return Some(value);
+        }
+
+        self.stack.push(value);
+        None
+    }
+
+    fn error(&self, evaluator: &Evaluator, message: String) -> RuntimeErr {
+        RuntimeErr {
+            message,
+            source: self.location(),
+            trace: evaluator.get_trace(),
+        }
+    }
+
+    fn location(&self) -> Location {
+        let frame = self.frames.last().unwrap();
+        frame.closure.prototype.chunk.locations[frame.ip - 1]
+    }
+
+    fn run(&mut self, evaluator: &mut Evaluator) -> Evaluation {
+        loop {
+            let frame = self.frames.last_mut().unwrap();
+            let instruction = frame.closure.prototype.chunk.code[frame.ip];
+            frame.ip += 1;
+            let frame = self.frames.last().unwrap();
+
+            match instruction {
+                Instruction::Constant(index) => {
+                    let constant = Rc::clone(&frame.closure.prototype.chunk.constants[index as usize]);
+                    self.stack.push(constant);
+                }
+                Instruction::Pop => {
+                    self.stack.pop();
+                }
+                Instruction::Swap => {
+                    let length = self.stack.len();
+                    self.stack.swap(length - 1, length - 2);
+                }
+                Instruction::GetLocal(slot) => {
+                    let value = Rc::clone(&self.stack[frame.base + slot as usize]);
+                    self.stack.push(value);
+                }
+                Instruction::SetLocal(slot) => {
+                    let index = frame.base + slot as usize;
+                    self.stack[index] = Rc::clone(self.stack.last().unwrap());
+                }
+                Instruction::GetCell(index) => {
+                    let value = Rc::clone(&frame.cells[index as usize].borrow());
+                    self.stack.push(value);
+                }
+                Instruction::SetCell(index) => {
+                    *frame.cells[index as usize].borrow_mut() = Rc::clone(self.stack.last().unwrap());
+                }
+                Instruction::GetUpvalue(index) => {
+                    let value = Rc::clone(&frame.closure.upvalues[index as usize].borrow());
+                    self.stack.push(value);
+                }
+                Instruction::SetUpvalue(index) => {
+                    *frame.closure.upvalues[index as usize].borrow_mut() = Rc::clone(self.stack.last().unwrap());
+                }
+                Instruction::GetGlobal(name) => {
+                    let name = &frame.closure.prototype.chunk.names[name as usize];
+                    let value = self.lookup_global(evaluator, name)?;
+                    self.stack.push(value);
+                }
+                Instruction::DeclareGlobal { name, mutable } => {
+                    let name = &frame.closure.prototype.chunk.names[name as usize];
+                    let value = Rc::clone(self.stack.last().unwrap());
+                    let declared = frame
+                        .closure
+                        .globals
+                        .borrow_mut()
+                        .declare_variable(name, value, mutable);
+                    if let Err(EnvironmentErr { message }) = declared {
+                        return Err(self.error(evaluator, message));
+                    }
+                }
+                Instruction::AssignGlobal(name) => {
+                    let name = &frame.closure.prototype.chunk.names[name as usize];
+                    let value = Rc::clone(self.stack.last().unwrap());
+                    let assigned = frame.closure.globals.borrow_mut().assign_variable(name, value);
+                    if let Err(EnvironmentErr { message }) = assigned {
+                        return Err(self.error(evaluator, message));
+                    }
+                }
+                Instruction::Jump(target) => {
+                    self.frames.last_mut().unwrap().ip = target as usize;
+                }
+                Instruction::JumpIfFalse(target) => {
+                    if !self.stack.pop().unwrap().is_truthy() {
+                        self.frames.last_mut().unwrap().ip = target as usize;
+                    }
+                }
+                Instruction::Closure(index) => {
+                    let prototype = Rc::clone(&frame.closure.prototype.chunk.prototypes[index as usize]);
+                    let upvalues = prototype
+                        .captures
+                        .iter()
+                        .map(|capture| match capture {
+                            Capture::Cell(index) => Rc::clone(&frame.cells[*index as usize]),
+                            Capture::Upvalue(index) => Rc::clone(&frame.closure.upvalues[*index as usize]),
+                        })
+                        .collect();
+                    let closure = Closure {
+                        prototype,
+                        upvalues,
+                        globals: Rc::clone(&frame.closure.globals),
+                        arguments: vec![],
+                        cache: None,
+                    };
+                    self.stack
+                        .push(Rc::new(Object::Function(Function::Compiled(Rc::new(closure)))));
+                }
+                Instruction::Call(count) | Instruction::TailCall(count) => {
+                    let arguments = self.stack.split_off(self.stack.len() - count as usize);
+                    let function = self.pop();
+                    let source = self.location();
+                    let tail = matches!(instruction, Instruction::TailCall(_));
+                    self.call_value(evaluator, function, arguments, source, tail)?;
+                }
+                Instruction::CallSpread => {
+                    let arguments = match &*self.pop() {
+                        Object::List(arguments) => arguments.iter().cloned().collect(),
+                        _ => unreachable!(),
+                    };
+                    let function = self.pop();
+                    let source = self.location();
+                    self.call_value(evaluator, function, arguments, source, false)?;
+                }
+                Instruction::Return => {
+                    let value = self.pop();
+                    if let Some(result) = self.return_value(evaluator, value) {
+                        return Ok(result);
+                    }
+                }
+                Instruction::ReturnSignal => {
+                    let value = Rc::new(Object::Return(self.pop()));
+                    if let Some(result) = self.return_value(evaluator, value) {
+                        return Ok(result);
+                    }
+                }
+                Instruction::Break => {
+                    let value = Rc::new(Object::Break(self.pop()));
+                    if let Some(result) = self.return_value(evaluator, value) {
+                        return Ok(result);
+                    }
+                }
+                Instruction::List(count) => {
+                    let elements = self.stack.split_off(self.stack.len() - count as usize);
+                    self.stack.push(Rc::new(Object::List(Vector::from(elements))));
+                }
+                Instruction::Append => {
+                    let element = self.pop();
+                    let list = self.pop();
+                    let mut list = match Rc::try_unwrap(list) {
+                        Ok(Object::List(list)) => list,
+                        Ok(_) => unreachable!(),
+                        Err(list) => match &*list {
+                            Object::List(list) => list.clone(),
+                            _ => unreachable!(),
+                        },
+                    };
+                    list.push_back(element);
+                    self.stack.push(Rc::new(Object::List(list)));
+                }
+                Instruction::Extend => {
+                    let spread = self.pop();
+                    let list = self.pop();
+                    let mut list = match &*list {
+                        Object::List(list) => list.clone(),
+                        _ => unreachable!(),
+                    };
+                    match &*spread {
+                        Object::List(elements) => list.append(elements.clone()),
+                        Object::LazySequence(sequence) => {
+                            let source = self.location();
+                            let shared_evaluator = Rc::new(RefCell::new(&mut *evaluator));
+                            list.append(sequence.resolve_iter(shared_evaluator, source).collect_until_halted()?);
+                        }
+                        _ => {
+                            return Err(self.error(
+                                evaluator,
+                                format!("Expected a List or LazySequence to spread, found: {}", spread.name()),
+                            ));
+                        }
+                    }
+                    self.stack.push(Rc::new(Object::List(list)));
+                }
+                Instruction::Set(count) => {
+                    let elements = self.stack.split_off(self.stack.len() - count as usize);
+                    let set = self.collect_set(evaluator, elements)?;
+                    self.stack.push(set);
+                }
+                Instruction::ListToSet => {
+                    let elements = match &*self.pop() {
+                        Object::List(list) => list.iter().cloned().collect(),
+                        _ => unreachable!(),
+                    };
+                    let set = self.collect_set(evaluator, elements)?;
+                    self.stack.push(set);
+                }
+                Instruction::CheckKey => {
+                    if !self.peek().is_hashable() {
+                        let message = format!("Unable to use a {} as a Dictionary key", self.peek().name());
+                        return Err(self.error(evaluator, message));
+                    }
+                }
+                Instruction::Dictionary(count) => {
+                    let entries = self.stack.split_off(self.stack.len() - 2 * count as usize);
+                    let mut dictionary = HashMap::default();
+                    for entry in entries.chunks(2) {
+                        dictionary.insert(Rc::clone(&entry[0]), Rc::clone(&entry[1]));
+                    }
+                    self.stack.push(Rc::new(Object::Dictionary(dictionary)));
+                }
+                Instruction::Index => {
+                    let index = self.pop();
+                    let left = self.pop();
+                    let source = self.location();
+                    let value = crate::evaluator::index::lookup(evaluator, left, index, source)?;
+                    self.stack.push(value);
+                }
+                Instruction::SetIndex => {
+                    let value = self.pop();
+                    let index = self.pop();
+                    let left = self.pop();
+                    let source = self.location();
+                    let updated = crate::evaluator::index::assign(evaluator, left, index, value, source)?;
+                    self.stack.push(updated);
+                }
+                Instruction::Add
+                | Instruction::Subtract
+                | Instruction::Multiply
+                | Instruction::Divide
+                | Instruction::Modulo
+                | Instruction::Equal
+                | Instruction::NotEqual
+                | Instruction::LessThan
+                | Instruction::LessThanEqual
+                | Instruction::GreaterThan
+                | Instruction::GreaterThanEqual => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    let result = self.apply_operator(evaluator, instruction, &left, &right)?;
+                    self.stack.push(result);
+                }
+                Instruction::Not => {
+                    let value = self.pop();
+                    self.stack.push(Rc::new(Object::Boolean(!value.is_truthy())));
+                }
+                Instruction::Negate => {
+                    let value = self.pop();
+                    let negated = match &*value {
+                        Object::Integer(v) => match v.checked_neg() {
+                            Some(v) => Object::Integer(v),
+                            None => Object::from(-BigInt::from(*v)),
+                        },
+                        Object::BigInteger(v) => Object::from(-v),
+                        Object::Decimal(v) => Object::Decimal(-v),
+                        object => {
+                            let message = format!("Unexpected prefix operation: -{}", object.name());
+                            return Err(self.error(evaluator, message));
+                        }
+                    };
+                    self.stack.push(Rc::new(negated));
+                }
+                Instruction::InclusiveRange => {
+                    let to = self.pop();
+                    let from = self.pop();
+                    match (&*from, &*to) {
+                        (Object::Integer(from), Object::Integer(to)) => self
+                            .stack
+                            .push(Rc::new(Object::LazySequence(LazySequence::inclusive_range(*from, *to)))),
+                        (from, to) => {
+                            let message = format!(
+                                "Expected Integer inclusive range, found: {}..={}",
+                                from.name(),
+                                to.name()
+                            );
+                            return Err(self.error(evaluator, message));
+                        }
+                    }
+                }
+                Instruction::ExclusiveRange => {
+                    let until = self.pop();
+                    let from = self.pop();
+                    match (&*from, &*until) {
+                        (Object::Integer(from), Object::Integer(until)) => {
+                            self.stack
+                                .push(Rc::new(Object::LazySequence(LazySequence::exclusive_range(
+                                    *from, *until,
+                                ))))
+                        }
+                        (from, until) => {
+                            let message = format!(
+                                "Expected Integer inclusive range, found: {}..{}",
+                                from.name(),
+                                until.name()
+                            );
+                            return Err(self.error(evaluator, message));
+                        }
+                    }
+                }
+                Instruction::UnboundedRange => {
+                    let from = self.pop();
+                    match &*from {
+                        Object::Integer(from) => self
+                            .stack
+                            .push(Rc::new(Object::LazySequence(LazySequence::unbounded_range(*from)))),
+                        from => {
+                            let message = format!("Expected Integer unbounded range, found: {}..", from.name());
+                            return Err(self.error(evaluator, message));
+                        }
+                    }
+                }
+                Instruction::Interpolate(count) => {
+                    let parts = self.stack.split_off(self.stack.len() - count as usize);
+                    let mut value = String::new();
+                    for part in parts {
+                        match &*part {
+                            Object::String(string) => value.push_str(string),
+                            object => value.push_str(&object.to_string()),
+                        }
+                    }
+                    self.stack.push(Rc::new(Object::String(value)));
+                }
+                Instruction::CheckFunction => {
+                    if !matches!(&**self.peek(), Object::Function(_)) {
+                        let message = format!("Expected a Function, found: {}", self.peek().name());
+                        return Err(self.error(evaluator, message));
+                    }
+                }
+                Instruction::Compose(count) => {
+                    let functions = self
+                        .stack
+                        .split_off(self.stack.len() - count as usize)
+                        .into_iter()
+                        .map(|function| match &*function {
+                            Object::Function(function) => function.clone(),
+                            _ => unreachable!(),
+                        })
+                        .collect();
+                    self.stack
+                        .push(Rc::new(Object::Function(Function::Composition { functions })));
+                }
+                Instruction::ExpectList { argument } => {
+                    let value = self.pop();
+                    if !matches!(&*value, Object::List(_)) {
+                        let message = if argument {
+                            format!("Expected a List argument to destructure, found: {}", value.name())
+                        } else {
+                            format!("Expected a List to destructure, found: {}", value.name())
+                        };
+                        return Err(self.error(evaluator, message));
+                    }
+                }
+                Instruction::ExpectDictionary { argument } => {
+                    let value = self.pop();
+                    if !matches!(&*value, Object::Dictionary(_)) {
+                        let message = if argument {
+                            format!("Expected a Dictionary argument to destructure, found: {}", value.name())
+                        } else {
+                            format!("Expected a Dictionary to destructure, found: {}", value.name())
+                        };
+                        return Err(self.error(evaluator, message));
+                    }
+                }
+                Instruction::ListElement(index) => {
+                    let element = match &*self.pop() {
+                        Object::List(list) => list.get(index as usize).cloned(),
+                        _ => None,
+                    };
+                    self.stack.push(element.unwrap_or_else(|| Rc::clone(&self.nil)));
+                }
+                Instruction::ListElementOrEmpty(index) => {
+                    let element = match &*self.pop() {
+                        Object::List(list) => list.get(index as usize).cloned(),
+                        _ => None,
+                    };
+                    self.stack
+                        .push(element.unwrap_or_else(|| Rc::new(Object::List(Vector::new()))));
+                }
+                Instruction::ListElementFromEnd(offset) => {
+                    let element = match &*self.pop() {
+                        Object::List(list) => list.get(list.len() - offset as usize).cloned(),
+                        _ => None,
+                    };
+                    self.stack.push(element.unwrap_or_else(|| Rc::clone(&self.nil)));
+                }
+                Instruction::ListRest(start) => {
+                    let rest = match &*self.pop() {
+                        Object::List(list) => list.iter().skip(start as usize).cloned().collect(),
+                        _ => Vector::new(),
+                    };
+                    self.stack.push(Rc::new(Object::List(rest)));
+                }
+                Instruction::ListSlice { start, end } => {
+                    let slice = match &*self.pop() {
+                        Object::List(list) => list.clone().slice(start as usize..list.len() - end as usize),
+                        _ => Vector::new(),
+                    };
+                    self.stack.push(Rc::new(Object::List(slice)));
+                }
+                Instruction::DictionaryGet => {
+                    let key = self.pop();
+                    let value = match &*self.pop() {
+                        Object::Dictionary(dictionary) => dictionary.get(&key).cloned(),
+                        _ => None,
+                    };
+                    self.stack.push(value.unwrap_or_else(|| Rc::clone(&self.nil)));
+                }
+                Instruction::DictionaryRest(count) => {
+                    let keys = self.stack.split_off(self.stack.len() - count as usize);
+                    let mut rest = match &*self.pop() {
+                        Object::Dictionary(dictionary) => dictionary.clone(),
+                        _ => HashMap::default(),
+                    };
+                    for key in keys {
+                        rest.remove(&key);
+                    }
+                    self.stack.push(Rc::new(Object::Dictionary(rest)));
+                }
+                Instruction::MatchList { length, rest } => {
+                    let matched = match &*self.pop() {
+                        Object::List(list) if rest => list.len() >= length as usize,
+                        Object::List(list) => list.len() == length as usize,
+                        _ => false,
+                    };
+                    self.stack.push(Rc::new(Object::Boolean(matched)));
+                }
+                Instruction::MatchDictionary => {
+                    let matched = matches!(&*self.pop(), Object::Dictionary(_));
+                    self.stack.push(Rc::new(Object::Boolean(matched)));
+                }
+                Instruction::MatchKey => {
+                    let key = self.pop();
+                    let matched = match &*self.pop() {
+                        Object::Dictionary(dictionary) => dictionary.contains_key(&key),
+                        _ => false,
+                    };
+                    self.stack.push(Rc::new(Object::Boolean(matched)));
+                }
+                Instruction::MatchRange { range, strict } => {
+                    let range = &frame.closure.prototype.chunk.ranges[range as usize];
+                    let matched = match &*self.stack.pop().unwrap() {
+                        value @ (Object::Integer(_) | Object::BigInteger(_)) if strict => {
+                            !matches!(range, instruction::RangePattern::Any) && range.contains(value)
+                        }
+                        value @ (Object::Integer(_) | Object::BigInteger(_)) => range.contains(value),
+                        _ => !strict,
+                    };
+                    self.stack.push(Rc::new(Object::Boolean(matched)));
+                }
+                Instruction::MatchVariant { name, length } | Instruction::MatchLocalVariant { name, length } => {
+                    let local = matches!(instruction, Instruction::MatchLocalVariant { .. });
+                    let name = &frame.closure.prototype.chunk.names[name as usize];
+                    let subject = self.stack.pop().unwrap();
+                    let declared = if local {
+                        self.stack.pop()
+                    } else {
+                        frame.closure.globals.borrow().get_variable(name)
+                    };
+                    let length = length.map(|length| length as usize);
+                    let matched = matcher::variant_matches(declared.as_deref(), name, length, &subject);
+                    self.stack.push(Rc::new(Object::Boolean(matched)));
+                }
+                Instruction::VariantField(index) => {
+                    let field = match &*self.pop() {
+                        Object::Variant { fields, .. } => fields.get(index as usize).cloned(),
+                        _ => None,
+                    };
+                    self.stack.push(field.unwrap_or_else(|| Rc::clone(&self.nil)));
+                }
+                Instruction::MatchString { prefix, suffix } => {
+                    let names = &frame.closure.prototype.chunk.names;
+                    let prefix = prefix.map(|prefix| names[prefix as usize].as_str());
+                    let suffix = suffix.map(|suffix| names[suffix as usize].as_str());
+                    let remainder = matcher::string_remainder(prefix, suffix, &self.stack.pop().unwrap());
+                    self.stack.push(remainder.unwrap_or_else(|| Rc::clone(&self.nil)));
+                }
+                Instruction::MatchRegex(index) => {
+                    let regex = &frame.closure.prototype.chunk.regexes[index as usize];
+                    let captures = match matcher::regex_captures(regex, &self.stack.pop().unwrap()) {
+                        Some(captures) => Rc::new(Object::List(captures.into())),
+                        None => Rc::clone(&self.nil),
+                    };
+                    self.stack.push(captures);
+                }
+                Instruction::Section(index) => {
+                    let (name, body, attributes) = &frame.closure.prototype.chunk.sections[index as usize];
+                    frame
+                        .closure
+                        .globals
+                        .borrow_mut()
+                        .add_section(name, Rc::clone(body), attributes.clone());
+                    let nil = Rc::clone(&self.nil);
+                    self.stack.push(nil);
+                }
+                Instruction::BindingAttributes(index) => {
+                    let (name, attributes) = &frame.closure.prototype.chunk.binding_attributes[index as usize];
+                    frame
+                        .closure
+                        .globals
+                        .borrow_mut()
+                        .add_binding_attributes(name, attributes.clone());
+                }
+                Instruction::Import(index) => {
+                    let prototype = Rc::clone(&frame.closure.prototype);
+                    let (path, bindings) = &prototype.chunk.imports[index as usize];
+                    let source = self.location();
+                    let result = crate::evaluator::import::import(evaluator, path, bindings, source)?;
+                    self.stack.push(result);
+                }
+                Instruction::Error(message) => {
+                    let message = frame.closure.prototype.chunk.names[message as usize].clone();
+                    return Err(self.error(evaluator, message));
+                }
+            }
+        }
+    }
+
+    fn lookup_global(&self, evaluator: &Evaluator, name: &str) -> Evaluation {
+        let frame = self.frames.last().unwrap();
+
+        if let Some(value) = frame.closure.globals.borrow().get_variable(name) {
+            return Ok(value);
+        }
+
+        if let Some(builtin) = crate::evaluator::builtins::builtins(name) {
+            return Ok(builtin);
+        }
+
+        if let Some(builtin) = crate::evaluator::builtins::builtin_aliases(name) {
+            return Ok(builtin);
+        }
+
+        if let Some(external_functions) = &evaluator.external_functions {
+            if let Some(external) = external_functions.get(name) {
+                return Ok(Rc::clone(external));
+            }
+        }
+
+        Err(self.error(evaluator, format!("Identifier can not be found: {}", name)))
+    }
+
+    fn collect_set(&self, evaluator: &Evaluator, elements: Vec<Rc<Object>>) -> Evaluation {
+        let mut set = HashSet::default();
+        for element in elements {
+            if !element.is_hashable() {
+                let message = format!("Unable to include a {} within an Set", element.name());
+                return Err(self.error(evaluator, message));
+            }
+            set.insert(element);
+        }
+        Ok(Rc::new(Object::Set(set)))
+    }
+
+    fn apply_operator(
+        &self,
+        evaluator: &mut Evaluator,
+        instruction: Instruction,
+        left: &Rc<Object>,
+        right: &Rc<Object>,
+    ) -> Evaluation {
+        use crate::evaluator::builtins::operators;
+
+        let source = self.location();
+        match instruction {
+            Instruction::Add => operators::plus(evaluator, left, right, source),
+            Instruction::Subtract => operators::minus(evaluator, left, right, source),
+            Instruction::Multiply => operators::asterisk(left, right, source),
+            Instruction::Divide => operators::slash(left, right, source),
+            Instruction::Modulo => operators::modulo(left, right, source),
+            Instruction::Equal => operators::equal(left, right),
+            Instruction::NotEqual => operators::not_equal(left, right),
+            Instruction::LessThan => operators::less_than(left, right),
+            Instruction::LessThanEqual => operators::less_than_equal(left, right),
+            Instruction::GreaterThan => operators::greater_than(left, right),
+            Instruction::GreaterThanEqual => operators::greater_than_equal(left, right),
+            _ => unreachable!(),
+        }
+    }
+}
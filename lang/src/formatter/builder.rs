@@ -1,5 +1,7 @@
 use super::doc::Doc;
-use crate::parser::ast::{Expression, ExpressionKind, Infix, MatchCase, Prefix, Program, Statement, StatementKind};
+use crate::parser::ast::{
+    Attribute, Expression, ExpressionKind, Infix, MatchCase, Prefix, Program, Statement, StatementKind, StringPart,
+};
 
 /// Standard indentation level in spaces.
 /// All nested blocks use this consistent indent for visual hierarchy.
@@ -24,9 +26,13 @@ pub fn build_program(program: &Program) -> Doc {
     let mut result = Vec::new();
     for (i, stmt) in program.statements.iter().enumerate() {
         if i > 0 {
-            // Always blank line between top-level statements
-            result.push(Doc::HardLine);
+            // Always blank line between top-level statements, apart from consecutive imports
+            let is_consecutive_import = matches!(stmt.kind, StatementKind::Import { .. })
+                && matches!(program.statements[i - 1].kind, StatementKind::Import { .. });
             result.push(Doc::HardLine);
+            if !is_consecutive_import {
+                result.push(Doc::HardLine);
+            }
         }
         result.push(build_statement(stmt, true));
 
@@ -107,13 +113,38 @@ fn build_statement(stmt: &Statement, is_top_level: bool) -> Doc {
 
         StatementKind::Comment(text) => Doc::text(text.as_str()),
 
-        StatementKind::Section { name, body, attributes } => {
-            let mut parts = Vec::new();
+        StatementKind::Import { path, bindings } => match bindings {
+            Some(bindings) => Doc::concat(vec![
+                Doc::text(format!("import {{ {} }} from ", bindings.join(", "))),
+                build_string(path),
+            ]),
+            None => Doc::concat(vec![Doc::text("import "), build_string(path)]),
+        },
 
-            for attr in attributes {
-                parts.push(Doc::text(format!("@{}", attr.name)));
-                parts.push(Doc::HardLine);
-            }
+        StatementKind::Type { name, variants } => {
+            let docs: Vec<Doc> = variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    Doc::concat(vec![
+                        Doc::line(),
+                        if i == 0 {
+                            Doc::if_break(Doc::text("| "), Doc::Nil)
+                        } else {
+                            Doc::text("| ")
+                        },
+                        Doc::text(variant.to_string()),
+                    ])
+                })
+                .collect();
+            Doc::group(Doc::concat(vec![
+                Doc::text(format!("type {} =", name)),
+                Doc::nest(INDENT_SIZE, Doc::concat(docs)),
+            ]))
+        }
+
+        StatementKind::Section { name, body, attributes } => {
+            let mut parts = build_attributes(attributes);
 
             parts.push(Doc::text(format!("{}: ", name)));
 
@@ -139,6 +170,14 @@ fn build_statement(stmt: &Statement, is_top_level: bool) -> Doc {
             Doc::concat(parts)
         }
 
+        StatementKind::Attributed {
+            attributes, expression, ..
+        } => {
+            let mut parts = build_attributes(attributes);
+            parts.push(build_expression(expression));
+            Doc::concat(parts)
+        }
+
         StatementKind::Expression(expr) => build_expression(expr),
 
         StatementKind::Block(statements) => {
@@ -165,18 +204,22 @@ fn build_expression(expr: &Expression) -> Doc {
         ExpressionKind::Integer(value) => Doc::text(value),
         ExpressionKind::Decimal(value) => Doc::text(value),
         ExpressionKind::String(value) => build_string(value),
+        ExpressionKind::InterpolatedString(parts) => build_interpolated_string(parts),
         ExpressionKind::Boolean(value) => Doc::text(if *value { "true" } else { "false" }),
         ExpressionKind::Nil => Doc::text("nil"),
         ExpressionKind::Placeholder => Doc::text("_"),
 
         // Identifiers
-        ExpressionKind::Identifier(name) => Doc::text(name),
-        ExpressionKind::RestIdentifier(name) => Doc::concat(vec![Doc::text(".."), Doc::text(name)]),
+        ExpressionKind::Identifier(name) | ExpressionKind::ResolvedIdentifier { name, .. } => Doc::text(name),
+        ExpressionKind::RestIdentifier(name) | ExpressionKind::ResolvedRestIdentifier { name, .. } => {
+            Doc::concat(vec![Doc::text(".."), Doc::text(name)])
+        }
 
         // Bindings
         ExpressionKind::Let { name, value } => build_let(name, value, false),
         ExpressionKind::MutableLet { name, value } => build_let(name, value, true),
         ExpressionKind::Assign { name, value } => build_assign(name, value),
+        ExpressionKind::CompoundAssign { name, operator, value } => build_compound_assign(name, operator, value),
 
         // Collections
         ExpressionKind::List(elements) => build_collection("[", elements, "]"),
@@ -218,6 +261,42 @@ fn build_expression(expr: &Expression) -> Doc {
         ExpressionKind::DictionaryEntryPattern { key, value } => {
             Doc::concat(vec![build_expression(key), Doc::text(": "), build_expression(value)])
         }
+        ExpressionKind::VariantMatchPattern { name, fields } => match fields {
+            Some(fields) => {
+                let docs: Vec<Doc> = fields.iter().map(build_expression).collect();
+                Doc::concat(vec![
+                    Doc::text(format!("{}(", name)),
+                    Doc::join(docs, Doc::text(", ")),
+                    Doc::text(")"),
+                ])
+            }
+            None => Doc::text(name),
+        },
+        ExpressionKind::OrMatchPattern(alternatives) => {
+            let docs: Vec<Doc> = alternatives.iter().map(build_expression).collect();
+            Doc::join(docs, Doc::text(" | "))
+        }
+        ExpressionKind::StringMatchPattern {
+            prefix,
+            remainder,
+            suffix,
+        } => {
+            let mut docs = vec![];
+            if let Some(prefix) = prefix {
+                docs.extend([build_string(prefix), Doc::text(" ++ ")]);
+            }
+            docs.push(build_expression(remainder));
+            if let Some(suffix) = suffix {
+                docs.extend([Doc::text(" ++ "), build_string(suffix)]);
+            }
+            Doc::concat(docs)
+        }
+        ExpressionKind::RegexMatchPattern { pattern, .. } => Doc::text(format!("r\"{}\"", pattern)),
+        ExpressionKind::BindingMatchPattern { name, pattern } => Doc::concat(vec![
+            build_expression(name),
+            Doc::text(" @ "),
+            build_expression(pattern),
+        ]),
     }
 }
 
@@ -225,6 +304,14 @@ fn build_assign(name: &Expression, value: &Expression) -> Doc {
     Doc::concat(vec![build_expression(name), Doc::text(" = "), build_expression(value)])
 }
 
+fn build_compound_assign(name: &Expression, operator: &Infix, value: &Expression) -> Doc {
+    Doc::concat(vec![
+        build_expression(name),
+        Doc::text(format!(" {}= ", operator)),
+        build_expression(value),
+    ])
+}
+
 fn build_call(function: &Expression, arguments: &[Expression]) -> Doc {
     if arguments.is_empty() {
         return Doc::concat(vec![build_expression(function), Doc::text("()")]);
@@ -375,9 +462,7 @@ fn build_prefix_expr(operator: &Prefix, right: &Expression) -> Doc {
     let right_doc = build_expression(right);
     let needs_parens = matches!(
         right.kind,
-        ExpressionKind::Infix { .. }
-            | ExpressionKind::FunctionThread { .. }
-            | ExpressionKind::FunctionComposition(_)
+        ExpressionKind::Infix { .. } | ExpressionKind::FunctionThread { .. } | ExpressionKind::FunctionComposition(_)
     );
     if needs_parens {
         Doc::concat(vec![build_prefix(operator), Doc::text("("), right_doc, Doc::text(")")])
@@ -395,6 +480,22 @@ fn build_string(value: &str) -> Doc {
     Doc::text(format!("\"{}\"", escaped))
 }
 
+fn build_interpolated_string(parts: &[StringPart]) -> Doc {
+    let mut docs = vec![Doc::text("$\"")];
+    for part in parts {
+        match part {
+            StringPart::Literal(value) => docs.push(Doc::text(escape_string(value).replace('{', "\\{"))),
+            StringPart::Expression(expression) => {
+                docs.push(Doc::text("{"));
+                docs.push(build_expression(expression));
+                docs.push(Doc::text("}"));
+            }
+        }
+    }
+    docs.push(Doc::text("\""));
+    Doc::concat(docs)
+}
+
 /// Extracts the last argument if it's a lambda function
 struct TrailingClosure<'a> {
     parameters: &'a [Expression],
@@ -424,6 +525,23 @@ fn extract_trailing_closure(arguments: &[Expression]) -> Option<TrailingClosure<
     })
 }
 
+fn build_attributes(attributes: &[Attribute]) -> Vec<Doc> {
+    let mut parts = Vec::new();
+
+    for attr in attributes {
+        parts.push(Doc::text(format!("@{}", attr.name)));
+        if !attr.arguments.is_empty() {
+            let arguments: Vec<Doc> = attr.arguments.iter().map(build_expression).collect();
+            parts.push(Doc::text("("));
+            parts.push(Doc::join(arguments, Doc::text(", ")));
+            parts.push(Doc::text(")"));
+        }
+        parts.push(Doc::HardLine);
+    }
+
+    parts
+}
+
 fn build_block_body(stmts: &[Statement]) -> Doc {
     if stmts.is_empty() {
         Doc::text("{}")
@@ -476,9 +594,7 @@ fn build_chain(initial: &Expression, functions: &[Expression], op: &str) -> Doc
             // Lambdas that aren't the last element need block braces to prevent
             // the next pipe from being parsed as part of the lambda body
             let f_doc = match &f.kind {
-                ExpressionKind::Function { parameters, body } if !is_last => {
-                    build_lambda_with_block(parameters, body)
-                }
+                ExpressionKind::Function { parameters, body } if !is_last => build_lambda_with_block(parameters, body),
                 _ => build_expression(f),
             };
             Doc::concat(vec![
@@ -814,11 +930,13 @@ fn contains_block_lambda(expr: &Expression) -> bool {
 
 fn contains_block_lambda_in_stmt(stmt: &Statement) -> bool {
     match &stmt.kind {
-        StatementKind::Expression(expr) => contains_block_lambda(expr),
+        StatementKind::Expression(expr) | StatementKind::Attributed { expression: expr, .. } => {
+            contains_block_lambda(expr)
+        }
         StatementKind::Block(stmts) => stmts.iter().any(contains_block_lambda_in_stmt),
         StatementKind::Return(expr) | StatementKind::Break(expr) => contains_block_lambda(expr),
         StatementKind::Section { body, .. } => body.statements.iter().any(contains_block_lambda_in_stmt),
-        StatementKind::Comment(_) => false,
+        StatementKind::Comment(_) | StatementKind::Import { .. } | StatementKind::Type { .. } => false,
     }
 }
 
@@ -100,6 +100,43 @@ fn format_string_short_few_newlines_escapes() {
     assert_eq!(format("\"hello\\nworld\"").unwrap(), "\"hello\\nworld\"\n");
 }
 
+#[test]
+fn format_interpolated_string() {
+    assert_eq!(
+        format(r#"$"x={x+1}, xs={ [1,2] |> sum }""#).unwrap(),
+        "$\"x={x + 1}, xs={[1, 2] |> sum}\"\n"
+    );
+}
+
+#[test]
+fn format_interpolated_string_with_escaped_braces() {
+    assert_eq!(format(r#"$"\{x} {x}\n\"""#).unwrap(), "$\"\\{x} {x}\\n\\\"\"\n");
+}
+
+#[test]
+fn format_import() {
+    assert_eq!(
+        format(r#"import "lib/grid.santa""#).unwrap(),
+        "import \"lib/grid.santa\"\n"
+    );
+}
+
+#[test]
+fn format_selective_import() {
+    assert_eq!(
+        format(r#"import {neighbours,directions} from "lib/grid.santa";"#).unwrap(),
+        "import { neighbours, directions } from \"lib/grid.santa\"\n"
+    );
+}
+
+#[test]
+fn format_consecutive_imports_without_blank_line() {
+    assert_eq!(
+        format("import \"a.santa\";\nimport \"b.santa\";\nlet x = 1;").unwrap(),
+        "import \"a.santa\"\nimport \"b.santa\"\n\nlet x = 1\n"
+    );
+}
+
 #[test]
 fn format_boolean_true() {
     assert_eq!(format("true").unwrap(), "true\n");
@@ -561,6 +598,15 @@ fn format_let_with_expression() {
     assert_eq!(format("let x=1+2").unwrap(), "let x = 1 + 2\n");
 }
 
+#[test]
+fn format_compound_assign() {
+    assert_eq!(format("x+=1").unwrap(), "x += 1\n");
+    assert_eq!(format("x -= 1").unwrap(), "x -= 1\n");
+    assert_eq!(format("x*=y+1").unwrap(), "x *= y + 1\n");
+    assert_eq!(format("xs[0]/=2").unwrap(), "xs[0] /= 2\n");
+    assert_eq!(format("x%=2").unwrap(), "x %= 2\n");
+}
+
 #[test]
 fn format_assign() {
     assert_eq!(format("x=1").unwrap(), "x = 1\n");
@@ -621,6 +667,26 @@ fn format_section_with_multiple_attributes() {
     );
 }
 
+#[test]
+fn format_section_with_attribute_arguments() {
+    assert_eq!(
+        format("@skip\n@name( \"example\",2 )\ntest: { 1 }").unwrap(),
+        "@skip\n@name(\"example\", 2)\ntest: 1\n"
+    );
+}
+
+#[test]
+fn format_let_with_attributes() {
+    assert_eq!(
+        format("@input(\"sample.txt\") @slow let x = 1;\nx").unwrap(),
+        "@input(\"sample.txt\")\n@slow\nlet x = 1\n\nx\n"
+    );
+    assert_eq!(
+        format("part_one: { @memo let x = 1; x }").unwrap(),
+        "part_one: {\n  @memo\n  let x = 1;\n\n  x\n}\n"
+    );
+}
+
 #[test]
 fn format_sections_have_blank_lines_between() {
     assert_eq!(
@@ -712,6 +778,16 @@ fn idempotent_multiline() {
     assert_idempotent("let x = 1;\nlet y = 2;\nx + y");
 }
 
+#[test]
+fn idempotent_interpolated_string() {
+    assert_idempotent(r#"$"{a}: {#{"b": {1}}} \{c}""#);
+}
+
+#[test]
+fn idempotent_import() {
+    assert_idempotent(r#"import { a, b } from "lib.santa""#);
+}
+
 #[test]
 fn idempotent_complex() {
     let source = "let data = [1, 2, 3, 4, 5]; data |> sum";
@@ -1219,3 +1295,38 @@ fn format_lambda_last_in_pipe_chain_can_inline() {
     let result = format(input).unwrap();
     assert_eq!(result, "a |> |x| x\n");
 }
+
+#[test]
+fn format_type_statement() {
+    assert_eq!(
+        format("type Instr = Move(dx, dy) | Stop").unwrap(),
+        "type Instr = Move(dx, dy) | Stop\n"
+    );
+}
+
+#[test]
+fn round_trip_match_variant() {
+    assert_round_trip("match instr { Move(x, [y]) { x } Stop { 0 } }");
+}
+
+#[test]
+fn round_trip_match_or_pattern() {
+    assert_round_trip("match dir { \"L\" | \"R\" { 1 } [x, 1] | [1, x] { x } }");
+}
+
+#[test]
+fn round_trip_match_binding_pattern() {
+    assert_round_trip("match point { p @ [x, Integer] { p } }");
+}
+
+#[test]
+fn round_trip_match_string_pattern() {
+    assert_round_trip(
+        "match line { \"move \" ++ n { n } \"(\" ++ inner ++ \")\" { inner } name ++ \".txt\" { name } }",
+    );
+}
+
+#[test]
+fn round_trip_match_regex_pattern() {
+    assert_round_trip(r#"match line { r"(?<dir>[LR])(?<n>\d+)" { [dir, n] } }"#);
+}
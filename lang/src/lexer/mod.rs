@@ -18,6 +18,15 @@ pub struct Lexer<'a> {
     token_buffer: Option<Token>,
     line: usize,
     blank_lines_before: bool,
+    interpolations: Vec<Interpolation>,
+}
+
+// Interpolated strings (`$"x={x}"`) switch the lexer between consuming literal string parts and
+// regular tokens. Each `{` within an interpolated expression is counted so the lexer knows which
+// `}` closes the interpolation.
+enum Interpolation {
+    String,
+    Expression(usize),
 }
 
 impl<'a> Lexer<'a> {
@@ -29,6 +38,7 @@ impl<'a> Lexer<'a> {
             token_buffer: None,
             line: 1,
             blank_lines_before: false,
+            interpolations: vec![],
         }
     }
 
@@ -38,6 +48,10 @@ impl<'a> Lexer<'a> {
             return token;
         }
 
+        if let Some(Interpolation::String) = self.interpolations.last() {
+            return self.consume_interpolated_string_part();
+        }
+
         self.skip_whitespace();
 
         let start = self.position;
@@ -99,10 +113,19 @@ impl<'a> Lexer<'a> {
             '#' => match self.peek() {
                 '{' => {
                     self.consume();
+                    self.open_interpolated_brace();
                     T!["#{"]
                 }
                 _ => T![ILLEGAL],
             },
+            '$' => match self.peek() {
+                '"' => {
+                    self.consume();
+                    self.interpolations.push(Interpolation::String);
+                    T![ISTR_START]
+                }
+                _ => T![ILLEGAL],
+            },
             '.' => match self.peek() {
                 '.' => {
                     self.consume();
@@ -117,22 +140,60 @@ impl<'a> Lexer<'a> {
                 _ => T![ILLEGAL],
             },
 
-            '+' => T![+],
-            '-' => T![-],
-            '*' => T![*],
+            '+' => match self.peek() {
+                '=' => {
+                    self.consume();
+                    T![+=]
+                }
+                '+' => {
+                    self.consume();
+                    T![++]
+                }
+                _ => T![+],
+            },
+            '-' => match self.peek() {
+                '=' => {
+                    self.consume();
+                    T![-=]
+                }
+                _ => T![-],
+            },
+            '*' => match self.peek() {
+                '=' => {
+                    self.consume();
+                    T![*=]
+                }
+                _ => T![*],
+            },
             '/' => match self.peek() {
                 '/' => self.consume_comment(),
+                '=' => {
+                    self.consume();
+                    T![/=]
+                }
                 _ => T![/],
             },
-            '%' => T![%],
+            '%' => match self.peek() {
+                '=' => {
+                    self.consume();
+                    T![%=]
+                }
+                _ => T![%],
+            },
 
             ';' => T![;],
             ',' => T![,],
             ':' => T![:],
             '(' => T!['('],
             ')' => T![')'],
-            '{' => T!['{'],
-            '}' => T!['}'],
+            '{' => {
+                self.open_interpolated_brace();
+                T!['{']
+            }
+            '}' => {
+                self.close_interpolated_brace();
+                T!['}']
+            }
             '[' => T!['['],
             ']' => T![']'],
             '_' => T![_],
@@ -141,6 +202,10 @@ impl<'a> Lexer<'a> {
             '`' => self.consume_backtick(),
             '"' => self.consume_string(),
             '0'..='9' => return self.consume_number(start, line, blank_lines_before),
+            'r' if self.peek() == '"' => {
+                self.consume();
+                self.consume_regex()
+            }
             'a'..='z' | 'A'..='Z' => self.consume_identifier_or_keyword(start),
 
             EOF_CHAR => T![EOF],
@@ -181,6 +246,70 @@ impl<'a> Lexer<'a> {
         T![STR]
     }
 
+    // Regex literals are kept verbatim, as the regex syntax has its own escape sequences.
+    fn consume_regex(&mut self) -> TokenKind {
+        match self.consume_string() {
+            T![STR] => T![REGEX],
+            token => token,
+        }
+    }
+
+    fn consume_interpolated_string_part(&mut self) -> Token {
+        let start = self.position;
+
+        let kind = match self.peek() {
+            '"' => {
+                self.consume();
+                self.interpolations.pop();
+                T![ISTR_END]
+            }
+            '{' => {
+                self.consume();
+                self.interpolations.push(Interpolation::Expression(0));
+                T!['{']
+            }
+            EOF_CHAR => {
+                self.interpolations.pop();
+                T![ILLEGAL]
+            }
+            _ => {
+                loop {
+                    match self.peek() {
+                        '"' | '{' | EOF_CHAR => break,
+                        '\\' => {
+                            self.consume();
+                            self.consume();
+                        }
+                        _ => {
+                            self.consume();
+                        }
+                    }
+                }
+                T![ISTR_PART]
+            }
+        };
+
+        Token::new(kind, start, self.position, self.line, false)
+    }
+
+    fn open_interpolated_brace(&mut self) {
+        if let Some(Interpolation::Expression(depth)) = self.interpolations.last_mut() {
+            *depth += 1;
+        }
+    }
+
+    fn close_interpolated_brace(&mut self) {
+        match self.interpolations.last_mut() {
+            Some(Interpolation::Expression(0)) => {
+                self.interpolations.pop();
+            }
+            Some(Interpolation::Expression(depth)) => {
+                *depth -= 1;
+            }
+            _ => {}
+        }
+    }
+
     fn consume_number(&mut self, start: usize, line: usize, blank_lines_before: bool) -> Token {
         self.consume_while(|ch| matches!(ch, '0'..='9' | '_'));
 
@@ -262,6 +391,10 @@ impl<'a> Lexer<'a> {
         self.blank_lines_before = newline_count >= MIN_NEWLINES_FOR_BLANK_LINE;
     }
 
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
     pub fn get_source(&self, token: &Token) -> &'a str {
         &self.input[token.source.start..token.source.end]
     }
@@ -79,6 +79,60 @@ fn string_with_multibyte_unicode_characters() {
     )
 }
 
+#[test]
+fn regex() {
+    assert_tokens(
+        r#"r"(?<dir>[LR])(?<n>\d+)" r "a""#,
+        expect![[r#"
+            [
+                "Token { kind: Regex, source: 0..24, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: Identifier, source: 25..26, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: String, source: 27..30, line: 1, preceded_by_blank_line: false }",
+            ]"#]],
+    )
+}
+
+#[test]
+fn concatenation_symbol() {
+    assert_tokens(
+        "\"move \" ++ rest",
+        expect![[r#"
+            [
+                "Token { kind: String, source: 0..7, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: PlusPlus, source: 8..10, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: Identifier, source: 11..15, line: 1, preceded_by_blank_line: false }",
+            ]"#]],
+    )
+}
+
+#[test]
+fn interpolated_string() {
+    assert_tokens(
+        r#"$"x={x}, y={#{"a": 1}["a"]} \{}""#,
+        expect![[r#"
+            [
+                "Token { kind: InterpolatedStringStart, source: 0..2, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: InterpolatedStringPart, source: 2..4, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: LBrace, source: 4..5, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: Identifier, source: 5..6, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: RBrace, source: 6..7, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: InterpolatedStringPart, source: 7..11, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: LBrace, source: 11..12, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: HashLBrace, source: 12..14, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: String, source: 14..17, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: Colon, source: 17..18, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: Integer, source: 19..20, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: RBrace, source: 20..21, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: LBracket, source: 21..22, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: String, source: 22..25, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: RBracket, source: 25..26, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: RBrace, source: 26..27, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: InterpolatedStringPart, source: 27..31, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: InterpolatedStringEnd, source: 31..32, line: 1, preceded_by_blank_line: false }",
+            ]"#]],
+    )
+}
+
 #[test]
 fn backticks() {
     assert_tokens(
@@ -288,6 +342,21 @@ fn symbols() {
     );
 }
 
+#[test]
+fn compound_assignment_symbols() {
+    assert_tokens(
+        "+= -= *= /= %=",
+        expect![[r#"
+            [
+                "Token { kind: PlusAssign, source: 0..2, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: MinusAssign, source: 3..5, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: AsteriskAssign, source: 6..8, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: SlashAssign, source: 9..11, line: 1, preceded_by_blank_line: false }",
+                "Token { kind: ModuloAssign, source: 12..14, line: 1, preceded_by_blank_line: false }",
+            ]"#]],
+    );
+}
+
 #[test]
 fn script() {
     assert_tokens(
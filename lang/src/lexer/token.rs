@@ -20,16 +20,26 @@ pub enum TokenKind {
     Integer,
     Decimal,
     String,
+    Regex,
+    InterpolatedStringStart,
+    InterpolatedStringPart,
+    InterpolatedStringEnd,
     Comment,
     Underscore,
 
     Assign,
     Plus,
+    PlusPlus,
     Minus,
     Bang,
     Asterisk,
     Slash,
     Modulo,
+    PlusAssign,
+    MinusAssign,
+    AsteriskAssign,
+    SlashAssign,
+    ModuloAssign,
 
     Equal,
     NotEqual,
@@ -64,6 +74,7 @@ pub enum TokenKind {
 
     Mutable,
     Match,
+    Import,
     Let,
     If,
     Else,
@@ -101,6 +112,7 @@ impl Token {
         match id {
             "mut" => Some(TokenKind::Mutable),
             "match" => Some(TokenKind::Match),
+            "import" => Some(TokenKind::Import),
             "let" => Some(TokenKind::Let),
             "if" => Some(TokenKind::If),
             "else" => Some(TokenKind::Else),
@@ -130,16 +142,26 @@ macro_rules! T {
     [INT] => { $crate::lexer::TokenKind::Integer };
     [DEC] => { $crate::lexer::TokenKind::Decimal };
     [STR] => { $crate::lexer::TokenKind::String };
+    [REGEX] => { $crate::lexer::TokenKind::Regex };
+    [ISTR_START] => { $crate::lexer::TokenKind::InterpolatedStringStart };
+    [ISTR_PART] => { $crate::lexer::TokenKind::InterpolatedStringPart };
+    [ISTR_END] => { $crate::lexer::TokenKind::InterpolatedStringEnd };
     [CMT] => { $crate::lexer::TokenKind::Comment };
     [_] => { $crate::lexer::TokenKind::Underscore };
 
     [=] => { $crate::lexer::TokenKind::Assign };
     [+] => { $crate::lexer::TokenKind::Plus };
+    [++] => { $crate::lexer::TokenKind::PlusPlus };
     [-] => { $crate::lexer::TokenKind::Minus };
     [!] => { $crate::lexer::TokenKind::Bang };
     [*] => { $crate::lexer::TokenKind::Asterisk };
     [/] => { $crate::lexer::TokenKind::Slash };
     [%] => { $crate::lexer::TokenKind::Modulo };
+    [+=] => { $crate::lexer::TokenKind::PlusAssign };
+    [-=] => { $crate::lexer::TokenKind::MinusAssign };
+    [*=] => { $crate::lexer::TokenKind::AsteriskAssign };
+    [/=] => { $crate::lexer::TokenKind::SlashAssign };
+    [%=] => { $crate::lexer::TokenKind::ModuloAssign };
 
     [==] => { $crate::lexer::TokenKind::Equal };
     [!=] => { $crate::lexer::TokenKind::NotEqual };
@@ -174,6 +196,7 @@ macro_rules! T {
 
     [MUT] => { $crate::lexer::TokenKind::Mutable };
     [MATCH] => { $crate::lexer::TokenKind::Match };
+    [IMPORT] => { $crate::lexer::TokenKind::Import };
     [LET] => { $crate::lexer::TokenKind::Let };
     [IF] => { $crate::lexer::TokenKind::If };
     [ELSE] => { $crate::lexer::TokenKind::Else };
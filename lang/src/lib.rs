@@ -7,8 +7,11 @@ mod lexer;
 mod parser;
 mod runner;
 
-pub use crate::evaluator::{Arguments, Environment, Evaluation, Evaluator, ExternalFnDef, Object, RuntimeErr};
+pub use crate::evaluator::{
+    Arguments, AssertionFailure, Backend, DEFAULT_MAX_FRAME_DEPTH, Environment, Evaluation, Evaluator, ExternalFnDef,
+    InterruptFn, ModuleSource, Object, RuntimeErr,
+};
 pub use crate::formatter::{format, is_formatted};
 pub use crate::lexer::{Lexer, Location, TokenKind};
 pub use crate::parser::{Parser, ParserErr, ast::ExpressionKind};
-pub use crate::runner::{AoCRunner, RunErr, RunEvaluation, Time};
+pub use crate::runner::{AoCRunner, DEFAULT_TEST_TIMEOUT, RunErr, RunEvaluation, Time};
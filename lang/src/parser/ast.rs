@@ -12,9 +12,16 @@ pub type Section = Program;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Attribute {
     pub name: String,
+    pub arguments: Vec<Expression>,
     pub source: Location,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeVariant {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub kind: StatementKind,
@@ -33,6 +40,20 @@ pub enum StatementKind {
         body: Box<Section>,
         attributes: Vec<Attribute>,
     },
+    Import {
+        path: String,
+        bindings: Option<Vec<String>>,
+    },
+    Type {
+        name: String,
+        variants: Vec<TypeVariant>,
+    },
+    // Attributes may also annotate a single-name `let` binding, recorded against the name once declared.
+    Attributed {
+        name: String,
+        attributes: Vec<Attribute>,
+        expression: Box<Expression>,
+    },
     Expression(Box<Expression>),
     Block(Vec<Statement>),
 }
@@ -69,6 +90,12 @@ pub enum Infix {
     Call(Box<Expression>),
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringPart {
+    Literal(String),
+    Expression(Expression),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Expression {
     pub kind: ExpressionKind,
@@ -79,6 +106,17 @@ pub struct Expression {
 pub enum ExpressionKind {
     Identifier(String),
     RestIdentifier(String),
+    // Produced by the resolver, addressing a variable `depth` environments out from where it is used,
+    // at the given `slot` within that environment's store.
+    ResolvedIdentifier {
+        name: String,
+        depth: usize,
+        slot: usize,
+    },
+    ResolvedRestIdentifier {
+        name: String,
+        slot: usize,
+    },
     Let {
         name: Box<Expression>,
         value: Box<Expression>,
@@ -135,6 +173,11 @@ pub enum ExpressionKind {
         name: Box<Expression>,
         value: Box<Expression>,
     },
+    CompoundAssign {
+        name: Box<Expression>,
+        operator: Infix,
+        value: Box<Expression>,
+    },
     FunctionThread {
         initial: Box<Expression>,
         functions: Vec<Expression>,
@@ -143,6 +186,7 @@ pub enum ExpressionKind {
     Integer(String),
     Decimal(String),
     String(String),
+    InterpolatedString(Vec<StringPart>),
     Boolean(bool),
     Spread(Box<Expression>),
     IdentifierListPattern(Vec<Expression>),
@@ -153,6 +197,28 @@ pub enum ExpressionKind {
         key: Box<Expression>,
         value: Box<Expression>,
     },
+    // Variants are matched by name alone when no fields are given, i.e. `Stop` as opposed to `Move(dx, dy)`.
+    // A name alone also matches any value of the type it names, be it declared (`Instr`) or built-in (`Integer`).
+    VariantMatchPattern {
+        name: String,
+        fields: Option<Vec<Expression>>,
+    },
+    OrMatchPattern(Vec<Expression>),
+    // Strings are matched upon a literal prefix and/or suffix, i.e. `"move " ++ rest`, binding what remains.
+    StringMatchPattern {
+        prefix: Option<String>,
+        remainder: Box<Expression>,
+        suffix: Option<String>,
+    },
+    // Regex patterns must match the string in its entirety, with each named group being bound as a variable.
+    RegexMatchPattern {
+        pattern: String,
+        captures: Vec<Expression>,
+    },
+    BindingMatchPattern {
+        name: Box<Expression>,
+        pattern: Box<Expression>,
+    },
     Placeholder,
     Nil,
 }
@@ -177,17 +243,27 @@ impl fmt::Display for StatementKind {
             Self::Break(value) => format!("break {};", value),
             Self::Comment(value) => format!("//{}", value),
             Self::Section { name, body, attributes } => {
-                let attrs = attributes
-                    .iter()
-                    .map(|a| format!("@{}", a.name))
-                    .collect::<Vec<_>>()
-                    .join(" ");
+                let attrs = attributes.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(" ");
                 if attrs.is_empty() {
                     format!("{}: {{{}}}", name, body)
                 } else {
                     format!("{} {}: {{{}}}", attrs, name, body)
                 }
             }
+            Self::Import { path, bindings } => match bindings {
+                Some(bindings) => format!("import {{ {} }} from \"{}\";", bindings.join(", "), path),
+                None => format!("import \"{}\";", path),
+            },
+            Self::Type { name, variants } => {
+                let formatted: Vec<String> = variants.iter().map(|variant| variant.to_string()).collect();
+                format!("type {} = {};", name, formatted.join(" | "))
+            }
+            Self::Attributed {
+                attributes, expression, ..
+            } => {
+                let attrs = attributes.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(" ");
+                format!("{} {}", attrs, expression)
+            }
             Self::Expression(expression) => format!("{}", expression),
             Self::Block(statements) => {
                 let formatted: Vec<String> = statements.iter().map(|statement| statement.to_string()).collect();
@@ -202,6 +278,27 @@ impl fmt::Display for StatementKind {
     }
 }
 
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.arguments.is_empty() {
+            write!(f, "@{}", self.name)
+        } else {
+            let formatted: Vec<String> = self.arguments.iter().map(|argument| argument.to_string()).collect();
+            write!(f, "@{}({})", self.name, formatted.join(", "))
+        }
+    }
+}
+
+impl fmt::Display for TypeVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.fields.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}({})", self.name, self.fields.join(", "))
+        }
+    }
+}
+
 impl fmt::Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.kind)
@@ -213,6 +310,8 @@ impl fmt::Display for ExpressionKind {
         let s = match self {
             Self::Identifier(name) => name.to_string(),
             Self::RestIdentifier(name) => format!("..{}", name),
+            Self::ResolvedIdentifier { name, .. } => name.to_string(),
+            Self::ResolvedRestIdentifier { name, .. } => format!("..{}", name),
             Self::Let { name, value } => format!("let {} = {};", name, value),
             Self::MutableLet { name, value } => format!("let mut {} = {};", name, value),
             Self::List(elements) => {
@@ -272,6 +371,7 @@ impl fmt::Display for ExpressionKind {
             Self::Prefix { operator, right } => format!("({}{})", operator, right),
             Self::Infix { left, operator, right } => format!("({} {} {})", left, operator, right),
             Self::Assign { name, value } => format!("({} = {})", name, value),
+            Self::CompoundAssign { name, operator, value } => format!("({} {}= {})", name, operator, value),
             Self::FunctionThread { initial, functions } => {
                 let formatted: Vec<String> = functions.iter().map(|function| function.to_string()).collect();
                 format!("({} |> {})", initial, formatted.join(" |> "))
@@ -283,6 +383,16 @@ impl fmt::Display for ExpressionKind {
             Self::Integer(value) => value.to_string(),
             Self::Decimal(value) => value.to_string(),
             Self::String(value) => value.to_string(),
+            Self::InterpolatedString(parts) => {
+                let formatted: Vec<String> = parts
+                    .iter()
+                    .map(|part| match part {
+                        StringPart::Literal(value) => value.to_string(),
+                        StringPart::Expression(expression) => format!("{{{}}}", expression),
+                    })
+                    .collect();
+                format!("$\"{}\"", formatted.join(""))
+            }
             Self::Boolean(value) => value.to_string(),
             Self::Spread(value) => format!("..{}", value),
             Self::IdentifierListPattern(pattern) => {
@@ -302,6 +412,33 @@ impl fmt::Display for ExpressionKind {
                 format!("#{{{}}}", formatted.join(", "))
             }
             Self::DictionaryEntryPattern { key, value } => format!("{}: {}", key, value),
+            Self::VariantMatchPattern { name, fields } => match fields {
+                Some(fields) => {
+                    let formatted: Vec<String> = fields.iter().map(|field| field.to_string()).collect();
+                    format!("{}({})", name, formatted.join(", "))
+                }
+                None => name.to_string(),
+            },
+            Self::OrMatchPattern(alternatives) => {
+                let formatted: Vec<String> = alternatives.iter().map(|alternative| alternative.to_string()).collect();
+                formatted.join(" | ")
+            }
+            Self::BindingMatchPattern { name, pattern } => format!("{} @ {}", name, pattern),
+            Self::StringMatchPattern {
+                prefix,
+                remainder,
+                suffix,
+            } => {
+                let mut formatted = remainder.to_string();
+                if let Some(prefix) = prefix {
+                    formatted = format!("\"{}\" ++ {}", prefix, formatted);
+                }
+                if let Some(suffix) = suffix {
+                    formatted = format!("{} ++ \"{}\"", formatted, suffix);
+                }
+                formatted
+            }
+            Self::RegexMatchPattern { pattern, .. } => format!("r\"{}\"", pattern),
             Self::Placeholder => "_".to_owned(),
             Self::Nil => "nil".to_owned(),
         };
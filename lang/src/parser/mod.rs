@@ -6,6 +6,8 @@ mod tests;
 use super::lexer::{Lexer, Location, Token, TokenKind};
 use crate::T;
 use ast::*;
+use regex::Regex;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 #[repr(u8)]
@@ -37,7 +39,7 @@ type RExpressions = Result<Vec<Expression>, ParserErr>;
 fn infix_binding_precedence(token: &TokenKind) -> Precedence {
     match token {
         T![&&] | T![||] => Precedence::AndOr,
-        T![==] | T![!=] | T![=] => Precedence::Equals,
+        T![==] | T![!=] | T![=] | T![+=] | T![-=] | T![*=] | T![/=] | T![%=] => Precedence::Equals,
         T![<] | T![<=] | T![>] | T![>=] => Precedence::LessGreater,
         T![>>] | T![|>] | T![..] | T![..=] => Precedence::Composition,
         T![+] | T![-] => Precedence::Sum,
@@ -48,15 +50,159 @@ fn infix_binding_precedence(token: &TokenKind) -> Precedence {
     }
 }
 
+// Interpolated strings additionally allow braces to be escaped, so they can appear literally.
+fn unescape_string(string: &str, source: Location, interpolated: bool) -> Result<String, ParserErr> {
+    let mut value = String::with_capacity(string.len());
+    let mut characters = string.chars();
+    while let Some(character) = characters.next() {
+        let unescaped = match character {
+            '\\' => match characters.next() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('b') => '\x08',
+                Some('f') => '\x0C',
+                Some('\\') => '\\',
+                Some('"') => '"',
+                Some(character @ ('{' | '}')) if interpolated => character,
+                Some(character) => {
+                    return Err(ParserErr {
+                        message: format!("Unexpected escape sequence: \\{character}"),
+                        source,
+                    });
+                }
+                None => {
+                    return Err(ParserErr {
+                        message: "Unexpected EOF within escape sequence".to_owned(),
+                        source,
+                    });
+                }
+            },
+            character => character,
+        };
+        value.push(unescaped);
+    }
+    Ok(value)
+}
+
+fn is_variant_name(name: &str) -> bool {
+    name.starts_with(|character: char| character.is_ascii_uppercase())
+}
+
+const TYPE_NAMES: [&str; 14] = [
+    "Nil",
+    "Integer",
+    "Decimal",
+    "Boolean",
+    "String",
+    "List",
+    "Set",
+    "Dictionary",
+    "PriorityQueue",
+    "Grid",
+    "LazySequence",
+    "UnboundedRange",
+    "BoundedRange",
+    "Function",
+];
+
+// Bare uppercase names within match patterns are only variant patterns when declared by a `type` statement,
+// imported by name, or a built-in type name; otherwise they bind the subject like any other identifier.
+// Variants brought in by a whole-module import are matched upon with their fields, i.e. `Stop()`.
+fn declared_variant_names(input: &str) -> HashSet<String> {
+    let mut names: HashSet<String> = TYPE_NAMES.iter().map(|name| name.to_string()).collect();
+    let lexer = Lexer::new(input);
+    let tokens: Vec<Token> = Lexer::new(input).collect();
+
+    let mut position = 0;
+    while position < tokens.len() {
+        let token = &tokens[position];
+        position += 1;
+
+        if token.kind == T![IMPORT] && tokens.get(position).is_some_and(|token| token.kind == T!['{']) {
+            while let Some(binding) = tokens.get(position).filter(|token| token.kind != T!['}']) {
+                if binding.kind == T![ID] {
+                    names.insert(lexer.get_source(binding).to_string());
+                }
+                position += 1;
+            }
+            continue;
+        }
+
+        let is_type_statement = token.kind == T![ID]
+            && lexer.get_source(token) == "type"
+            && tokens.get(position).is_some_and(|token| token.kind == T![ID])
+            && tokens.get(position + 1).is_some_and(|token| token.kind == T![=]);
+        if !is_type_statement {
+            continue;
+        }
+
+        names.insert(lexer.get_source(&tokens[position]).to_string());
+        position += 2;
+        if tokens.get(position).is_some_and(|token| token.kind == T![|]) {
+            position += 1;
+        }
+        while let Some(variant) = tokens.get(position).filter(|token| token.kind == T![ID]) {
+            names.insert(lexer.get_source(variant).to_string());
+            position += 1;
+            if tokens.get(position).is_some_and(|token| token.kind == T!['(']) {
+                while tokens.get(position).is_some_and(|token| token.kind != T![')']) {
+                    position += 1;
+                }
+                position += 1;
+            }
+            if !tokens.get(position).is_some_and(|token| token.kind == T![|]) {
+                break;
+            }
+            position += 1;
+        }
+    }
+
+    names
+}
+
+// Regex patterns must match the subject in its entirety, as opposed to just a portion of it.
+pub fn compile_regex_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!(r"\A(?:{})\z", pattern))
+}
+
+fn match_pattern_bindings(pattern: &Expression) -> Vec<&str> {
+    let mut bindings = match &pattern.kind {
+        ExpressionKind::Identifier(name) | ExpressionKind::RestIdentifier(name) => vec![name.as_str()],
+        ExpressionKind::ListMatchPattern(elements) | ExpressionKind::DictionaryMatchPattern(elements) => {
+            elements.iter().flat_map(match_pattern_bindings).collect()
+        }
+        ExpressionKind::DictionaryEntryPattern { value, .. } => match_pattern_bindings(value),
+        ExpressionKind::VariantMatchPattern { fields, .. } => {
+            fields.iter().flatten().flat_map(match_pattern_bindings).collect()
+        }
+        ExpressionKind::StringMatchPattern { remainder, .. } => match_pattern_bindings(remainder),
+        ExpressionKind::RegexMatchPattern { captures, .. } => {
+            captures.iter().flat_map(match_pattern_bindings).collect()
+        }
+        ExpressionKind::OrMatchPattern(alternatives) => match_pattern_bindings(&alternatives[0]),
+        ExpressionKind::BindingMatchPattern { name, pattern } => {
+            let mut bindings = match_pattern_bindings(pattern);
+            bindings.extend(match_pattern_bindings(name));
+            bindings
+        }
+        _ => vec![],
+    };
+    bindings.sort_unstable();
+    bindings
+}
+
 pub struct Parser<'a> {
     lexer: Lexer<'a>,
     current_token: Token,
     next_token: Token,
     prev_token_line: usize,
+    variant_names: HashSet<String>,
 }
 
 impl<'a> Parser<'a> {
     pub fn new(mut lexer: Lexer<'a>) -> Self {
+        let variant_names = declared_variant_names(lexer.input());
         let current_token = lexer.next_token();
         let next_token = lexer.next_token();
 
@@ -65,6 +211,7 @@ impl<'a> Parser<'a> {
             current_token,
             next_token,
             prev_token_line: 1,
+            variant_names,
         }
     }
 
@@ -133,20 +280,22 @@ impl<'a> Parser<'a> {
         let attributes = self.parse_attributes()?;
 
         match self.current_token.kind {
-            T![RETURN] | T![BREAK] | T![CMT] if !attributes.is_empty() => Err(ParserErr {
-                message: "Attributes can only be applied to sections".to_owned(),
+            T![RETURN] | T![BREAK] | T![CMT] | T![IMPORT] if !attributes.is_empty() => Err(ParserErr {
+                message: "Attributes can only be applied to sections and let bindings".to_owned(),
                 source: attributes[0].source,
             }),
             T![RETURN] => Ok(Some(self.parse_return_statement()?)),
             T![BREAK] => Ok(Some(self.parse_break_statement()?)),
             T![CMT] => Ok(Some(self.parse_comment_statement()?)),
+            T![IMPORT] => Ok(Some(self.parse_import_statement()?)),
             T![ID] if self.next_token.kind == T![:] => {
                 Ok(Some(self.parse_section_statement(attributes, preceded_by_blank_line)?))
             }
             T!['}'] | T![EOF] => {
                 if !attributes.is_empty() {
                     return Err(ParserErr {
-                        message: "Unexpected attribute - attributes can only be applied to sections".to_owned(),
+                        message: "Unexpected attribute - attributes can only be applied to sections and let bindings"
+                            .to_owned(),
                         source: attributes[0].source,
                     });
                 }
@@ -156,13 +305,19 @@ impl<'a> Parser<'a> {
                 source: self.current_token.source,
                 message: "Illegal token".to_owned(),
             }),
+            T![LET] if !attributes.is_empty() => Ok(Some(
+                self.parse_attributed_let_statement(attributes, preceded_by_blank_line)?,
+            )),
             _ => {
                 if !attributes.is_empty() {
                     return Err(ParserErr {
-                        message: "Attributes can only be applied to sections".to_owned(),
+                        message: "Attributes can only be applied to sections and let bindings".to_owned(),
                         source: attributes[0].source,
                     });
                 }
+                if self.is_type_statement() {
+                    return Ok(Some(self.parse_type_statement()?));
+                }
                 Ok(Some(self.parse_expression_statement()?))
             }
         }
@@ -176,9 +331,45 @@ impl<'a> Parser<'a> {
             let name_token = self.expect(T![ID])?;
             let name = self.lexer.get_source(&name_token).to_string();
 
+            if !self.consume_if(T!['(']) {
+                attributes.push(Attribute {
+                    name,
+                    arguments: vec![],
+                    source: start.source_range(&name_token),
+                });
+                continue;
+            }
+
+            let mut arguments = vec![];
+            while self.current_token.kind != T![')'] {
+                let argument = self.parse_expression(Precedence::Lowest)?;
+                if !matches!(
+                    argument.kind,
+                    ExpressionKind::Integer(_)
+                        | ExpressionKind::Decimal(_)
+                        | ExpressionKind::String(_)
+                        | ExpressionKind::Boolean(_)
+                        | ExpressionKind::Nil
+                ) {
+                    return Err(ParserErr {
+                        message: "Attribute arguments must be literal values".to_owned(),
+                        source: argument.source,
+                    });
+                }
+                arguments.push(argument);
+                if !self.consume_if(T![,]) {
+                    break;
+                }
+            }
+            let end = self.expect(T![')'])?;
+
             attributes.push(Attribute {
                 name,
-                source: start.source_range(&name_token),
+                arguments,
+                source: Location {
+                    start: start.source.start,
+                    end: end.source.end,
+                },
             });
         }
 
@@ -232,6 +423,109 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_import_statement(&mut self) -> RStatement {
+        let preceded_by_blank_line = self.current_token.preceded_by_blank_line;
+        let start = self.expect(T![IMPORT])?;
+
+        let bindings = if self.consume_if(T!['{']) {
+            let mut bindings = vec![];
+            while self.current_token.kind != T!['}'] {
+                let binding = self.expect(T![ID])?;
+                bindings.push(self.lexer.get_source(&binding).to_string());
+                if !self.consume_if(T![,]) {
+                    break;
+                }
+            }
+            self.expect(T!['}'])?;
+
+            let from = self.expect(T![ID])?;
+            if self.lexer.get_source(&from) != "from" {
+                return Err(ParserErr {
+                    message: format!("Expected: from, Actual: {}", self.lexer.get_source(&from)),
+                    source: from.source,
+                });
+            }
+
+            Some(bindings)
+        } else {
+            None
+        };
+
+        let path = match self.parse_string_expression()?.kind {
+            ExpressionKind::String(path) => path,
+            _ => unreachable!(),
+        };
+        let end_line = self.prev_token_line;
+        self.consume_if(T![;]);
+        let trailing_comment = self.consume_trailing_comment_on_line(end_line);
+
+        Ok(Statement {
+            kind: StatementKind::Import { path, bindings },
+            source: start.source_range(&self.current_token),
+            preceded_by_blank_line,
+            trailing_comment,
+        })
+    }
+
+    // `type` is not reserved, as it is also the name of a builtin function.
+    fn is_type_statement(&self) -> bool {
+        self.current_token.kind == T![ID]
+            && self.next_token.kind == T![ID]
+            && self.lexer.get_source(&self.current_token) == "type"
+    }
+
+    fn parse_type_statement(&mut self) -> RStatement {
+        let preceded_by_blank_line = self.current_token.preceded_by_blank_line;
+        let start = self.expect(T![ID])?;
+
+        let name = self.expect(T![ID])?;
+        let name = self.lexer.get_source(&name).to_string();
+        self.expect(T![=])?;
+
+        self.consume_if(T![|]);
+        let mut variants = vec![self.parse_type_variant()?];
+        while self.consume_if(T![|]) {
+            variants.push(self.parse_type_variant()?);
+        }
+
+        let end_line = self.prev_token_line;
+        self.consume_if(T![;]);
+        let trailing_comment = self.consume_trailing_comment_on_line(end_line);
+
+        Ok(Statement {
+            kind: StatementKind::Type { name, variants },
+            source: start.source_range(&self.current_token),
+            preceded_by_blank_line,
+            trailing_comment,
+        })
+    }
+
+    fn parse_type_variant(&mut self) -> Result<TypeVariant, ParserErr> {
+        let token = self.expect(T![ID])?;
+        let name = self.lexer.get_source(&token).to_string();
+
+        if !is_variant_name(&name) {
+            return Err(ParserErr {
+                message: format!("Variant names must begin with an uppercase letter, found: {}", name),
+                source: token.source,
+            });
+        }
+
+        let mut fields = vec![];
+        if self.consume_if(T!['(']) {
+            while self.current_token.kind != T![')'] {
+                let field = self.expect(T![ID])?;
+                fields.push(self.lexer.get_source(&field).to_string());
+                if !self.consume_if(T![,]) {
+                    break;
+                }
+            }
+            self.expect(T![')'])?;
+        }
+
+        Ok(TypeVariant { name, fields })
+    }
+
     fn parse_section_statement(&mut self, attributes: Vec<Attribute>, preceded_by_blank_line: bool) -> RStatement {
         let token = self.expect(T![ID])?;
         let name = self.lexer.get_source(&token).to_string();
@@ -257,6 +551,46 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_attributed_let_statement(
+        &mut self,
+        attributes: Vec<Attribute>,
+        preceded_by_blank_line: bool,
+    ) -> RStatement {
+        let statement = self.parse_expression_statement()?;
+        let StatementKind::Expression(expression) = statement.kind else {
+            unreachable!()
+        };
+
+        let name = match &expression.kind {
+            ExpressionKind::Let { name, .. } | ExpressionKind::MutableLet { name, .. } => match &name.kind {
+                ExpressionKind::Identifier(name) => name.to_owned(),
+                _ => {
+                    return Err(ParserErr {
+                        message: "Attributes can only be applied to let bindings of a single name".to_owned(),
+                        source: name.source,
+                    });
+                }
+            },
+            _ => {
+                return Err(ParserErr {
+                    message: "Attributes can only be applied to sections and let bindings".to_owned(),
+                    source: attributes[0].source,
+                });
+            }
+        };
+
+        Ok(Statement {
+            kind: StatementKind::Attributed {
+                name,
+                attributes,
+                expression,
+            },
+            source: statement.source,
+            preceded_by_blank_line,
+            trailing_comment: statement.trailing_comment,
+        })
+    }
+
     fn parse_expression_statement(&mut self) -> RStatement {
         let preceded_by_blank_line = self.current_token.preceded_by_blank_line;
         let start = self.current_token;
@@ -332,6 +666,7 @@ impl<'a> Parser<'a> {
             T![INT] => self.parse_integer_expression(),
             T![DEC] => self.parse_decimal_expression(),
             T![STR] => self.parse_string_expression(),
+            T![ISTR_START] => self.parse_interpolated_string_expression(),
             T![TRUE] | T![FALSE] => self.parse_booleon_expression(),
             T![NIL] => Ok(Expression {
                 kind: ExpressionKind::Nil,
@@ -379,7 +714,7 @@ impl<'a> Parser<'a> {
             T!['['] => Ok(Some(self.parse_index_expression(left)?)),
             T![..] => Ok(Some(self.parse_exclusive_range_expression(left)?)),
             T![..=] => Ok(Some(self.parse_inclusive_range_expression(left)?)),
-            T![=] => Ok(Some(self.parse_assignment_expression(left)?)),
+            T![=] | T![+=] | T![-=] | T![*=] | T![/=] | T![%=] => Ok(Some(self.parse_assignment_expression(left)?)),
             T![>>] => Ok(Some(self.parse_function_composition_expression(left)?)),
             T![|>] => Ok(Some(self.parse_function_threading_expression(left)?)),
             _ => Ok(None),
@@ -538,37 +873,7 @@ impl<'a> Parser<'a> {
         let token = self.expect(T![STR])?;
 
         let string = self.lexer.get_source(&token);
-        let unquoted = &string[1..string.len() - 1];
-
-        let mut value = String::with_capacity(unquoted.len());
-        let mut characters = unquoted.chars();
-        while let Some(character) = characters.next() {
-            let unescaped = match character {
-                '\\' => match characters.next() {
-                    Some('n') => '\n',
-                    Some('t') => '\t',
-                    Some('r') => '\r',
-                    Some('b') => '\x08',
-                    Some('f') => '\x0C',
-                    Some('\\') => '\\',
-                    Some('"') => '"',
-                    Some(character) => {
-                        return Err(ParserErr {
-                            message: format!("Unexpected escape sequence: \\{character}"),
-                            source: token.source,
-                        });
-                    }
-                    None => {
-                        return Err(ParserErr {
-                            message: "Unexpected EOF within escape sequence".to_owned(),
-                            source: token.source,
-                        });
-                    }
-                },
-                character => character,
-            };
-            value.push(unescaped);
-        }
+        let value = unescape_string(&string[1..string.len() - 1], token.source, false)?;
 
         Ok(Expression {
             kind: ExpressionKind::String(value),
@@ -576,6 +881,45 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_interpolated_string_expression(&mut self) -> RExpression {
+        let start = self.expect(T![ISTR_START])?;
+
+        let mut parts = vec![];
+        loop {
+            match self.current_token.kind {
+                T![ISTR_PART] => {
+                    let token = self.expect(T![ISTR_PART])?;
+                    let value = unescape_string(self.lexer.get_source(&token), token.source, true)?;
+                    parts.push(StringPart::Literal(value));
+                }
+                T!['{'] => {
+                    self.next_token();
+                    parts.push(StringPart::Expression(self.parse_expression(Precedence::Lowest)?));
+                    self.expect(T!['}'])?;
+                }
+                T![ISTR_END] => break,
+                _ => {
+                    return Err(ParserErr {
+                        message: format!(
+                            "Unexpected token within interpolated string: {:?}",
+                            self.current_token.kind
+                        ),
+                        source: self.current_token.source,
+                    });
+                }
+            }
+        }
+        let end = self.expect(T![ISTR_END])?;
+
+        Ok(Expression {
+            kind: ExpressionKind::InterpolatedString(parts),
+            source: Location {
+                start: start.source.start,
+                end: end.source.end,
+            },
+        })
+    }
+
     fn parse_booleon_expression(&mut self) -> RExpression {
         let token = self.current_token;
         self.next_token();
@@ -790,14 +1134,37 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_assignment_expression(&mut self, name: Expression) -> RExpression {
-        let start = self.expect(T![=])?;
+        let start = self.current_token;
+
+        let operator = match start.kind {
+            T![=] => None,
+            T![+=] => Some(Infix::Plus),
+            T![-=] => Some(Infix::Minus),
+            T![*=] => Some(Infix::Asterisk),
+            T![/=] => Some(Infix::Slash),
+            T![%=] => Some(Infix::Modulo),
+            _ => {
+                return Err(ParserErr {
+                    message: format!("{:?} is not a legal assignment operator", start.kind),
+                    source: start.source,
+                });
+            }
+        };
+        self.next_token();
 
         let value = Box::new(self.parse_expression(Precedence::Equals)?);
 
         Ok(Expression {
-            kind: ExpressionKind::Assign {
-                name: Box::new(name),
-                value,
+            kind: match operator {
+                Some(operator) => ExpressionKind::CompoundAssign {
+                    name: Box::new(name),
+                    operator,
+                    value,
+                },
+                None => ExpressionKind::Assign {
+                    name: Box::new(name),
+                    value,
+                },
             },
             source: start.source_range(&self.current_token),
         })
@@ -868,7 +1235,56 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_match_pattern(&mut self) -> RExpression {
+        if self.current_token.kind == T![ID] && self.next_token.kind == T![@] {
+            return self.parse_match_binding_pattern();
+        }
+
+        let start = self.current_token;
+        let pattern = self.parse_match_single_pattern()?;
+        if self.current_token.kind != T![|] {
+            return Ok(pattern);
+        }
+
+        let mut alternatives = vec![pattern];
+        while self.consume_if(T![|]) {
+            alternatives.push(self.parse_match_single_pattern()?);
+        }
+
+        let source = start.source_range(&self.current_token);
+        let bindings = match_pattern_bindings(&alternatives[0]);
+        if alternatives[1..]
+            .iter()
+            .any(|alternative| match_pattern_bindings(alternative) != bindings)
+        {
+            return Err(ParserErr {
+                message: "Alternatives within a match pattern must all bind the same variables".to_owned(),
+                source,
+            });
+        }
+
+        Ok(Expression {
+            kind: ExpressionKind::OrMatchPattern(alternatives),
+            source,
+        })
+    }
+
+    fn parse_match_binding_pattern(&mut self) -> RExpression {
+        let start = self.current_token;
+        let name = Box::new(self.parse_identifier_expression()?);
+        self.expect(T![@])?;
+        let pattern = Box::new(self.parse_match_pattern()?);
+
+        Ok(Expression {
+            kind: ExpressionKind::BindingMatchPattern { name, pattern },
+            source: start.source_range(&self.current_token),
+        })
+    }
+
+    fn parse_match_single_pattern(&mut self) -> RExpression {
         match self.current_token.kind {
+            T![STR] | T![ID] | T![_] if self.next_token.kind == T![++] => self.parse_match_string_pattern(),
+            T![REGEX] => self.parse_match_regex_pattern(),
+            T![ID] if self.is_variant_pattern() => self.parse_match_variant_pattern(),
             T![ID] => self.parse_identifier_expression(),
             T![INT] => self.parse_expression(Precedence::Lowest), // handles ranges as well
             T![DEC] => self.parse_decimal_expression(),
@@ -897,6 +1313,109 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_match_string_pattern(&mut self) -> RExpression {
+        let start = self.current_token;
+
+        let prefix = if self.current_token.kind == T![STR] {
+            let prefix = self.parse_string_literal()?;
+            self.expect(T![++])?;
+            Some(prefix)
+        } else {
+            None
+        };
+
+        let remainder = Box::new(match self.current_token.kind {
+            T![_] => Expression {
+                kind: ExpressionKind::Placeholder,
+                source: self.expect(T![_])?.source,
+            },
+            _ => self.parse_identifier_expression()?,
+        });
+
+        let suffix = if self.consume_if(T![++]) {
+            Some(self.parse_string_literal()?)
+        } else {
+            None
+        };
+
+        Ok(Expression {
+            kind: ExpressionKind::StringMatchPattern {
+                prefix,
+                remainder,
+                suffix,
+            },
+            source: start.source_range(&self.current_token),
+        })
+    }
+
+    fn parse_string_literal(&mut self) -> Result<String, ParserErr> {
+        match self.parse_string_expression()?.kind {
+            ExpressionKind::String(value) => Ok(value),
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_match_regex_pattern(&mut self) -> RExpression {
+        let token = self.expect(T![REGEX])?;
+        let source = self.lexer.get_source(&token);
+        let pattern = source[2..source.len() - 1].to_owned();
+
+        let Ok(regex) = compile_regex_pattern(&pattern) else {
+            return Err(ParserErr {
+                message: format!("Failed to compile regex pattern: {}", pattern),
+                source: token.source,
+            });
+        };
+
+        let captures = regex
+            .capture_names()
+            .flatten()
+            .map(|name| Expression {
+                kind: ExpressionKind::Identifier(name.to_owned()),
+                source: token.source,
+            })
+            .collect();
+
+        Ok(Expression {
+            kind: ExpressionKind::RegexMatchPattern { pattern, captures },
+            source: token.source,
+        })
+    }
+
+    fn is_variant_pattern(&self) -> bool {
+        let name = self.lexer.get_source(&self.current_token);
+        is_variant_name(name) && (self.next_token.kind == T!['('] || self.variant_names.contains(name))
+    }
+
+    fn parse_match_variant_pattern(&mut self) -> RExpression {
+        let start = self.expect(T![ID])?;
+        let name = self.lexer.get_source(&start).to_string();
+
+        if !self.consume_if(T!['(']) {
+            return Ok(Expression {
+                kind: ExpressionKind::VariantMatchPattern { name, fields: None },
+                source: start.source,
+            });
+        }
+
+        let mut fields = Vec::<Expression>::new();
+        while self.current_token.kind != T![')'] {
+            fields.push(self.parse_match_pattern()?);
+            if !self.consume_if(T![,]) {
+                break;
+            }
+        }
+        self.expect(T![')'])?;
+
+        Ok(Expression {
+            kind: ExpressionKind::VariantMatchPattern {
+                name,
+                fields: Some(fields),
+            },
+            source: start.source_range(&self.current_token),
+        })
+    }
+
     fn parse_match_list_pattern(&mut self) -> RExpression {
         let start = self.expect(T!['['])?;
 
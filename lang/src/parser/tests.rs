@@ -846,6 +846,94 @@ fn ranges() {
     );
 }
 
+#[test]
+fn compound_assignments() {
+    assert_ast(
+        r#"
+            x += 1;
+            grid[y][x] *= 2;
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: CompoundAssign {
+                                    name: Expression {
+                                        kind: Identifier(
+                                            "x",
+                                        ),
+                                        source: 0..1,
+                                    },
+                                    operator: Plus,
+                                    value: Expression {
+                                        kind: Integer(
+                                            "1",
+                                        ),
+                                        source: 5..6,
+                                    },
+                                },
+                                source: 2..6,
+                            },
+                        ),
+                        source: 0..20,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: CompoundAssign {
+                                    name: Expression {
+                                        kind: Index {
+                                            left: Expression {
+                                                kind: Index {
+                                                    left: Expression {
+                                                        kind: Identifier(
+                                                            "grid",
+                                                        ),
+                                                        source: 20..24,
+                                                    },
+                                                    index: Expression {
+                                                        kind: Identifier(
+                                                            "y",
+                                                        ),
+                                                        source: 25..26,
+                                                    },
+                                                },
+                                                source: 24..27,
+                                            },
+                                            index: Expression {
+                                                kind: Identifier(
+                                                    "x",
+                                                ),
+                                                source: 28..29,
+                                            },
+                                        },
+                                        source: 27..31,
+                                    },
+                                    operator: Asterisk,
+                                    value: Expression {
+                                        kind: Integer(
+                                            "2",
+                                        ),
+                                        source: 34..35,
+                                    },
+                                },
+                                source: 31..35,
+                            },
+                        ),
+                        source: 20..36,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..36,
+            }"#]],
+    );
+}
+
 #[test]
 fn let_assignments() {
     assert_ast(
@@ -3174,6 +3262,162 @@ fn sections() {
     );
 }
 
+#[test]
+fn sections_with_attributes() {
+    assert_ast(
+        r#"
+            @slow
+            @name("example", 2)
+            test: 1
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Section {
+                            name: "test",
+                            body: Program {
+                                statements: [
+                                    Statement {
+                                        kind: Expression(
+                                            Expression {
+                                                kind: Integer(
+                                                    "1",
+                                                ),
+                                                source: 56..57,
+                                            },
+                                        ),
+                                        source: 56..57,
+                                        preceded_by_blank_line: false,
+                                        trailing_comment: None,
+                                    },
+                                ],
+                                source: 50..57,
+                            },
+                            attributes: [
+                                Attribute {
+                                    name: "slow",
+                                    arguments: [],
+                                    source: 0..1,
+                                },
+                                Attribute {
+                                    name: "name",
+                                    arguments: [
+                                        Expression {
+                                            kind: String(
+                                                "example",
+                                            ),
+                                            source: 24..33,
+                                        },
+                                        Expression {
+                                            kind: Integer(
+                                                "2",
+                                            ),
+                                            source: 35..36,
+                                        },
+                                    ],
+                                    source: 18..37,
+                                },
+                            ],
+                        },
+                        source: 50..57,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..57,
+            }"#]],
+    );
+}
+
+#[test]
+fn invalid_attribute_argument() {
+    assert_error(
+        "@name(x) test: 1",
+        expect![[r#"
+            ParserErr {
+                message: "Attribute arguments must be literal values",
+                source: 6..7,
+            }"#]],
+    );
+}
+
+#[test]
+fn let_with_attributes() {
+    assert_ast(
+        "@timeout(5000) let mut x = 1;",
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Attributed {
+                            name: "x",
+                            attributes: [
+                                Attribute {
+                                    name: "timeout",
+                                    arguments: [
+                                        Expression {
+                                            kind: Integer(
+                                                "5000",
+                                            ),
+                                            source: 9..13,
+                                        },
+                                    ],
+                                    source: 0..14,
+                                },
+                            ],
+                            expression: Expression {
+                                kind: MutableLet {
+                                    name: Expression {
+                                        kind: Identifier(
+                                            "x",
+                                        ),
+                                        source: 23..24,
+                                    },
+                                    value: Expression {
+                                        kind: Integer(
+                                            "1",
+                                        ),
+                                        source: 27..28,
+                                    },
+                                },
+                                source: 15..28,
+                            },
+                        },
+                        source: 15..29,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..29,
+            }"#]],
+    );
+}
+
+#[test]
+fn attributes_on_let_with_pattern() {
+    assert_error(
+        "@memo let [a, b] = [1, 2]",
+        expect![[r#"
+            ParserErr {
+                message: "Attributes can only be applied to let bindings of a single name",
+                source: 6..17,
+            }"#]],
+    );
+}
+
+#[test]
+fn attributes_on_expression() {
+    assert_error(
+        "@memo 1 + 2",
+        expect![[r#"
+            ParserErr {
+                message: "Attributes can only be applied to sections and let bindings",
+                source: 0..1,
+            }"#]],
+    );
+}
+
 #[test]
 fn match_with_primitives() {
     assert_ast(
@@ -3990,7 +4234,7 @@ fn illegal_token() {
         "h@llo",
         expect![[r#"
             ParserErr {
-                message: "Unexpected attribute - attributes can only be applied to sections",
+                message: "Unexpected attribute - attributes can only be applied to sections and let bindings",
                 source: 1..2,
             }"#]],
     );
@@ -4448,6 +4692,637 @@ fn match_dictionary_pattern_with_entry() {
     );
 }
 
+#[test]
+fn interpolated_strings() {
+    assert_ast(
+        r#"
+            $"x={x}, size={size(xs)}\{}"
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: InterpolatedString(
+                                    [
+                                        Literal(
+                                            "x=",
+                                        ),
+                                        Expression(
+                                            Expression {
+                                                kind: Identifier(
+                                                    "x",
+                                                ),
+                                                source: 5..6,
+                                            },
+                                        ),
+                                        Literal(
+                                            ", size=",
+                                        ),
+                                        Expression(
+                                            Expression {
+                                                kind: Call {
+                                                    function: Expression {
+                                                        kind: Identifier(
+                                                            "size",
+                                                        ),
+                                                        source: 15..19,
+                                                    },
+                                                    arguments: [
+                                                        Expression {
+                                                            kind: Identifier(
+                                                                "xs",
+                                                            ),
+                                                            source: 20..22,
+                                                        },
+                                                    ],
+                                                },
+                                                source: 19..23,
+                                            },
+                                        ),
+                                        Literal(
+                                            "{}",
+                                        ),
+                                    ],
+                                ),
+                                source: 0..28,
+                            },
+                        ),
+                        source: 0..28,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..28,
+            }"#]],
+    );
+}
+
+#[test]
+fn unterminated_interpolated_string() {
+    assert_error(
+        r#"
+            $"x={x"
+        "#,
+        expect![[r#"
+            ParserErr {
+                message: "Expected: RBrace, Actual: Illegal",
+                source: 6..7,
+            }"#]],
+    );
+}
+
+#[test]
+fn import_statements() {
+    assert_ast(
+        r#"
+            import "lib/grid.santa";
+            import { neighbours, directions } from "lib/grid.santa";
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Import {
+                            path: "lib/grid.santa",
+                            bindings: None,
+                        },
+                        source: 0..37,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                    Statement {
+                        kind: Import {
+                            path: "lib/grid.santa",
+                            bindings: Some(
+                                [
+                                    "neighbours",
+                                    "directions",
+                                ],
+                            ),
+                        },
+                        source: 37..93,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..93,
+            }"#]],
+    );
+}
+
+#[test]
+fn import_without_from() {
+    assert_error(
+        r#"
+            import { neighbours } "lib/grid.santa";
+        "#,
+        expect![[r#"
+            ParserErr {
+                message: "Expected: Identifier, Actual: String",
+                source: 22..38,
+            }"#]],
+    );
+}
+
+#[test]
+fn type_statements() {
+    assert_ast(
+        r#"
+            type Instr = Move(dx, dy) | Stop;
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Type {
+                            name: "Instr",
+                            variants: [
+                                TypeVariant {
+                                    name: "Move",
+                                    fields: [
+                                        "dx",
+                                        "dy",
+                                    ],
+                                },
+                                TypeVariant {
+                                    name: "Stop",
+                                    fields: [],
+                                },
+                            ],
+                        },
+                        source: 0..33,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..33,
+            }"#]],
+    );
+}
+
+#[test]
+fn type_statement_with_lowercase_variant() {
+    assert_error(
+        r#"
+            type Instr = move(dx, dy);
+        "#,
+        expect![[r#"
+            ParserErr {
+                message: "Variant names must begin with an uppercase letter, found: move",
+                source: 13..17,
+            }"#]],
+    );
+}
+
+#[test]
+fn match_variant_patterns() {
+    assert_ast(
+        r#"
+            type Instr = Move(dx, dy) | Stop;
+            match instr { Move(x, [y]) { x } Stop { 0 } }
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Type {
+                            name: "Instr",
+                            variants: [
+                                TypeVariant {
+                                    name: "Move",
+                                    fields: [
+                                        "dx",
+                                        "dy",
+                                    ],
+                                },
+                                TypeVariant {
+                                    name: "Stop",
+                                    fields: [],
+                                },
+                            ],
+                        },
+                        source: 0..46,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: Match {
+                                    subject: Expression {
+                                        kind: Identifier(
+                                            "instr",
+                                        ),
+                                        source: 52..57,
+                                    },
+                                    cases: [
+                                        MatchCase {
+                                            pattern: Expression {
+                                                kind: VariantMatchPattern {
+                                                    name: "Move",
+                                                    fields: Some(
+                                                        [
+                                                            Expression {
+                                                                kind: Identifier(
+                                                                    "x",
+                                                                ),
+                                                                source: 65..66,
+                                                            },
+                                                            Expression {
+                                                                kind: ListMatchPattern(
+                                                                    [
+                                                                        Expression {
+                                                                            kind: Identifier(
+                                                                                "y",
+                                                                            ),
+                                                                            source: 69..70,
+                                                                        },
+                                                                    ],
+                                                                ),
+                                                                source: 68..71,
+                                                            },
+                                                        ],
+                                                    ),
+                                                },
+                                                source: 60..73,
+                                            },
+                                            guard: None,
+                                            consequence: Statement {
+                                                kind: Block(
+                                                    [
+                                                        Statement {
+                                                            kind: Expression(
+                                                                Expression {
+                                                                    kind: Identifier(
+                                                                        "x",
+                                                                    ),
+                                                                    source: 75..76,
+                                                                },
+                                                            ),
+                                                            source: 75..77,
+                                                            preceded_by_blank_line: false,
+                                                            trailing_comment: None,
+                                                        },
+                                                    ],
+                                                ),
+                                                source: 73..79,
+                                                preceded_by_blank_line: false,
+                                                trailing_comment: None,
+                                            },
+                                            trailing_comment: None,
+                                        },
+                                        MatchCase {
+                                            pattern: Expression {
+                                                kind: VariantMatchPattern {
+                                                    name: "Stop",
+                                                    fields: None,
+                                                },
+                                                source: 79..83,
+                                            },
+                                            guard: None,
+                                            consequence: Statement {
+                                                kind: Block(
+                                                    [
+                                                        Statement {
+                                                            kind: Expression(
+                                                                Expression {
+                                                                    kind: Integer(
+                                                                        "0",
+                                                                    ),
+                                                                    source: 86..87,
+                                                                },
+                                                            ),
+                                                            source: 86..88,
+                                                            preceded_by_blank_line: false,
+                                                            trailing_comment: None,
+                                                        },
+                                                    ],
+                                                ),
+                                                source: 84..90,
+                                                preceded_by_blank_line: false,
+                                                trailing_comment: None,
+                                            },
+                                            trailing_comment: None,
+                                        },
+                                    ],
+                                },
+                                source: 46..91,
+                            },
+                        ),
+                        source: 46..91,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..91,
+            }"#]],
+    );
+}
+
+#[test]
+fn match_undeclared_uppercase_name_binds_identifier() {
+    assert_ast(
+        r#"
+            match instr { Stop { 0 } }
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: Match {
+                                    subject: Expression {
+                                        kind: Identifier(
+                                            "instr",
+                                        ),
+                                        source: 6..11,
+                                    },
+                                    cases: [
+                                        MatchCase {
+                                            pattern: Expression {
+                                                kind: Identifier(
+                                                    "Stop",
+                                                ),
+                                                source: 14..18,
+                                            },
+                                            guard: None,
+                                            consequence: Statement {
+                                                kind: Block(
+                                                    [
+                                                        Statement {
+                                                            kind: Expression(
+                                                                Expression {
+                                                                    kind: Integer(
+                                                                        "0",
+                                                                    ),
+                                                                    source: 21..22,
+                                                                },
+                                                            ),
+                                                            source: 21..23,
+                                                            preceded_by_blank_line: false,
+                                                            trailing_comment: None,
+                                                        },
+                                                    ],
+                                                ),
+                                                source: 19..25,
+                                                preceded_by_blank_line: false,
+                                                trailing_comment: None,
+                                            },
+                                            trailing_comment: None,
+                                        },
+                                    ],
+                                },
+                                source: 0..26,
+                            },
+                        ),
+                        source: 0..26,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..26,
+            }"#]],
+    );
+}
+
+#[test]
+fn match_or_and_binding_patterns() {
+    assert_ast(
+        r#"
+            match x { d @ "L" | "R" { d } }
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: Match {
+                                    subject: Expression {
+                                        kind: Identifier(
+                                            "x",
+                                        ),
+                                        source: 6..7,
+                                    },
+                                    cases: [
+                                        MatchCase {
+                                            pattern: Expression {
+                                                kind: BindingMatchPattern {
+                                                    name: Expression {
+                                                        kind: Identifier(
+                                                            "d",
+                                                        ),
+                                                        source: 10..11,
+                                                    },
+                                                    pattern: Expression {
+                                                        kind: OrMatchPattern(
+                                                            [
+                                                                Expression {
+                                                                    kind: String(
+                                                                        "L",
+                                                                    ),
+                                                                    source: 14..17,
+                                                                },
+                                                                Expression {
+                                                                    kind: String(
+                                                                        "R",
+                                                                    ),
+                                                                    source: 20..23,
+                                                                },
+                                                            ],
+                                                        ),
+                                                        source: 14..24,
+                                                    },
+                                                },
+                                                source: 10..24,
+                                            },
+                                            guard: None,
+                                            consequence: Statement {
+                                                kind: Block(
+                                                    [
+                                                        Statement {
+                                                            kind: Expression(
+                                                                Expression {
+                                                                    kind: Identifier(
+                                                                        "d",
+                                                                    ),
+                                                                    source: 26..27,
+                                                                },
+                                                            ),
+                                                            source: 26..28,
+                                                            preceded_by_blank_line: false,
+                                                            trailing_comment: None,
+                                                        },
+                                                    ],
+                                                ),
+                                                source: 24..30,
+                                                preceded_by_blank_line: false,
+                                                trailing_comment: None,
+                                            },
+                                            trailing_comment: None,
+                                        },
+                                    ],
+                                },
+                                source: 0..31,
+                            },
+                        ),
+                        source: 0..31,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..31,
+            }"#]],
+    );
+}
+
+#[test]
+fn match_or_pattern_with_differing_bindings() {
+    assert_error(
+        r#"
+            match x { [a] | b { a } }
+        "#,
+        expect![[r#"
+            ParserErr {
+                message: "Alternatives within a match pattern must all bind the same variables",
+                source: 10..18,
+            }"#]],
+    );
+}
+
+#[test]
+fn match_string_and_regex_patterns() {
+    assert_ast(
+        r#"
+            match line { "move " ++ n { n } r"(?<dir>[LR])(\d+)" { dir } }
+        "#,
+        expect![[r#"
+            Program {
+                statements: [
+                    Statement {
+                        kind: Expression(
+                            Expression {
+                                kind: Match {
+                                    subject: Expression {
+                                        kind: Identifier(
+                                            "line",
+                                        ),
+                                        source: 6..10,
+                                    },
+                                    cases: [
+                                        MatchCase {
+                                            pattern: Expression {
+                                                kind: StringMatchPattern {
+                                                    prefix: Some(
+                                                        "move ",
+                                                    ),
+                                                    remainder: Expression {
+                                                        kind: Identifier(
+                                                            "n",
+                                                        ),
+                                                        source: 24..25,
+                                                    },
+                                                    suffix: None,
+                                                },
+                                                source: 13..26,
+                                            },
+                                            guard: None,
+                                            consequence: Statement {
+                                                kind: Block(
+                                                    [
+                                                        Statement {
+                                                            kind: Expression(
+                                                                Expression {
+                                                                    kind: Identifier(
+                                                                        "n",
+                                                                    ),
+                                                                    source: 28..29,
+                                                                },
+                                                            ),
+                                                            source: 28..30,
+                                                            preceded_by_blank_line: false,
+                                                            trailing_comment: None,
+                                                        },
+                                                    ],
+                                                ),
+                                                source: 26..32,
+                                                preceded_by_blank_line: false,
+                                                trailing_comment: None,
+                                            },
+                                            trailing_comment: None,
+                                        },
+                                        MatchCase {
+                                            pattern: Expression {
+                                                kind: RegexMatchPattern {
+                                                    pattern: "(?<dir>[LR])(\\d+)",
+                                                    captures: [
+                                                        Expression {
+                                                            kind: Identifier(
+                                                                "dir",
+                                                            ),
+                                                            source: 32..52,
+                                                        },
+                                                    ],
+                                                },
+                                                source: 32..52,
+                                            },
+                                            guard: None,
+                                            consequence: Statement {
+                                                kind: Block(
+                                                    [
+                                                        Statement {
+                                                            kind: Expression(
+                                                                Expression {
+                                                                    kind: Identifier(
+                                                                        "dir",
+                                                                    ),
+                                                                    source: 55..58,
+                                                                },
+                                                            ),
+                                                            source: 55..59,
+                                                            preceded_by_blank_line: false,
+                                                            trailing_comment: None,
+                                                        },
+                                                    ],
+                                                ),
+                                                source: 53..61,
+                                                preceded_by_blank_line: false,
+                                                trailing_comment: None,
+                                            },
+                                            trailing_comment: None,
+                                        },
+                                    ],
+                                },
+                                source: 0..62,
+                            },
+                        ),
+                        source: 0..62,
+                        preceded_by_blank_line: false,
+                        trailing_comment: None,
+                    },
+                ],
+                source: 0..62,
+            }"#]],
+    );
+}
+
+#[test]
+fn match_invalid_regex_pattern() {
+    assert_error(
+        r#"
+            match line { r"(?<dir>[LR]" { dir } }
+        "#,
+        expect![[r#"
+            ParserErr {
+                message: "Failed to compile regex pattern: (?<dir>[LR]",
+                source: 13..27,
+            }"#]],
+    );
+}
+
 fn assert_ast(input: &str, expected: Expect) {
     let mut parser = Parser::new(Lexer::new(input.trim()));
     let program = parser.parse();
@@ -1,17 +1,30 @@
-use crate::evaluator::{Environment, EnvironmentErr, EnvironmentRef, Evaluator, ExternalFnDef, Object, RuntimeErr};
+use crate::evaluator::{
+    AssertionFailure, Backend, Environment, EnvironmentErr, EnvironmentRef, Evaluator, ExternalFnDef, ModuleSource,
+    Object, RuntimeErr,
+};
 use crate::lexer::{Lexer, Location};
-use crate::parser::{Parser, ParserErr, ast::Section};
+use crate::parser::{
+    Parser, ParserErr,
+    ast::{Attribute, ExpressionKind, Section},
+};
 use std::rc::Rc;
 
 #[cfg(test)]
 mod tests;
 
+// Each test case (its body, input, expectations and the solution parts it exercises) shares a single deadline,
+// halting it once its timeout (in milliseconds) is exceeded. The timeout can be overridden for a given test using
+// the `@timeout(ms)` attribute, whilst a part section's `@timeout(ms)` instead gives that part its own deadline.
+// Tests marked `@slow` are expected to take a while, so are only timed out when they specify their own `@timeout(ms)`.
+pub const DEFAULT_TEST_TIMEOUT: u128 = 30_000;
+
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RunErr {
     pub message: String,
     pub source: Location,
     pub trace: Vec<Location>,
+    pub module: Option<ModuleSource>,
 }
 
 impl From<RuntimeErr> for RunErr {
@@ -20,6 +33,7 @@ impl From<RuntimeErr> for RunErr {
             message: error.message,
             source: error.source,
             trace: error.trace,
+            module: None,
         }
     }
 }
@@ -30,6 +44,7 @@ impl From<ParserErr> for RunErr {
             message: error.message,
             source: error.source,
             trace: vec![],
+            module: None,
         }
     }
 }
@@ -39,6 +54,7 @@ impl From<ParserErr> for RunErr {
 pub struct RunResult {
     pub value: String,
     pub duration: u128,
+    pub timed_out: bool,
 }
 
 #[derive(Debug)]
@@ -57,13 +73,20 @@ struct SourceEvaluation {
     result: Rc<Object>,
     part_one: Option<Rc<Section>>,
     part_two: Option<Rc<Section>>,
+    part_one_timeout: Option<u128>,
+    part_two_timeout: Option<u128>,
 }
 
 #[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TestCase {
+    pub number: usize,
+    pub name: Option<String>,
     pub part_one: Option<TestCaseResult>,
     pub part_two: Option<TestCaseResult>,
+    pub assertions: usize,
+    pub assertion: Option<AssertionFailure>,
+    pub timed_out: bool,
     pub slow: bool,
 }
 
@@ -73,33 +96,80 @@ pub struct TestCaseResult {
     pub expected: String,
     pub actual: String,
     pub passed: bool,
+    pub timed_out: bool,
 }
 
 pub struct AoCRunner<T: Time> {
     evaluator: Evaluator,
-    time: T,
+    time: Rc<T>,
+    test_filter: Option<String>,
+    test_timeout: Option<u128>,
 }
 
 pub trait Time {
     fn now(&self) -> u128;
 }
 
-impl<T: Time> AoCRunner<T> {
+impl<T: Time + 'static> AoCRunner<T> {
     pub fn new(time: T) -> Self {
         Self {
             evaluator: Evaluator::new(),
-            time,
+            time: Rc::new(time),
+            test_filter: None,
+            test_timeout: Some(DEFAULT_TEST_TIMEOUT),
         }
     }
 
     pub fn new_with_external_functions(time: T, external_functions: &[ExternalFnDef]) -> Self {
         Self {
             evaluator: Evaluator::new_with_external_functions(external_functions),
-            time,
+            time: Rc::new(time),
+            test_filter: None,
+            test_timeout: Some(DEFAULT_TEST_TIMEOUT),
         }
     }
 
+    pub fn set_max_frame_depth(&mut self, max_frame_depth: usize) {
+        self.evaluator.set_max_frame_depth(max_frame_depth);
+    }
+
+    pub fn set_max_steps(&mut self, max_steps: Option<u64>) {
+        self.evaluator.set_max_steps(max_steps);
+    }
+
+    pub fn set_backend(&mut self, backend: Backend) {
+        self.evaluator.set_backend(backend);
+    }
+
+    pub fn set_source_path(&mut self, path: &str) {
+        self.evaluator.set_source_path(path);
+    }
+
+    pub fn set_file_imports(&mut self, enabled: bool) {
+        self.evaluator.set_file_imports(enabled);
+    }
+
+    pub fn set_test_filter(&mut self, filter: &str) {
+        self.test_filter = Some(filter.to_owned());
+    }
+
+    pub fn set_test_timeout(&mut self, timeout: Option<u128>) {
+        self.test_timeout = timeout;
+    }
+
     pub fn run(&mut self, source: &str) -> Result<RunEvaluation, RunErr> {
+        self.evaluator.reset_steps();
+        let result = self.run_source(source);
+        self.with_failed_module(result)
+    }
+
+    pub fn test(&mut self, source: &str, include_slow: bool) -> Result<Vec<TestCase>, RunErr> {
+        self.evaluator.reset_steps();
+        let result = self.test_source(source, include_slow);
+        self.with_failed_module(result)
+    }
+
+    fn run_source(&mut self, source: &str) -> Result<RunEvaluation, RunErr> {
         let start = self.time.now();
 
         let evaluation = self.evaluate_source(source)?;
@@ -108,6 +178,7 @@ impl<T: Time> AoCRunner<T> {
             return Ok(RunEvaluation::Script(RunResult {
                 value: evaluation.result.to_string(),
                 duration: self.elapsed_millis(start),
+                timed_out: false,
             }));
         }
 
@@ -117,6 +188,7 @@ impl<T: Time> AoCRunner<T> {
                 message: "Expected a single 'input' section".to_owned(),
                 source: input[1].source,
                 trace: vec![],
+                module: None,
             });
         }
         let evaluated_input: Option<Rc<Object>> = if input.len() == 1 {
@@ -129,23 +201,23 @@ impl<T: Time> AoCRunner<T> {
         };
 
         let mut part_one_result: Option<RunResult> = None;
-        if let Some(part_one) = evaluation.part_one {
-            let start = self.time.now();
-            let value = self.evaluate_solution(&part_one, Rc::clone(&evaluation.environment), &evaluated_input)?;
-            part_one_result = Some(RunResult {
-                value: value.to_string(),
-                duration: self.elapsed_millis(start),
-            });
+        if let Some(part_one) = &evaluation.part_one {
+            part_one_result = Some(self.run_solution(
+                part_one,
+                Rc::clone(&evaluation.environment),
+                &evaluated_input,
+                evaluation.part_one_timeout,
+            )?);
         }
 
         let mut part_two_result: Option<RunResult> = None;
-        if let Some(part_two) = evaluation.part_two {
-            let start = self.time.now();
-            let value = self.evaluate_solution(&part_two, Rc::clone(&evaluation.environment), &evaluated_input)?;
-            part_two_result = Some(RunResult {
-                value: value.to_string(),
-                duration: self.elapsed_millis(start),
-            });
+        if let Some(part_two) = &evaluation.part_two {
+            part_two_result = Some(self.run_solution(
+                part_two,
+                Rc::clone(&evaluation.environment),
+                &evaluated_input,
+                evaluation.part_two_timeout,
+            )?);
         }
 
         Ok(RunEvaluation::Solution {
@@ -154,102 +226,254 @@ impl<T: Time> AoCRunner<T> {
         })
     }
 
-    pub fn test(&mut self, source: &str, include_slow: bool) -> Result<Vec<TestCase>, RunErr> {
+    fn test_source(&mut self, source: &str, include_slow: bool) -> Result<Vec<TestCase>, RunErr> {
         let evaluation = self.evaluate_source(source)?;
 
         let mut results = vec![];
 
-        for (test, attributes) in evaluation.environment.borrow().get_sections_with_attributes("test") {
+        // Test cases are numbered by their position within the source, with slow tests skipped unless explicitly
+        // requested prior to determining whether any of the remaining tests are focused upon.
+        let tests: Vec<_> = evaluation
+            .environment
+            .borrow()
+            .get_sections_with_attributes("test")
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (_, attributes))| include_slow || !Environment::section_has_attribute(attributes, "slow"))
+            .collect();
+        let is_focused = tests
+            .iter()
+            .any(|(_, (_, attributes))| Environment::section_has_attribute(attributes, "only"));
+
+        for (index, (test, attributes)) in tests {
             let is_slow = Environment::section_has_attribute(&attributes, "slow");
 
-            // Skip slow tests unless explicitly requested
-            if is_slow && !include_slow {
+            // When any test is marked with @only, all others are skipped
+            if Environment::section_has_attribute(&attributes, "skip")
+                || is_focused && !Environment::section_has_attribute(&attributes, "only")
+            {
                 continue;
             }
 
-            let test_environment = Environment::from(Rc::clone(&evaluation.environment));
-            let _test_result = self
-                .evaluator
-                .evaluate_with_environment(&test, Rc::clone(&test_environment))?;
+            let name = test_name(&attributes)?;
+            let timeout = attribute_timeout(&attributes)?.or(self.test_timeout.filter(|_| !is_slow));
+            let deadline = self.deadline(timeout);
+            if let Some(filter) = &self.test_filter {
+                if !name.as_ref().is_some_and(|name| name.contains(filter.as_str())) {
+                    continue;
+                }
+            }
 
-            let expected_part_one = test_environment.borrow().get_sections("part_one");
-            let expected_part_two = test_environment.borrow().get_sections("part_two");
+            let assertions = self.evaluator.assertions();
+            self.evaluator.take_assertion_failure();
+            let mut test_case = TestCase {
+                number: index + 1,
+                name,
+                part_one: None,
+                part_two: None,
+                assertions: 0,
+                assertion: None,
+                timed_out: false,
+                slow: is_slow,
+            };
 
-            if expected_part_one.is_empty() && expected_part_two.is_empty() {
-                continue;
-            }
+            // Failed assertions and timeouts only fail the test case they occur within, any other error is fatal.
+            let has_expectations = match self.evaluate_test_case(&evaluation, &test, deadline, &mut test_case) {
+                Ok(has_expectations) => has_expectations,
+                Err(error) => {
+                    if let Some(failure) = self.evaluator.take_assertion_failure() {
+                        test_case.assertion = Some(failure);
+                    } else if self.evaluator.take_interrupted() {
+                        test_case.timed_out = true;
+                    } else {
+                        return Err(error);
+                    }
+                    true
+                }
+            };
 
-            if expected_part_one.len() > 1 {
-                return Err(RunErr {
-                    message: "Expected a single 'part_one' assertion".to_owned(),
-                    source: expected_part_one[1].source,
-                    trace: vec![],
-                });
+            test_case.assertions = self.evaluator.assertions() - assertions;
+            if has_expectations || test_case.assertions > 0 {
+                results.push(test_case);
             }
+        }
 
-            if expected_part_two.len() > 1 {
-                return Err(RunErr {
-                    message: "Expected a single 'part_two' assertion".to_owned(),
-                    source: expected_part_two[1].source,
-                    trace: vec![],
-                });
-            }
+        Ok(results)
+    }
 
-            let input = test_environment.borrow().get_sections("input");
-            if input.len() > 1 {
-                return Err(RunErr {
-                    message: "Expected a single 'input' fixture".to_owned(),
-                    source: input[1].source,
-                    trace: vec![],
-                });
-            }
-            let evaluated_input: Option<Rc<Object>> = if input.len() == 1 {
-                Some(
-                    self.evaluator
-                        .evaluate_with_environment(&input[0].clone(), Rc::clone(&evaluation.environment))?,
-                )
-            } else {
-                None
-            };
+    fn evaluate_test_case(
+        &mut self,
+        source: &SourceEvaluation,
+        test: &Section,
+        deadline: Option<u128>,
+        test_case: &mut TestCase,
+    ) -> Result<bool, RunErr> {
+        let test_environment = Environment::from(Rc::clone(&source.environment));
+        self.with_deadline(deadline, |runner| {
+            runner
+                .evaluator
+                .evaluate_with_environment(test, Rc::clone(&test_environment))
+        })?;
+
+        let expected_part_one = test_environment.borrow().get_sections("part_one");
+        let expected_part_two = test_environment.borrow().get_sections("part_two");
+
+        if expected_part_one.is_empty() && expected_part_two.is_empty() {
+            return Ok(false);
+        }
+
+        if expected_part_one.len() > 1 {
+            return Err(RunErr {
+                message: "Expected a single 'part_one' assertion".to_owned(),
+                source: expected_part_one[1].source,
+                trace: vec![],
+                module: None,
+            });
+        }
 
-            let mut part_one_result: Option<TestCaseResult> = None;
-            if expected_part_one.len() == 1 {
-                if let Some(part_one) = &evaluation.part_one {
-                    let expected = self
+        if expected_part_two.len() > 1 {
+            return Err(RunErr {
+                message: "Expected a single 'part_two' assertion".to_owned(),
+                source: expected_part_two[1].source,
+                trace: vec![],
+                module: None,
+            });
+        }
+
+        let input = test_environment.borrow().get_sections("input");
+        if input.len() > 1 {
+            return Err(RunErr {
+                message: "Expected a single 'input' fixture".to_owned(),
+                source: input[1].source,
+                trace: vec![],
+                module: None,
+            });
+        }
+        let evaluated_input: Option<Rc<Object>> = if input.len() == 1 {
+            Some(self.with_deadline(deadline, |runner| {
+                runner
+                    .evaluator
+                    .evaluate_with_environment(&input[0].clone(), Rc::clone(&source.environment))
+            })?)
+        } else {
+            None
+        };
+
+        if expected_part_one.len() == 1 {
+            if let Some(part_one) = &source.part_one {
+                let expected = self.with_deadline(deadline, |runner| {
+                    runner
                         .evaluator
-                        .evaluate_with_environment(&expected_part_one[0], Rc::clone(&test_environment))?;
-                    let value = self.evaluate_solution(part_one, Rc::clone(&test_environment), &evaluated_input)?;
-                    part_one_result = Some(TestCaseResult {
-                        expected: expected.to_string(),
-                        actual: value.to_string(),
-                        passed: expected == value,
-                    });
-                }
+                        .evaluate_with_environment(&expected_part_one[0], Rc::clone(&test_environment))
+                })?;
+                let deadline = match source.part_one_timeout {
+                    Some(timeout) => self.deadline(Some(timeout)),
+                    None => deadline,
+                };
+                test_case.part_one = Some(self.evaluate_test_solution(
+                    part_one,
+                    Rc::clone(&test_environment),
+                    &evaluated_input,
+                    expected,
+                    deadline,
+                )?);
             }
+        }
 
-            let mut part_two_result: Option<TestCaseResult> = None;
-            if expected_part_two.len() == 1 {
-                if let Some(part_two) = &evaluation.part_two {
-                    let expected = self
+        if expected_part_two.len() == 1 {
+            if let Some(part_two) = &source.part_two {
+                let expected = self.with_deadline(deadline, |runner| {
+                    runner
                         .evaluator
-                        .evaluate_with_environment(&expected_part_two[0], Rc::clone(&test_environment))?;
-                    let value = self.evaluate_solution(part_two, Rc::clone(&test_environment), &evaluated_input)?;
-                    part_two_result = Some(TestCaseResult {
-                        expected: expected.to_string(),
-                        actual: value.to_string(),
-                        passed: expected == value,
-                    });
-                }
+                        .evaluate_with_environment(&expected_part_two[0], Rc::clone(&test_environment))
+                })?;
+                let deadline = match source.part_two_timeout {
+                    Some(timeout) => self.deadline(Some(timeout)),
+                    None => deadline,
+                };
+                test_case.part_two = Some(self.evaluate_test_solution(
+                    part_two,
+                    Rc::clone(&test_environment),
+                    &evaluated_input,
+                    expected,
+                    deadline,
+                )?);
             }
+        }
 
-            results.push(TestCase {
-                part_one: part_one_result,
-                part_two: part_two_result,
-                slow: is_slow,
-            });
+        Ok(true)
+    }
+
+    // A part's `@timeout(ms)` halts it once exceeded, reporting the part as having timed out.
+    fn run_solution(
+        &mut self,
+        section: &Section,
+        environment: EnvironmentRef,
+        input: &Option<Rc<Object>>,
+        timeout: Option<u128>,
+    ) -> Result<RunResult, RunErr> {
+        let start = self.time.now();
+        let deadline = self.deadline(timeout);
+        match self.with_deadline(deadline, |runner| runner.evaluate_solution(section, environment, input)) {
+            Ok(value) => Ok(RunResult {
+                value: value.to_string(),
+                duration: self.elapsed_millis(start),
+                timed_out: false,
+            }),
+            Err(_) if self.evaluator.take_interrupted() => Ok(RunResult {
+                value: String::new(),
+                duration: self.elapsed_millis(start),
+                timed_out: true,
+            }),
+            Err(error) => Err(error.into()),
         }
+    }
 
-        Ok(results)
+    fn evaluate_test_solution(
+        &mut self,
+        section: &Section,
+        environment: EnvironmentRef,
+        input: &Option<Rc<Object>>,
+        expected: Rc<Object>,
+        deadline: Option<u128>,
+    ) -> Result<TestCaseResult, RunErr> {
+        match self.with_deadline(deadline, |runner| runner.evaluate_solution(section, environment, input)) {
+            Ok(value) => Ok(TestCaseResult {
+                expected: expected.to_string(),
+                actual: value.to_string(),
+                passed: expected == value,
+                timed_out: false,
+            }),
+            Err(_) if self.evaluator.take_interrupted() => Ok(TestCaseResult {
+                expected: expected.to_string(),
+                actual: String::new(),
+                passed: false,
+                timed_out: true,
+            }),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    fn deadline(&self, timeout: Option<u128>) -> Option<u128> {
+        timeout.map(|timeout| self.time.now() + timeout)
+    }
+
+    fn with_deadline<V>(&mut self, deadline: Option<u128>, evaluate: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(deadline) = deadline {
+            let time = Rc::clone(&self.time);
+            self.evaluator
+                .set_interrupt(Some(Rc::new(move || time.now() >= deadline)));
+        }
+        let result = evaluate(self);
+        self.evaluator.set_interrupt(None);
+        result
+    }
+
+    fn with_failed_module<V>(&mut self, result: Result<V, RunErr>) -> Result<V, RunErr> {
+        result.map_err(|error| RunErr {
+            module: self.evaluator.take_failed_module(),
+            ..error
+        })
     }
 
     fn elapsed_millis(&self, start: u128) -> u128 {
@@ -293,38 +517,96 @@ impl<T: Time> AoCRunner<T> {
         let result = self
             .evaluator
             .evaluate_with_environment(&program, Rc::clone(&environment))?;
-        let part_one = environment.borrow().get_sections("part_one");
-        let part_two = environment.borrow().get_sections("part_two");
+        let part_one = environment.borrow().get_sections_with_attributes("part_one");
+        let part_two = environment.borrow().get_sections_with_attributes("part_two");
 
         if part_one.len() > 1 {
             return Err(RunErr {
                 message: "Expected single 'part_one' solution".to_owned(),
-                source: part_one[1].source,
+                source: part_one[1].0.source,
                 trace: vec![],
+                module: None,
             });
         }
 
         if part_two.len() > 1 {
             return Err(RunErr {
                 message: "Expected single 'part_two' solution".to_owned(),
-                source: part_two[1].source,
+                source: part_two[1].0.source,
                 trace: vec![],
+                module: None,
             });
         }
 
         Ok(SourceEvaluation {
             environment,
             result,
-            part_one: if part_one.len() == 1 {
-                Some(Rc::clone(&part_one[0]))
-            } else {
-                None
+            part_one: part_one.first().map(|(section, _)| Rc::clone(section)),
+            part_two: part_two.first().map(|(section, _)| Rc::clone(section)),
+            part_one_timeout: match part_one.first() {
+                Some((_, attributes)) => attribute_timeout(attributes)?,
+                None => None,
             },
-            part_two: if part_two.len() == 1 {
-                Some(Rc::clone(&part_two[0]))
-            } else {
-                None
+            part_two_timeout: match part_two.first() {
+                Some((_, attributes)) => attribute_timeout(attributes)?,
+                None => None,
             },
         })
     }
 }
+
+fn test_name(attributes: &[Attribute]) -> Result<Option<String>, RunErr> {
+    let Some(attribute) = Environment::section_attribute(attributes, "name") else {
+        return Ok(None);
+    };
+
+    match attribute.arguments.as_slice() {
+        [argument] => match &argument.kind {
+            ExpressionKind::String(name) => Ok(Some(name.to_owned())),
+            _ => Err(RunErr {
+                message: "Expected a string argument for '@name'".to_owned(),
+                source: argument.source,
+                trace: vec![],
+                module: None,
+            }),
+        },
+        _ => Err(RunErr {
+            message: "Expected a single argument for '@name'".to_owned(),
+            source: attribute.source,
+            trace: vec![],
+            module: None,
+        }),
+    }
+}
+
+fn attribute_timeout(attributes: &[Attribute]) -> Result<Option<u128>, RunErr> {
+    let Some(attribute) = Environment::section_attribute(attributes, "timeout") else {
+        return Ok(None);
+    };
+
+    match attribute.arguments.as_slice() {
+        [argument] => match &argument.kind {
+            ExpressionKind::Integer(timeout) => match timeout.replace('_', "").parse::<u128>() {
+                Ok(timeout) => Ok(Some(timeout)),
+                Err(_) => Err(RunErr {
+                    message: "Expected a millisecond timeout for '@timeout'".to_owned(),
+                    source: argument.source,
+                    trace: vec![],
+                    module: None,
+                }),
+            },
+            _ => Err(RunErr {
+                message: "Expected an integer argument for '@timeout'".to_owned(),
+                source: argument.source,
+                trace: vec![],
+                module: None,
+            }),
+        },
+        _ => Err(RunErr {
+            message: "Expected a single argument for '@timeout'".to_owned(),
+            source: attribute.source,
+            trace: vec![],
+            module: None,
+        }),
+    }
+}
@@ -16,6 +16,7 @@ fn script() {
                     RunResult {
                         value: "[2, 3, 4, 5]",
                         duration: 0,
+                        timed_out: false,
                     },
                 ),
             )"#]],
@@ -48,12 +49,14 @@ fn solution_with_both_parts() {
                         RunResult {
                             value: "-1",
                             duration: 0,
+                            timed_out: false,
                         },
                     ),
                     part_two: Some(
                         RunResult {
                             value: "5",
                             duration: 0,
+                            timed_out: false,
                         },
                     ),
                 },
@@ -80,6 +83,7 @@ fn solution_with_only_part_one() {
                         RunResult {
                             value: "-1",
                             duration: 0,
+                            timed_out: false,
                         },
                     ),
                     part_two: None,
@@ -109,6 +113,7 @@ fn solution_with_only_part_two() {
                         RunResult {
                             value: "5",
                             duration: 0,
+                            timed_out: false,
                         },
                     ),
                 },
@@ -143,11 +148,14 @@ fn passing_test_case_with_both_parts() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "-1",
                                 actual: "-1",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
                         part_two: Some(
@@ -155,8 +163,12 @@ fn passing_test_case_with_both_parts() {
                                 expected: "5",
                                 actual: "5",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: false,
                     },
                 ],
@@ -183,14 +195,20 @@ fn passing_test_case_with_part_one() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "-1",
                                 actual: "-1",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
                         part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: false,
                     },
                 ],
@@ -218,14 +236,20 @@ fn passing_test_case_with_part_two() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: None,
                         part_two: Some(
                             TestCaseResult {
                                 expected: "5",
                                 actual: "5",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: false,
                     },
                 ],
@@ -260,11 +284,14 @@ fn failing_test_case_with_both_parts() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "-2",
                                 actual: "-1",
                                 passed: false,
+                                timed_out: false,
                             },
                         ),
                         part_two: Some(
@@ -272,8 +299,12 @@ fn failing_test_case_with_both_parts() {
                                 expected: "6",
                                 actual: "5",
                                 passed: false,
+                                timed_out: false,
                             },
                         ),
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: false,
                     },
                 ],
@@ -291,6 +322,7 @@ fn script_with_runtime_error() {
                     message: "Unsupported operation: Integer * String",
                     source: 2..7,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -306,6 +338,7 @@ fn script_with_parser_error() {
                     message: "Illegal token",
                     source: 2..3,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -321,6 +354,7 @@ fn solution_with_runtime_error() {
                     message: "Unsupported operation: Integer * String",
                     source: 12..17,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -336,6 +370,7 @@ fn solution_with_parser_error() {
                     message: "Illegal token",
                     source: 12..13,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -356,6 +391,7 @@ fn solution_with_multiple_input_sections() {
                     message: "Expected a single 'input' section",
                     source: 35..57,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -376,6 +412,7 @@ fn solution_with_multiple_part_one_sections() {
                     message: "Expected single 'part_one' solution",
                     source: 60..85,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -396,6 +433,7 @@ fn solution_with_multiple_part_two_sections() {
                     message: "Expected single 'part_two' solution",
                     source: 85..106,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -421,6 +459,7 @@ fn test_with_multiple_input_sections() {
                     message: "Expected a single 'input' fixture",
                     source: 135..161,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -446,6 +485,7 @@ fn test_with_multiple_part_one_sections() {
                     message: "Expected a single 'part_one' assertion",
                     source: 164..193,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -471,6 +511,7 @@ fn test_with_multiple_part_two_sections() {
                     message: "Expected a single 'part_two' assertion",
                     source: 193..218,
                     trace: [],
+                    module: None,
                 },
             )"#]],
     )
@@ -483,6 +524,856 @@ impl Time for StubTime {
     }
 }
 
+#[test]
+fn test_case_with_passing_assertions() {
+    assert_test(
+        r#"
+            let double = |x| x * 2;
+
+            part_one: { input * 2 }
+
+            test: {
+                assert(double(1) == 2);
+                assert_eq(4, double(2));
+            }
+
+            test: {
+                input: 2
+                part_one: 4
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: None,
+                        part_one: None,
+                        part_two: None,
+                        assertions: 2,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                    TestCase {
+                        number: 2,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "4",
+                                actual: "4",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn test_case_with_failing_assertion() {
+    assert_test(
+        r#"
+            let double = |x| x * 2;
+
+            part_one: {
+                assert_eq(2, input);
+                double(input)
+            }
+
+            test: {
+                assert_eq(3, double(1));
+            }
+
+            test: {
+                input: 3
+                part_one: 6
+            }
+
+            test: {
+                input: 2
+                part_one: 4
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: None,
+                        part_one: None,
+                        part_two: None,
+                        assertions: 1,
+                        assertion: Some(
+                            AssertionFailure {
+                                expected: "3",
+                                actual: "2",
+                                source: 180..189,
+                                module: None,
+                            },
+                        ),
+                        timed_out: false,
+                        slow: false,
+                    },
+                    TestCase {
+                        number: 2,
+                        name: None,
+                        part_one: None,
+                        part_two: None,
+                        assertions: 1,
+                        assertion: Some(
+                            AssertionFailure {
+                                expected: "2",
+                                actual: "3",
+                                source: 78..87,
+                                module: None,
+                            },
+                        ),
+                        timed_out: false,
+                        slow: false,
+                    },
+                    TestCase {
+                        number: 3,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "4",
+                                actual: "4",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 1,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn test_case_with_failing_assertion_within_try() {
+    assert_test(
+        r#"
+            let check = |x| try(|| assert_eq(2, x));
+
+            test: {
+                check(3);
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: None,
+                        part_one: None,
+                        part_two: None,
+                        assertions: 1,
+                        assertion: Some(
+                            AssertionFailure {
+                                expected: "2",
+                                actual: "3",
+                                source: 36..45,
+                                module: None,
+                            },
+                        ),
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn test_case_expecting_an_undefined_solution() {
+    assert_test(
+        r#"
+            part_one: { input * 2 }
+
+            test: {
+                input: 2
+                part_two: 4
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: None,
+                        part_one: None,
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn test_case_error_after_an_earlier_failed_assertion() {
+    let mut runner = AoCRunner::new(StubTime {});
+    let _ = runner.run("assert(false)");
+    let result = runner.test(
+        r#"
+            test: {
+                error("Unrelated")
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Err(
+            RunErr {
+                message: "Unrelated",
+                source: 37..42,
+                trace: [
+                    37..42,
+                ],
+                module: None,
+            },
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_runtime_error() {
+    assert_test(
+        r#"
+            test: {
+                assert_eq(1, 1);
+                1 + "a";
+            }
+        "#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Unsupported operation: Integer + String",
+                    source: 72..77,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn named_test_cases() {
+    assert_test(
+        r#"
+            part_one: { input * 2 }
+
+            @name("doubles one")
+            test: {
+                input: 1
+                part_one: 2
+            }
+
+            test: {
+                input: 2
+                part_one: 4
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: Some(
+                            "doubles one",
+                        ),
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "2",
+                                actual: "2",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                    TestCase {
+                        number: 2,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "4",
+                                actual: "4",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn skipped_test_cases_are_not_run() {
+    assert_test(
+        r#"
+            part_one: { input * 2 }
+
+            @skip
+            test: {
+                input: 1
+                part_one: 3
+            }
+
+            test: {
+                input: 2
+                part_one: 4
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 2,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "4",
+                                actual: "4",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn only_focused_test_cases_are_run() {
+    assert_test(
+        r#"
+            part_one: { input * 2 }
+
+            test: {
+                input: 1
+                part_one: 3
+            }
+
+            @only
+            test: {
+                input: 2
+                part_one: 4
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 2,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "4",
+                                actual: "4",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn test_cases_are_filtered_by_name() {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_test_filter("two");
+    let result = runner.test(
+        r#"
+            part_one: { input * 2 }
+
+            @name("example one")
+            test: {
+                input: 1
+                part_one: 2
+            }
+
+            @name("example two")
+            test: {
+                input: 2
+                part_one: 4
+            }
+
+            test: {
+                input: 3
+                part_one: 6
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 2,
+                    name: Some(
+                        "example two",
+                    ),
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "4",
+                            actual: "4",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_invalid_name() {
+    assert_test(
+        r#"
+            @name(1)
+            test: {
+                assert(true);
+            }
+        "#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Expected a string argument for '@name'",
+                    source: 19..20,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
+// Each reading advances the clock by a millisecond, so timeouts elapse deterministically.
+#[derive(Default)]
+struct TickingTime {
+    now: std::cell::Cell<u128>,
+}
+impl Time for TickingTime {
+    fn now(&self) -> u128 {
+        self.now.set(self.now.get() + 1);
+        self.now.get()
+    }
+}
+
+#[test]
+fn test_case_with_timed_out_part() {
+    let result = AoCRunner::new(TickingTime::default()).test(
+        r#"
+            let spin = |n| spin(n + 1);
+
+            part_one: { input }
+
+            @timeout(5)
+            part_two: { spin(input) }
+
+            test: {
+                input: 1
+                part_one: 1
+                part_two: 2
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "1",
+                            actual: "1",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    part_two: Some(
+                        TestCaseResult {
+                            expected: "2",
+                            actual: "",
+                            passed: false,
+                            timed_out: true,
+                        },
+                    ),
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_timed_out_test() {
+    let mut runner = AoCRunner::new(TickingTime::default());
+    runner.set_test_timeout(None);
+    let result = runner.test(
+        r#"
+            let spin = |n| spin(n + 1);
+
+            part_one: { input }
+
+            @timeout(5)
+            test: {
+                try(|| spin(0));
+            }
+
+            test: {
+                input: 1
+                part_one: 1
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: None,
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: true,
+                    slow: false,
+                },
+                TestCase {
+                    number: 2,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "1",
+                            actual: "1",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn slow_test_case_is_not_given_the_test_timeout() {
+    let mut runner = AoCRunner::new(TickingTime::default());
+    runner.set_test_timeout(Some(10));
+    let result = runner.test(
+        r#"
+            let burn = |n| 1..n |> each(|_| nil);
+
+            part_one: { burn(input); input }
+
+            @slow
+            test: {
+                input: 100_000
+                part_one: 100_000
+            }
+
+            test: {
+                input: 100_000
+                part_one: 100_000
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "100000",
+                            actual: "100000",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: true,
+                },
+                TestCase {
+                    number: 2,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "100000",
+                            actual: "",
+                            passed: false,
+                            timed_out: true,
+                        },
+                    ),
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_shares_its_timeout_across_the_test_and_parts() {
+    let mut runner = AoCRunner::new(TickingTime::default());
+    runner.set_test_timeout(None);
+    let result = runner.test(
+        r#"
+            let burn = |n| 1..n |> each(|_| nil);
+
+            part_one: { burn(2_000); input }
+
+            @timeout(10)
+            part_two: { burn(2_000); input }
+
+            @timeout(6)
+            test: {
+                burn(2_000);
+                input: 1
+                part_one: 1
+                part_two: 1
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "1",
+                            actual: "",
+                            passed: false,
+                            timed_out: true,
+                        },
+                    ),
+                    part_two: Some(
+                        TestCaseResult {
+                            expected: "1",
+                            actual: "1",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_default_timeout() {
+    let mut runner = AoCRunner::new(TickingTime::default());
+    runner.set_test_timeout(Some(5));
+    let result = runner.test(
+        r#"
+            let spin = |n| spin(n + 1);
+
+            part_one: { spin(input) }
+
+            test: {
+                input: 1
+                part_one: 1
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "1",
+                            actual: "",
+                            passed: false,
+                            timed_out: true,
+                        },
+                    ),
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_timeout_on_bytecode_backend() {
+    let mut runner = AoCRunner::new(TickingTime::default());
+    runner.set_backend(Backend::Bytecode);
+    runner.set_test_timeout(Some(5));
+    let result = runner.test(
+        r#"
+            let spin = |n| spin(n + 1);
+
+            part_one: { spin(input) }
+
+            test: {
+                input: 1
+                part_one: 1
+            }
+        "#,
+        true,
+    );
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "1",
+                            actual: "",
+                            passed: false,
+                            timed_out: true,
+                        },
+                    ),
+                    part_two: None,
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn solution_with_timed_out_part() {
+    let result = AoCRunner::new(TickingTime::default()).run(
+        r#"
+            let spin = |n| spin(n + 1);
+
+            input: 1
+
+            @timeout(5)
+            part_one: { spin(input) }
+
+            part_two: { input + 1 }
+        "#,
+    );
+    expect![[r#"
+        Ok(
+            Solution {
+                part_one: Some(
+                    RunResult {
+                        value: "",
+                        duration: 7,
+                        timed_out: true,
+                    },
+                ),
+                part_two: Some(
+                    RunResult {
+                        value: "2",
+                        duration: 1,
+                        timed_out: false,
+                    },
+                ),
+            },
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_invalid_timeout() {
+    assert_test(
+        r#"
+            @timeout("5s")
+            test: {
+                assert(true);
+            }
+        "#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Expected an integer argument for '@timeout'",
+                    source: 22..26,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
 fn assert_run(input: &str, expected: Expect) {
     let result = AoCRunner::new(StubTime {}).run(input);
     let actual = format!("{:#?}", result);
@@ -495,6 +1386,20 @@ fn assert_test(input: &str, expected: Expect) {
     expected.assert_eq(&actual)
 }
 
+fn assert_run_with_file_imports(input: &str, expected: Expect) {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_file_imports(true);
+    let actual = format!("{:#?}", runner.run(input));
+    expected.assert_eq(&actual)
+}
+
+fn assert_test_with_file_imports(input: &str, expected: Expect) {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_file_imports(true);
+    let actual = format!("{:#?}", runner.test(input, true));
+    expected.assert_eq(&actual)
+}
+
 fn assert_test_with_slow(input: &str, include_slow: bool, expected: Expect) {
     let result = AoCRunner::new(StubTime {}).test(input, include_slow);
     let actual = format!("{:#?}", result);
@@ -518,14 +1423,20 @@ fn slow_test_is_included_when_flag_set() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "42",
                                 actual: "42",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
                         part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: true,
                     },
                 ],
@@ -553,6 +1464,50 @@ fn slow_test_is_skipped_by_default() {
     )
 }
 
+#[test]
+fn skipped_slow_test_does_not_focus_other_test_cases() {
+    assert_test_with_slow(
+        r#"
+            part_one: { input * 2 }
+
+            @slow
+            @only
+            test: {
+                input: 1
+                part_one: 2
+            }
+
+            test: {
+                input: 2
+                part_one: 4
+            }
+        "#,
+        false,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 2,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "4",
+                                actual: "4",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
 #[test]
 fn mixed_slow_and_normal_tests() {
     assert_test_with_slow(
@@ -575,14 +1530,20 @@ fn mixed_slow_and_normal_tests() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "42",
                                 actual: "42",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
                         part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: false,
                     },
                 ],
@@ -612,28 +1573,462 @@ fn mixed_slow_and_normal_tests_with_include_slow() {
             Ok(
                 [
                     TestCase {
+                        number: 1,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "42",
                                 actual: "42",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
                         part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: false,
                     },
                     TestCase {
+                        number: 2,
+                        name: None,
                         part_one: Some(
                             TestCaseResult {
                                 expected: "42",
                                 actual: "42",
                                 passed: true,
+                                timed_out: false,
                             },
                         ),
                         part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
                         slow: true,
                     },
                 ],
             )"#]],
     )
 }
+
+#[test]
+fn script_with_module_import() {
+    assert_run_with_file_imports(
+        r#"
+            import "src/runner/fixtures/lib/grid.santa";
+            neighbours([1, 1]) |> list;
+        "#,
+        expect![[r#"
+            Ok(
+                Script(
+                    RunResult {
+                        value: "[[0, 1], [1, 2], [2, 1], [1, 0]]",
+                        duration: 0,
+                        timed_out: false,
+                    },
+                ),
+            )"#]],
+    )
+}
+
+#[test]
+fn script_with_module_import_relative_to_source_path() {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_source_path("src/runner/fixtures/solution.santa");
+    runner.set_file_imports(true);
+    let result = runner.run(
+        r#"
+            import "lib/grid.santa";
+            neighbours([1, 1]) |> list;
+        "#,
+    );
+    expect![[r#"
+        Ok(
+            Script(
+                RunResult {
+                    value: "[[0, 1], [1, 2], [2, 1], [1, 0]]",
+                    duration: 0,
+                    timed_out: false,
+                },
+            ),
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn selective_module_import_only_exposes_named_bindings() {
+    assert_run_with_file_imports(
+        r#"
+            import { neighbours } from "src/runner/fixtures/lib/grid.santa";
+            input: [2, 2]
+            part_one: neighbours(input) |> size;
+            part_two: directions;
+        "#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Identifier can not be found: directions",
+                    source: 175..185,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn script_with_diamond_module_imports() {
+    assert_run_with_file_imports(
+        r#"
+            import "src/runner/fixtures/lib/grid.santa";
+            import "src/runner/fixtures/lib/path.santa";
+            import "src/runner/fixtures/lib/vector.santa";
+            import "src/runner/fixtures/lib/vector.santa";
+            translate(neighbours([1, 1]), [1, 1]) |> map(add([1, 1]));
+        "#,
+        expect![[r#"
+            Ok(
+                Script(
+                    RunResult {
+                        value: "[[2, 3], [3, 4], [4, 3], [3, 2]]",
+                        duration: 0,
+                        timed_out: false,
+                    },
+                ),
+            )"#]],
+    )
+}
+
+#[test]
+fn module_import_does_not_expose_the_modules_own_imports() {
+    assert_run_with_file_imports(
+        r#"
+            import "src/runner/fixtures/lib/grid.santa";
+            add([1, 1], [2, 2]);
+        "#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Identifier can not be found: add",
+                    source: 70..73,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn script_with_missing_module_binding() {
+    assert_run_with_file_imports(
+        r#"import { unknown } from "src/runner/fixtures/lib/grid.santa";"#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Module 'src/runner/fixtures/lib/grid.santa' does not define 'unknown'",
+                    source: 0..61,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn script_with_missing_module() {
+    assert_run_with_file_imports(
+        r#"import "src/runner/fixtures/lib/unknown.santa";"#,
+        expect![[r#"
+        Err(
+            RunErr {
+                message: "Unable to find module: src/runner/fixtures/lib/unknown.santa",
+                source: 0..47,
+                trace: [],
+                module: None,
+            },
+        )"#]],
+    )
+}
+
+#[test]
+fn script_with_module_import_cycle() {
+    assert_run_with_file_imports(
+        r#"import "src/runner/fixtures/lib/cycle_a.santa";"#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Import cycle detected: src/runner/fixtures/lib/cycle_a.santa -> src/runner/fixtures/lib/cycle_b.santa -> src/runner/fixtures/lib/cycle_a.santa",
+                    source: 0..25,
+                    trace: [],
+                    module: Some(
+                        ModuleSource {
+                            path: "src/runner/fixtures/lib/cycle_b.santa",
+                            source: "import \"cycle_a.santa\";\n\nlet b = 2;\n",
+                        },
+                    ),
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn script_with_module_runtime_error() {
+    assert_run_with_file_imports(
+        r#"import "src/runner/fixtures/lib/broken.santa";"#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Unsupported operation: Integer * String",
+                    source: 14..19,
+                    trace: [],
+                    module: Some(
+                        ModuleSource {
+                            path: "src/runner/fixtures/lib/broken.santa",
+                            source: "let value = 1 * \"1\";\n",
+                        },
+                    ),
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn script_with_module_import_without_file_imports() {
+    assert_run(
+        r#"import "src/runner/fixtures/lib/grid.santa";"#,
+        expect![[r#"
+            Err(
+                RunErr {
+                    message: "Unable to import module src/runner/fixtures/lib/grid.santa, file imports are not enabled",
+                    source: 0..44,
+                    trace: [],
+                    module: None,
+                },
+            )"#]],
+    )
+}
+
+#[test]
+fn test_with_failed_assertion_within_module() {
+    assert_test_with_file_imports(
+        r#"
+            test: {
+                import "src/runner/fixtures/lib/assertion.santa";
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: None,
+                        part_one: None,
+                        part_two: None,
+                        assertions: 1,
+                        assertion: Some(
+                            AssertionFailure {
+                                expected: "3",
+                                actual: "2",
+                                source: 0..9,
+                                module: Some(
+                                    ModuleSource {
+                                        path: "src/runner/fixtures/lib/assertion.santa",
+                                        source: "assert_eq(3, 1 + 1);\n",
+                                    },
+                                ),
+                            },
+                        ),
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn test_with_module_import() {
+    assert_test_with_file_imports(
+        r#"
+            import { add } from "src/runner/fixtures/lib/vector.santa";
+            part_one: add(input, [1, 1]);
+            test: {
+                input: [1, 2]
+                part_one: [2, 3]
+            }
+        "#,
+        expect![[r#"
+            Ok(
+                [
+                    TestCase {
+                        number: 1,
+                        name: None,
+                        part_one: Some(
+                            TestCaseResult {
+                                expected: "[2, 3]",
+                                actual: "[2, 3]",
+                                passed: true,
+                                timed_out: false,
+                            },
+                        ),
+                        part_two: None,
+                        assertions: 0,
+                        assertion: None,
+                        timed_out: false,
+                        slow: false,
+                    },
+                ],
+            )"#]],
+    )
+}
+
+#[test]
+fn solution_with_bytecode_backend() {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_backend(Backend::Bytecode);
+    let result = runner.run(
+        r#"
+            input: "()())"
+
+            part_one: {
+                input |> fold(0) |floor, direction| {
+                    if direction == "(" { floor + 1 } else { floor - 1 };
+                }
+            }
+
+            part_two: {
+                zip(1.., input) |> fold(0) |floor, [index, direction]| {
+                    let next_floor = if direction == "(" { floor + 1 } else { floor - 1 };
+                    if next_floor < 0 { break index } else { next_floor };
+                }
+            }
+        "#,
+    );
+
+    expect![[r#"
+        Ok(
+            Solution {
+                part_one: Some(
+                    RunResult {
+                        value: "-1",
+                        duration: 0,
+                        timed_out: false,
+                    },
+                ),
+                part_two: Some(
+                    RunResult {
+                        value: "5",
+                        duration: 0,
+                        timed_out: false,
+                    },
+                ),
+            },
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_case_with_bytecode_backend() {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_backend(Backend::Bytecode);
+    let result = runner.test(
+        r#"
+            let parse = |input| input |> lines |> map(int);
+
+            part_one: parse(input) |> sum;
+
+            part_two: {
+                let mut total = 0;
+                parse(input) |> each(|value| total += value * 2);
+                total
+            }
+
+            test: {
+                input: "1\n2\n3"
+                part_one: 6
+                part_two: 12
+            }
+        "#,
+        false,
+    );
+
+    expect![[r#"
+        Ok(
+            [
+                TestCase {
+                    number: 1,
+                    name: None,
+                    part_one: Some(
+                        TestCaseResult {
+                            expected: "6",
+                            actual: "6",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    part_two: Some(
+                        TestCaseResult {
+                            expected: "12",
+                            actual: "12",
+                            passed: true,
+                            timed_out: false,
+                        },
+                    ),
+                    assertions: 0,
+                    assertion: None,
+                    timed_out: false,
+                    slow: false,
+                },
+            ],
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn solution_exceeding_max_steps() {
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_max_steps(Some(100));
+    let result = runner.run(
+        r#"
+            part_one: {
+                1.. |> map(_ + 1) |> sum
+            }
+        "#,
+    );
+
+    expect![[r#"
+        Err(
+            RunErr {
+                message: "Maximum evaluation steps of 100 exceeded",
+                source: 62..65,
+                trace: [
+                    62..65,
+                ],
+                module: None,
+            },
+        )"#]]
+    .assert_eq(&format!("{:#?}", result));
+}
+
+#[test]
+fn test_cases_share_max_steps_within_a_run() {
+    let test_case = r#"
+        test: {
+            input: 1..=10
+            part_one: 65
+        }
+    "#;
+    let solution = format!("part_one: {{ input |> map(_ + 1) |> sum }}\n{}", test_case);
+    let mut runner = AoCRunner::new(StubTime {});
+    runner.set_max_steps(Some(40));
+
+    let passes = |result: Result<Vec<TestCase>, RunErr>| match result {
+        Ok(test_cases) => format!("{} passed", test_cases.len()),
+        Err(error) => error.message,
+    };
+
+    expect!["1 passed"].assert_eq(&passes(runner.test(&solution, false)));
+    expect!["1 passed"].assert_eq(&passes(runner.test(&solution, false)));
+    expect!["Maximum evaluation steps of 40 exceeded"]
+        .assert_eq(&passes(runner.test(&format!("{}{}", solution, test_case), false)));
+}
@@ -21,11 +21,11 @@ pub fn definitions() -> Vec<ExternalFnDef> {
     ]
 }
 
-fn puts(arguments: Arguments, _source: Location) -> Evaluation {
-    match &**arguments.get("values").unwrap() {
+fn puts(arguments: &Arguments, _source: Location) -> Evaluation {
+    match &*arguments[0] {
         Object::List(values) => {
             for value in values {
-                print!("{} ", value);
+                print!("{} ", value.pretty());
             }
             println!();
             Ok(Rc::new(Object::Nil))
@@ -34,8 +34,8 @@ fn puts(arguments: Arguments, _source: Location) -> Evaluation {
     }
 }
 
-fn read(arguments: Arguments, source: Location) -> Evaluation {
-    match &**arguments.get("path").unwrap() {
+fn read(arguments: &Arguments, source: Location) -> Evaluation {
+    match &*arguments[0] {
         Object::String(path) => match Url::parse(path) {
             Ok(uri) if uri.scheme() == "aoc" => {
                 let cache = format!(
@@ -7,7 +7,10 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 use getopts::Options;
 use rustyline::DefaultEditor;
-use santa_lang::{AoCRunner, Environment, Evaluator, Lexer, Location, Object, Parser, RunErr, RunEvaluation, Time};
+use santa_lang::{
+    AoCRunner, Backend, DEFAULT_TEST_TIMEOUT, Environment, Evaluator, Lexer, Location, Object, Parser, RunErr,
+    RunEvaluation, Time,
+};
 use std::fs;
 use std::io::Read;
 use std::rc::Rc;
@@ -25,7 +28,20 @@ fn main() -> Result<()> {
     opts.optopt("e", "eval", "evaluate inline script", "SCRIPT");
     opts.optflag("t", "test", "run the solution's test suite");
     opts.optflag("s", "slow", "include slow tests (marked with @slow)");
+    opts.optopt(
+        "",
+        "filter",
+        "only run tests whose @name contains the pattern",
+        "PATTERN",
+    );
+    opts.optopt(
+        "",
+        "timeout",
+        "halt each test once it exceeds the timeout (0 disables)",
+        "MS",
+    );
     opts.optflag("r", "repl", "begin an interactive REPL session");
+    opts.optflag("", "vm", "execute using the bytecode compiler and VM");
     opts.optflag("f", "fmt", "format source code to stdout");
     opts.optflag("", "fmt-write", "format source code in place");
     opts.optflag("", "fmt-check", "check if source is formatted (exit 1 if not)");
@@ -46,8 +62,14 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    let backend = if matches.opt_present("vm") {
+        Backend::Bytecode
+    } else {
+        Backend::TreeWalking
+    };
+
     if matches.opt_present("r") {
-        return repl();
+        return repl(backend);
     }
 
     // Handle formatting options
@@ -88,7 +110,16 @@ fn main() -> Result<()> {
 
     if matches.opt_present("t") {
         let include_slow = matches.opt_present("s");
-        return aoc_test(&source, source_path.as_deref(), include_slow);
+        let filter = matches.opt_str("filter");
+        let timeout = match matches.opt_str("timeout") {
+            Some(timeout) => match timeout.parse::<u128>() {
+                Ok(0) => None,
+                Ok(timeout) => Some(timeout),
+                Err(_) => return Err(format!("Expected a millisecond timeout, found: {}", timeout).into()),
+            },
+            None => Some(DEFAULT_TEST_TIMEOUT),
+        };
+        return aoc_test(&source, source_path.as_deref(), include_slow, filter, timeout, backend);
     }
 
     #[cfg(feature = "profile")]
@@ -104,7 +135,7 @@ fn main() -> Result<()> {
         None
     };
 
-    aoc_run(&source, source_path.as_deref())?;
+    aoc_run(&source, source_path.as_deref(), backend)?;
 
     #[cfg(feature = "profile")]
     if let Some(guard) = profiler {
@@ -148,6 +179,10 @@ USAGE:
     santa-cli -e <CODE>             Evaluate inline script
     santa-cli -t <SCRIPT>           Run test suite
     santa-cli -t -s <SCRIPT>        Run tests including @slow
+    santa-cli -t --filter <PATTERN> <SCRIPT>
+                                    Run tests with a matching @name
+    santa-cli -t --timeout <MS> <SCRIPT>
+                                    Run tests with a per-test timeout
     santa-cli -r                    Start REPL
     santa-cli -h                    Show this help
     cat file | santa-cli            Read from stdin
@@ -156,21 +191,25 @@ OPTIONS:
     -e, --eval <CODE>    Evaluate inline script
     -t, --test           Run the solution's test suite
     -s, --slow           Include @slow tests (use with -t)
+    --filter <PATTERN>   Only run tests whose @name contains PATTERN (use with -t)
+    --timeout <MS>       Halt each test after MS milliseconds, 0 disables (default: {}, use with -t)
     -r, --repl           Start interactive REPL
     -f, --fmt            Format source and print to stdout
     --fmt-write          Format source and write in place
     --fmt-check          Check if source is formatted
+    --vm                 Execute using the bytecode VM
     -p, --profile        Enable CPU profiling
     -h, --help           Show this help message
     -v, --version        Display version information
 
 ENVIRONMENT:
     SANTA_CLI_SESSION_TOKEN    AOC session token for aoc:// URLs",
-        env!("CARGO_PKG_VERSION")
+        env!("CARGO_PKG_VERSION"),
+        DEFAULT_TEST_TIMEOUT
     );
 }
 
-fn repl() -> Result<()> {
+fn repl(backend: Backend) -> Result<()> {
     let environment = Environment::new();
 
     let mut functions = crate::external_functions::definitions();
@@ -188,6 +227,8 @@ fn repl() -> Result<()> {
     ));
 
     let mut evaluator = Evaluator::new_with_external_functions(&functions);
+    evaluator.set_backend(backend);
+    evaluator.set_file_imports(true);
 
     println!(
         "   ,--.\n  ()   \\\n   /    \\\n _/______\\_\n(__________)\n(/  @  @  \\)\n(`._,()._,')  Santa REPL\n(  `-'`-'  )\n \\        /\n  \\,,,,,,/\n"
@@ -212,7 +253,7 @@ fn repl() -> Result<()> {
                 };
 
                 match evaluator.evaluate_with_environment(&program, Rc::clone(&environment)) {
-                    Ok(evaluated) => println!("{}", evaluated),
+                    Ok(evaluated) => println!("{}", evaluated.pretty()),
                     Err(error) => println!("{}", error.message),
                 };
             }
@@ -226,26 +267,53 @@ fn repl() -> Result<()> {
     Ok(())
 }
 
-fn aoc_run(source: &str, source_path: Option<&str>) -> Result<()> {
+fn aoc_run(source: &str, source_path: Option<&str>, backend: Backend) -> Result<()> {
     let mut runner = AoCRunner::new_with_external_functions(CliTime {}, &crate::external_functions::definitions());
+    runner.set_backend(backend);
+    runner.set_file_imports(true);
+    if let Some(source_path) = source_path {
+        runner.set_source_path(source_path);
+    }
     match runner.run(source) {
         Ok(RunEvaluation::Script(result)) => {
             println!("{}", result.value);
             Ok(())
         }
         Ok(RunEvaluation::Solution { part_one, part_two }) => {
+            let mut exit_code = 0;
+
             if let Some(part_one) = part_one {
-                println!(
-                    "Part 1: \x1b[32m{}\x1b[0m \x1b[90m{}ms\x1b[0m",
-                    part_one.value, part_one.duration
-                )
+                if part_one.timed_out {
+                    println!(
+                        "Part 1: \x1b[31m✘ Timed out\x1b[0m \x1b[90m{}ms\x1b[0m",
+                        part_one.duration
+                    );
+                    exit_code = 2;
+                } else {
+                    println!(
+                        "Part 1: \x1b[32m{}\x1b[0m \x1b[90m{}ms\x1b[0m",
+                        part_one.value, part_one.duration
+                    )
+                }
             }
 
             if let Some(part_two) = part_two {
-                println!(
-                    "Part 2: \x1b[32m{}\x1b[0m \x1b[90m{}ms\x1b[0m",
-                    part_two.value, part_two.duration
-                )
+                if part_two.timed_out {
+                    println!(
+                        "Part 2: \x1b[31m✘ Timed out\x1b[0m \x1b[90m{}ms\x1b[0m",
+                        part_two.duration
+                    );
+                    exit_code = 2;
+                } else {
+                    println!(
+                        "Part 2: \x1b[32m{}\x1b[0m \x1b[90m{}ms\x1b[0m",
+                        part_two.value, part_two.duration
+                    )
+                }
+            }
+
+            if exit_code != 0 {
+                std::process::exit(exit_code);
             }
 
             Ok(())
@@ -257,30 +325,48 @@ fn aoc_run(source: &str, source_path: Option<&str>) -> Result<()> {
     }
 }
 
-fn aoc_test(source: &str, source_path: Option<&str>, include_slow: bool) -> Result<()> {
+fn aoc_test(
+    source: &str,
+    source_path: Option<&str>,
+    include_slow: bool,
+    filter: Option<String>,
+    timeout: Option<u128>,
+    backend: Backend,
+) -> Result<()> {
     let mut runner = AoCRunner::new_with_external_functions(CliTime {}, &crate::external_functions::definitions());
+    runner.set_backend(backend);
+    runner.set_file_imports(true);
+    runner.set_test_timeout(timeout);
+    if let Some(source_path) = source_path {
+        runner.set_source_path(source_path);
+    }
+    if let Some(filter) = filter {
+        runner.set_test_filter(&filter);
+    }
     match runner.test(source, include_slow) {
         Ok(test_cases) => {
             let mut exit_code = 0;
 
-            for (number, test_case) in test_cases.iter().enumerate() {
-                if number > 0 {
+            for (index, test_case) in test_cases.iter().enumerate() {
+                if index > 0 {
                     println!()
                 }
+                let title = match &test_case.name {
+                    Some(name) => format!("Testcase #{}: {}", test_case.number, name),
+                    None => format!("Testcase #{}", test_case.number),
+                };
                 if test_case.slow {
-                    println!("\x1b[4mTestcase #{}\x1b[0m \x1b[33m(slow)\x1b[0m", number + 1);
+                    println!("\x1b[4m{}\x1b[0m \x1b[33m(slow)\x1b[0m", title);
                 } else {
-                    println!("\x1b[4mTestcase #{}\x1b[0m", number + 1);
-                }
-
-                if test_case.part_one.is_none() && test_case.part_two.is_none() {
-                    println!("No expectations");
-                    continue;
+                    println!("\x1b[4m{}\x1b[0m", title);
                 }
 
                 if let Some(part_one) = &test_case.part_one {
                     if part_one.passed {
                         println!("Part 1: {} \x1b[32m✔\x1b[0m", part_one.actual);
+                    } else if part_one.timed_out {
+                        println!("Part 1: \x1b[31m✘ (Timed out, Expected: {})\x1b[0m", part_one.expected);
+                        exit_code = 3;
                     } else {
                         println!(
                             "Part 1: {} \x1b[31m✘ (Expected: {})\x1b[0m",
@@ -293,6 +379,9 @@ fn aoc_test(source: &str, source_path: Option<&str>, include_slow: bool) -> Resu
                 if let Some(part_two) = &test_case.part_two {
                     if part_two.passed {
                         println!("Part 2: {} \x1b[32m✔\x1b[0m", part_two.actual);
+                    } else if part_two.timed_out {
+                        println!("Part 2: \x1b[31m✘ (Timed out, Expected: {})\x1b[0m", part_two.expected);
+                        exit_code = 3;
                     } else {
                         println!(
                             "Part 2: {} \x1b[31m✘ (Expected: {})\x1b[0m",
@@ -301,6 +390,34 @@ fn aoc_test(source: &str, source_path: Option<&str>, include_slow: bool) -> Resu
                         exit_code = 3;
                     }
                 }
+
+                if let Some(assertion) = &test_case.assertion {
+                    // Assertions which fail within an imported module are located within that module's source.
+                    let location = match &assertion.module {
+                        Some(module) => {
+                            let (line, column) = calculate_line_column(&module.source, assertion.source);
+                            format!("{}:{}:{}", module.path, line + 1, column + 1)
+                        }
+                        None => {
+                            let (line, column) = calculate_line_column(source, assertion.source);
+                            format!("{}:{}", line + 1, column + 1)
+                        }
+                    };
+                    println!(
+                        "Assertion: {} \x1b[31m✘ (Expected: {})\x1b[0m \x1b[90m{}\x1b[0m",
+                        assertion.actual, assertion.expected, location
+                    );
+                    exit_code = 3;
+                } else if test_case.timed_out {
+                    println!("\x1b[31m✘ Timed out\x1b[0m");
+                    exit_code = 3;
+                } else if test_case.part_one.is_none() && test_case.part_two.is_none() {
+                    if test_case.assertions > 0 {
+                        println!("Assertions \x1b[32m✔\x1b[0m");
+                    } else {
+                        println!("No expectations");
+                    }
+                }
             }
 
             if exit_code != 0 {
@@ -370,6 +487,11 @@ fn handle_format(matches: &getopts::Matches, to_stdout: bool, write_file: bool,
 }
 
 fn print_error(source_path: &str, source: &str, error: RunErr) {
+    let (source_path, source) = match &error.module {
+        Some(module) => (module.path.as_str(), module.source.as_str()),
+        None => (source_path, source),
+    };
+
     let (line, column) = calculate_line_column(source, error.source);
 
     println!("\x1b[31m{}\x1b[0m\n", error.message);
@@ -393,17 +515,27 @@ fn print_error(source_path: &str, source: &str, error: RunErr) {
     println!("\n{}:\x1b[32m{}:{}\x1b[0m", source_path, line + 1, column + 1);
 
     if !error.trace.is_empty() {
-        for location in error.trace {
-            let (line, column) = calculate_line_column(source, location);
+        // Runaway recursion produces long runs of the same call site, so these are collapsed.
+        let mut locations = error.trace.iter().peekable();
+        while let Some(location) = locations.next() {
+            let mut repeated = 0;
+            while locations.next_if_eq(&location).is_some() {
+                repeated += 1;
+            }
+
+            let Some(snippet) = source.get(location.start..location.end) else {
+                continue;
+            };
+            let (line, column) = calculate_line_column(source, *location);
             println!(
                 "  \x1b[2m{}:\x1b[0m\x1b[32m{}:{}\x1b[0m",
-                &source[location.start..location.end]
-                    .split_whitespace()
-                    .collect::<Vec<_>>()
-                    .join(" "),
+                snippet.split_whitespace().collect::<Vec<_>>().join(" "),
                 line + 1,
                 column + 1
             );
+            if repeated > 0 {
+                println!("  \x1b[2m... repeated {} more times\x1b[0m", repeated);
+            }
         }
     }
 }
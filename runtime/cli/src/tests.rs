@@ -39,6 +39,180 @@ fn test_solution() {
         .stdout(predicate::str::contains("Part 2: 5 \u{1b}[32m✔\u{1b}[0m"));
 }
 
+#[test]
+fn test_solution_with_assertions() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("-t")
+        .arg(format!("{}/fixtures/assertions.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .code(3)
+        .stdout(predicate::str::contains(
+            "Testcase #1\u{1b}[0m\nAssertions \u{1b}[32m✔\u{1b}[0m",
+        ))
+        .stdout(predicate::str::contains(
+            "Assertion: 1 \u{1b}[31m✘ (Expected: 2)\u{1b}[0m \u{1b}[90m13:3\u{1b}[0m",
+        ))
+        .stdout(predicate::str::contains("Part 1: 1 \u{1b}[32m✔\u{1b}[0m"))
+        .stdout(predicate::str::contains("Testcase #4\u{1b}[0m\nNo expectations"));
+}
+
+#[test]
+fn test_solution_with_named_tests() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("-t")
+        .arg(format!("{}/fixtures/named_tests.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .success()
+        .stdout(predicate::str::contains("Testcase #1: example one"))
+        .stdout(predicate::str::contains("Testcase #2: example two"))
+        .stdout(predicate::str::contains("Testcase #3").not());
+}
+
+#[test]
+fn test_solution_with_filter() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("-t")
+        .arg("--filter")
+        .arg("two")
+        .arg(format!("{}/fixtures/named_tests.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .success()
+        .stdout(predicate::str::contains("Testcase #2: example two"))
+        .stdout(predicate::str::contains("example one").not());
+}
+
+#[test]
+fn solution_with_timeout() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg(format!("{}/fixtures/timeout.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .code(2)
+        .stdout(predicate::str::contains("Part 1: \u{1b}[32m3\u{1b}[0m"))
+        .stdout(predicate::str::contains("Part 2: \u{1b}[31m✘ Timed out\u{1b}[0m"));
+}
+
+#[test]
+fn test_solution_with_timeouts() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("-t")
+        .arg(format!("{}/fixtures/timeout.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .code(3)
+        .stdout(predicate::str::contains("Part 1: 3 \u{1b}[32m✔\u{1b}[0m"))
+        .stdout(predicate::str::contains(
+            "Part 2: \u{1b}[31m✘ (Timed out, Expected: 0)\u{1b}[0m",
+        ))
+        .stdout(predicate::str::contains(
+            "Testcase #2\u{1b}[0m\n\u{1b}[31m✘ Timed out\u{1b}[0m",
+        ));
+}
+
+#[test]
+fn test_solution_with_invalid_timeout() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("-t")
+        .arg("--timeout")
+        .arg("soon")
+        .arg(format!("{}/fixtures/timeout.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .failure()
+        .stderr(predicate::str::contains("Expected a millisecond timeout, found: soon"));
+}
+
+#[test]
+fn solution_with_vm() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("--vm")
+        .arg(format!("{}/fixtures/solution.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .success()
+        .stdout(predicate::str::contains("Part 1: \u{1b}[32m232\u{1b}[0m"))
+        .stdout(predicate::str::contains("Part 2: \u{1b}[32m1783\u{1b}[0m"));
+}
+
+#[test]
+fn test_solution_with_vm() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg("--vm")
+        .arg("-t")
+        .arg(format!("{}/fixtures/solution.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .success()
+        .stdout(predicate::str::contains("Part 1: -1 \u{1b}[32m✔\u{1b}[0m"))
+        .stdout(predicate::str::contains("Part 2: 5 \u{1b}[32m✔\u{1b}[0m"));
+}
+
+#[test]
+fn script_with_module_import() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg(format!("{}/fixtures/module.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert.success().stdout("4\n");
+}
+
+#[test]
+fn script_with_module_error() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg(format!("{}/fixtures/module_error.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .failure()
+        .stdout(predicate::str::contains("Unsupported operation: Integer * String"))
+        .stdout(predicate::str::contains("let value = 1 * \"1\";"))
+        .stdout(predicate::str::contains("lib/broken.santa:"));
+}
+
+#[test]
+fn script_with_deep_recursion() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg(format!("{}/fixtures/deep_recursion.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert.success().stdout("12502500\n");
+}
+
+#[test]
+fn script_with_runaway_recursion() {
+    #[allow(deprecated)]
+    let mut cmd = Command::cargo_bin("santa-cli").unwrap();
+    let assert = cmd
+        .arg(format!("{}/fixtures/recursion_limit.santa", env!("CARGO_MANIFEST_DIR")))
+        .assert();
+    assert
+        .failure()
+        .stdout(predicate::str::contains("Maximum frame depth of 20000 exceeded"))
+        .stdout(predicate::str::contains("repeated"));
+}
+
 #[test]
 fn repl() {
     #[allow(deprecated)]
@@ -19,11 +19,11 @@ pub fn definitions() -> Vec<ExternalFnDef> {
     ]
 }
 
-fn puts(arguments: Arguments, _source: Location) -> Evaluation {
-    match &**arguments.get("values").unwrap() {
+fn puts(arguments: &Arguments, _source: Location) -> Evaluation {
+    match &*arguments[0] {
         Object::List(values) => {
             for value in values {
-                print!("{} ", value);
+                print!("{} ", value.pretty());
             }
             println!();
             Ok(Rc::new(Object::Nil))
@@ -32,8 +32,8 @@ fn puts(arguments: Arguments, _source: Location) -> Evaluation {
     }
 }
 
-fn read(arguments: Arguments, source: Location) -> Evaluation {
-    match &**arguments.get("path").unwrap() {
+fn read(arguments: &Arguments, source: Location) -> Evaluation {
+    match &*arguments[0] {
         Object::String(path) => {
             if let Ok(content) = fs::read_to_string(path) {
                 return Ok(Rc::new(Object::String(content)));
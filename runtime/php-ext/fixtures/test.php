@@ -9,3 +9,9 @@ var_dump(santa_aoc_run($solution, cwd: __DIR__));
 var_dump(santa_aoc_test($solution));
 
 var_dump(santa_evaluate("1.. |> filter(_ % 2) |> take(3);"));
+
+try {
+    santa_evaluate("1.. |> map(_ + 1) |> sum;", max_steps: 100);
+} catch (Exception $e) {
+    var_dump($e->getMessage());
+}
@@ -20,11 +20,11 @@ pub fn definitions() -> Vec<ExternalFnDef> {
     ]
 }
 
-fn puts(arguments: Arguments, _source: Location) -> Evaluation {
-    match &**arguments.get("values").unwrap() {
+fn puts(arguments: &Arguments, _source: Location) -> Evaluation {
+    match &*arguments[0] {
         Object::List(values) => {
             for value in values {
-                php_print!("{}", value)
+                php_print!("{}", value.pretty())
             }
             php_println!();
             Ok(Rc::new(Object::Nil))
@@ -33,8 +33,8 @@ fn puts(arguments: Arguments, _source: Location) -> Evaluation {
     }
 }
 
-fn read(arguments: Arguments, source: Location) -> Evaluation {
-    match &**arguments.get("path").unwrap() {
+fn read(arguments: &Arguments, source: Location) -> Evaluation {
+    match &*arguments[0] {
         Object::String(path) => match Url::parse(path) {
             Ok(uri) if uri.scheme() == "aoc" => {
                 let cache = format!(
@@ -16,12 +16,25 @@ impl Time for PhpTime {
 }
 
 #[php_function(optional = "cwd")]
-pub fn santa_aoc_run(source: &str, cwd: Option<String>) -> PhpResult<Zval> {
+pub fn santa_aoc_run(
+    source: &str,
+    cwd: Option<String>,
+    max_steps: Option<u64>,
+    max_frame_depth: Option<usize>,
+    source_path: Option<String>,
+) -> PhpResult<Zval> {
     if let Some(dir) = cwd {
         std::env::set_current_dir(dir).unwrap();
     }
 
     let mut runner = AoCRunner::new_with_external_functions(PhpTime {}, &crate::external_functions::definitions());
+    if let Some(source_path) = source_path {
+        runner.set_source_path(&source_path);
+    }
+    runner.set_max_steps(max_steps);
+    if let Some(max_frame_depth) = max_frame_depth {
+        runner.set_max_frame_depth(max_frame_depth);
+    }
 
     match runner.run(source) {
         Ok(RunEvaluation::Script(result)) => {
@@ -40,6 +53,7 @@ pub fn santa_aoc_run(source: &str, cwd: Option<String>) -> PhpResult<Zval> {
                 let mut part_one_ht = ZendHashTable::new();
                 part_one_ht.insert("value", part_one.value)?;
                 part_one_ht.insert("duration", part_one.duration as u64)?;
+                part_one_ht.insert("timed_out", part_one.timed_out)?;
                 output_ht.insert("part_one", part_one_ht)?;
             }
 
@@ -47,6 +61,7 @@ pub fn santa_aoc_run(source: &str, cwd: Option<String>) -> PhpResult<Zval> {
                 let mut part_two_ht = ZendHashTable::new();
                 part_two_ht.insert("value", part_two.value)?;
                 part_two_ht.insert("duration", part_two.duration as u64)?;
+                part_two_ht.insert("timed_out", part_two.timed_out)?;
                 output_ht.insert("part_two", part_two_ht)?;
             }
 
@@ -59,12 +74,20 @@ pub fn santa_aoc_run(source: &str, cwd: Option<String>) -> PhpResult<Zval> {
 }
 
 #[php_function(optional = "cwd")]
-pub fn santa_aoc_test(source: &str, cwd: Option<String>, include_slow: Option<bool>) -> PhpResult<Zval> {
+pub fn santa_aoc_test(
+    source: &str,
+    cwd: Option<String>,
+    include_slow: Option<bool>,
+    source_path: Option<String>,
+) -> PhpResult<Zval> {
     if let Some(dir) = cwd {
         std::env::set_current_dir(dir).unwrap();
     }
 
     let mut runner = AoCRunner::new_with_external_functions(PhpTime {}, &crate::external_functions::definitions());
+    if let Some(source_path) = source_path {
+        runner.set_source_path(&source_path);
+    }
 
     match runner.test(source, include_slow.unwrap_or(false)) {
         Ok(test_cases) => {
@@ -72,12 +95,18 @@ pub fn santa_aoc_test(source: &str, cwd: Option<String>, include_slow: Option<bo
 
             for test_case in test_cases {
                 let mut test_case_ht = ZendHashTable::new();
+                test_case_ht.insert("number", test_case.number as u64)?;
+
+                if let Some(name) = test_case.name {
+                    test_case_ht.insert("name", name)?;
+                }
 
                 if let Some(part_one) = test_case.part_one {
                     let mut part_one_ht = ZendHashTable::new();
                     part_one_ht.insert("actual", part_one.actual)?;
                     part_one_ht.insert("expected", part_one.expected)?;
                     part_one_ht.insert("passed", part_one.passed)?;
+                    part_one_ht.insert("timed_out", part_one.timed_out)?;
                     test_case_ht.insert("part_one", part_one_ht)?;
                 }
 
@@ -86,9 +115,23 @@ pub fn santa_aoc_test(source: &str, cwd: Option<String>, include_slow: Option<bo
                     part_two_ht.insert("actual", part_two.actual)?;
                     part_two_ht.insert("expected", part_two.expected)?;
                     part_two_ht.insert("passed", part_two.passed)?;
+                    part_two_ht.insert("timed_out", part_two.timed_out)?;
                     test_case_ht.insert("part_two", part_two_ht)?;
                 }
 
+                test_case_ht.insert("assertions", test_case.assertions as u64)?;
+
+                if let Some(assertion) = test_case.assertion {
+                    let mut assertion_ht = ZendHashTable::new();
+                    assertion_ht.insert("actual", assertion.actual)?;
+                    assertion_ht.insert("expected", assertion.expected)?;
+                    test_case_ht.insert("assertion", assertion_ht)?;
+                }
+
+                if test_case.timed_out {
+                    test_case_ht.insert("timed_out", true)?;
+                }
+
                 output_ht.push(test_case_ht)?;
             }
 
@@ -101,12 +144,21 @@ pub fn santa_aoc_test(source: &str, cwd: Option<String>, include_slow: Option<bo
 }
 
 #[php_function(optional = "cwd")]
-pub fn santa_evaluate(expression: &str, cwd: Option<String>) -> PhpResult<String> {
+pub fn santa_evaluate(
+    expression: &str,
+    cwd: Option<String>,
+    max_steps: Option<u64>,
+    max_frame_depth: Option<usize>,
+) -> PhpResult<String> {
     if let Some(dir) = cwd {
         std::env::set_current_dir(dir).unwrap();
     }
 
     let mut evaluator = Evaluator::new_with_external_functions(&crate::external_functions::definitions());
+    evaluator.set_max_steps(max_steps);
+    if let Some(max_frame_depth) = max_frame_depth {
+        evaluator.set_max_frame_depth(max_frame_depth);
+    }
 
     let lexer = Lexer::new(expression);
     let mut parser = Parser::new(lexer);
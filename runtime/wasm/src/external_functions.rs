@@ -11,8 +11,8 @@ pub fn definitions(js_functions: &JsObject) -> Vec<ExternalFnDef> {
             (
                 definition.get(0).as_string().unwrap(),
                 vec![ExpressionKind::RestIdentifier("arguments".to_owned())],
-                Rc::new(move |arguments: Arguments, source: Location| -> Evaluation {
-                    let argument = serde_wasm_bindgen::to_value(arguments.get("arguments").unwrap()).unwrap();
+                Rc::new(move |arguments: &Arguments, source: Location| -> Evaluation {
+                    let argument = serde_wasm_bindgen::to_value(&arguments[0]).unwrap();
                     if let Ok(result) =
                         Function::from(definition.get(1)).apply(&JsValue::null(), &JsArray::from(&argument))
                     {
@@ -24,7 +24,7 @@ pub fn definitions(js_functions: &JsObject) -> Vec<ExternalFnDef> {
                             trace: vec![],
                         })
                     }
-                }) as Rc<dyn Fn(Arguments, Location) -> Evaluation>,
+                }) as Rc<dyn Fn(&Arguments, Location) -> Evaluation>,
             )
         })
         .collect()
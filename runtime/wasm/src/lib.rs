@@ -21,9 +21,18 @@ impl Time for WebTime {
 }
 
 #[wasm_bindgen]
-pub fn aoc_run(source: &str, js_functions: Object) -> Result<JsValue, JsValue> {
+pub fn aoc_run(
+    source: &str,
+    js_functions: Object,
+    max_steps: Option<u32>,
+    max_frame_depth: Option<u32>,
+) -> Result<JsValue, JsValue> {
     let mut runner =
         AoCRunner::new_with_external_functions(WebTime {}, &crate::external_functions::definitions(&js_functions));
+    runner.set_max_steps(max_steps.map(u64::from));
+    if let Some(max_frame_depth) = max_frame_depth {
+        runner.set_max_frame_depth(max_frame_depth as usize);
+    }
 
     match runner.run(source) {
         Ok(result) => Ok(serde_wasm_bindgen::to_value(&result).unwrap()),
@@ -48,13 +57,22 @@ pub fn aoc_test(source: &str, js_functions: Object, include_slow: Option<bool>)
 }
 
 #[wasm_bindgen]
-pub fn evaluate(expression: &str, js_functions: Option<Object>) -> Result<JsValue, JsValue> {
+pub fn evaluate(
+    expression: &str,
+    js_functions: Option<Object>,
+    max_steps: Option<u32>,
+    max_frame_depth: Option<u32>,
+) -> Result<JsValue, JsValue> {
     let external_functions = if let Some(js_functions) = js_functions {
         crate::external_functions::definitions(&js_functions)
     } else {
         vec![]
     };
     let mut evaluator = Evaluator::new_with_external_functions(&external_functions);
+    evaluator.set_max_steps(max_steps.map(u64::from));
+    if let Some(max_frame_depth) = max_frame_depth {
+        evaluator.set_max_frame_depth(max_frame_depth as usize);
+    }
 
     let lexer = Lexer::new(expression);
     let mut parser = Parser::new(lexer);
@@ -29,7 +29,7 @@ test: {
 
 #[wasm_bindgen_test]
 fn script() {
-    let result = aoc_run("1..5 |> map(_ + 1) |> reduce(+);", js_sys::Object::new()).unwrap();
+    let result = aoc_run("1..5 |> map(_ + 1) |> reduce(+);", js_sys::Object::new(), None, None).unwrap();
 
     assert_eq!(
         "14",
@@ -39,7 +39,7 @@ fn script() {
 
 #[wasm_bindgen_test]
 fn solution() {
-    let result = aoc_run(SOLUTION, js_sys::Object::new()).unwrap();
+    let result = aoc_run(SOLUTION, js_sys::Object::new(), None, None).unwrap();
 
     let part_one = Reflect::get(&result, &"part_one".into()).unwrap();
     assert_eq!(
@@ -93,11 +93,18 @@ fn test_solution() {
 
 #[wasm_bindgen_test]
 fn evaluation() {
-    let result = evaluate(SCRIPT, None).unwrap();
+    let result = evaluate(SCRIPT, None, None, None).unwrap();
 
     assert_eq!("14", result.as_string().unwrap())
 }
 
+#[wasm_bindgen_test]
+fn evaluation_exceeding_max_steps() {
+    let result = evaluate(SCRIPT, None, Some(3), None);
+
+    assert!(result.is_err());
+}
+
 #[wasm_bindgen_test]
 fn format_code() {
     let unformatted = "let x=1+2;";